@@ -86,18 +86,109 @@ pub fn run() -> ChuiResult<()> {
                     display_board = false;
                     continue;
                 }
+                Some(CommandKind::DisplayEPD) => {
+                    log();
+                    log_ln(Epd::from_game(&game).to_string());
+                    display_board = false;
+                    continue;
+                }
+                Some(CommandKind::DisplayPGN) => {
+                    log();
+                    log_ln(game.to_pgn());
+                    display_board = false;
+                    continue;
+                }
+                Some(CommandKind::Undo) => {
+                    log();
+                    match game.undo() {
+                        Ok(chess_move) => log_ln(format!("Took back {}.", chess_move)),
+                        Err(error) => log_ln(error.to_string()),
+                    }
+                    continue;
+                }
+                Some(CommandKind::Redo) => {
+                    log();
+                    match game.redo() {
+                        Ok(chess_move) => log_ln(format!("Played {} again.", chess_move)),
+                        Err(error) => log_ln(error.to_string()),
+                    }
+                    continue;
+                }
+                Some(CommandKind::ToggleLenient) => {
+                    log();
+                    game.lenient = !game.lenient;
+                    command.rebuild_commands(&game);
+                    log_ln(format!(
+                        "Lenient move input is {}.",
+                        if game.lenient { "on" } else { "off" }
+                    ));
+                    continue;
+                }
+                Some(CommandKind::ToggleFigurines) => {
+                    log();
+                    game.piece_style = match game.piece_style {
+                        PieceStyle::Letter => PieceStyle::Figurine,
+                        PieceStyle::Figurine => PieceStyle::Letter,
+                    };
+                    command.rebuild_commands(&game);
+                    log_ln(format!("Pieces are now shown as {:?}s.", game.piece_style));
+                    continue;
+                }
+                Some(CommandKind::SwitchLocale) => {
+                    log();
+                    log_str("Input locale (en, de, fr, es, nl, it).");
+
+                    let locale_input: String = Game::get_input();
+
+                    match Locale::try_from(locale_input.as_str()) {
+                        Ok(locale) => {
+                            game.set_locale(locale);
+                            command.rebuild_commands(&game);
+                            log_ln(format!("Piece letters are now {:?}.", locale));
+                        }
+                        Err(error) => log_ln(error.to_string()),
+                    }
+
+                    display_board = false;
+                    continue;
+                }
+                Some(CommandKind::Perft) => {
+                    log();
+                    log_str("Input perft depth.");
+
+                    let depth_input: String = Game::get_input();
+
+                    match depth_input.parse::<usize>() {
+                        Ok(depth) => match game.divide_report(depth) {
+                            Ok(report) => log_ln(report),
+                            Err(error) => log_ln(error.to_string()),
+                        },
+                        Err(error) => log_ln(format!("Invalid depth `{}`: {}", depth_input, error)),
+                    }
+
+                    display_board = false;
+                    continue;
+                }
                 Some(CommandKind::WhiteResigns) => {
                     log();
                     log_str("White resigns.");
-                    game.win_condition = Some(WinCondition::WhiteResigns);
-                    game.draw_condition = None;
+                    game.resign(Color::White);
                     continue;
                 }
                 Some(CommandKind::BlackResigns) => {
                     log();
                     log_str("Black resigns.");
-                    game.win_condition = Some(WinCondition::BlackResigns);
-                    game.draw_condition = None;
+                    game.resign(Color::Black);
+                    continue;
+                }
+                Some(CommandKind::ClaimDraw) => {
+                    log();
+                    match game.claim_draw() {
+                        Ok(draw_condition) => {
+                            log_ln(format!("The game is a draw ({:?}).", draw_condition))
+                        }
+                        Err(error) => log_ln(error.to_string()),
+                    }
                     continue;
                 }
                 Some(CommandKind::DisplayForWhiteEachMove) => {
@@ -117,14 +208,19 @@ pub fn run() -> ChuiResult<()> {
 
                     log();
 
-                    for (move_idx, move_obj) in game.move_list.iter().enumerate() {
+                    let move_list: Vec<String> = match game.piece_style {
+                        PieceStyle::Letter => game.get_san_move_list_for_locale(game.locale),
+                        PieceStyle::Figurine => game.get_fan_move_list(),
+                    };
+
+                    for (move_idx, san) in move_list.iter().enumerate() {
                         let numeral = if move_idx % 2 == 0 {
                             format!("\n{}. ", (move_idx + 2) / 2)
                         } else {
                             String::new()
                         };
 
-                        output = format!("{}{}{} ", output, numeral, move_obj);
+                        output = format!("{}{}{} ", output, numeral, san);
                     }
 
                     if game.move_list.is_empty() {
@@ -171,12 +267,10 @@ pub fn run() -> ChuiResult<()> {
                     }
 
                     if move_string.eq("1-0") {
-                        game.win_condition = Some(WinCondition::BlackResigns);
-                        game.draw_condition = None;
+                        game.resign(Color::Black);
                         break;
                     } else if move_string.eq("0-1") {
-                        game.win_condition = Some(WinCondition::WhiteResigns);
-                        game.draw_condition = None;
+                        game.resign(Color::White);
                         break;
                     } else if move_string.eq("1/2-1/2") || move_string.eq("½-½") {
                         game.win_condition = None;
@@ -184,24 +278,32 @@ pub fn run() -> ChuiResult<()> {
                         break;
                     }
 
-                    match game.parse(move_string, game.to_move).as_mut() {
+                    match game.parse(move_string, game.to_move) {
                         Ok(chess_move) => {
-                            if let Err(result) = chess_move.validate_move_for_board(&game.board) {
-                                log_ln(result.to_string());
-                                log_str("Move was found to be invalid by the validation function. Move not applied.");
+                            game.set_current_move(Some(chess_move));
+                            if let Err(error) = game.apply_move() {
+                                log_ln(error.to_string());
+                                log_str("Move not applied.");
                                 continue;
                             }
-                            game.set_current_move(Some(chess_move.clone()));
-                            if game.apply_move().is_ok() {
-                                game.move_list.push(chess_move.clone());
 
-                                game.half_move_counter += 1;
-                                if game.half_move_counter % 2 == 0 {
-                                    game.move_counter += 1;
-                                }
+                            if let Some(WinCondition::Checkmate) = game.win_condition {
+                                log_ln(format!("Checkmate. {:?} wins.", game.to_move.opposite()));
+                            } else if let Some(DrawCondition::Stalemate) = game.draw_condition {
+                                log_str("Stalemate. The game is a draw.");
+                            } else if let Some(draw_condition) = game.draw_condition {
+                                log_ln(format!("The game is a draw ({:?}).", draw_condition));
                             } else {
-                                log_str("Move not applied.");
-                                continue;
+                                if game.is_in_check() {
+                                    log_ln(format!("{:?} is in check.", game.to_move));
+                                }
+
+                                if let Some(draw_condition) = game.get_claimable_draw() {
+                                    log_ln(format!(
+                                        "{:?} may claim a draw ({:?}).",
+                                        game.to_move, draw_condition
+                                    ));
+                                }
                             }
                         }
 
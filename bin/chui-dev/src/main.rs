@@ -4,6 +4,7 @@
 #![allow(dead_code)]
 #![allow(clippy::shadow_unrelated)]
 
+use std::env;
 use std::mem::{size_of, size_of_val};
 
 use chui_core::prelude::{coord::*, *};
@@ -97,7 +98,34 @@ fn do_position(name: String, position: &mut dyn Position) -> ChuiResult<()> {
     Ok(())
 }
 
+/// Print the perft divide of a position to `depth`. The position defaults to the start
+/// position.
+///
+/// Usage: `chui-dev perft <depth> [fen]`
+fn perft(depth: &str, fen: Option<&str>) -> ChuiResult<()> {
+    let depth: usize = depth.parse()?;
+    let mut game = Game::default();
+
+    if let Some(fen) = fen {
+        game.set_fen(fen)?;
+    }
+
+    println!("{}", Fen::get_fen(&game));
+    println!();
+
+    println!("{}", game.divide_report(depth)?);
+
+    Ok(())
+}
+
 fn main() -> ChuiResult<()> {
+    let args: Vec<String> = env::args().collect();
+
+    if args.get(1).map(String::as_str) == Some("perft") {
+        let depth = args.get(2).map_or("1", String::as_str);
+        return perft(depth, args.get(3).map(String::as_str));
+    }
+
     let mut easy_2d_position = Easy2DPosition::new(Variant::StandardChess);
     let mut bit_position = BitPosition::new(Variant::StandardChess);
     let mut array_bit_position = ArrayBitPosition::new(Variant::StandardChess);
@@ -36,6 +36,17 @@ pub struct Board {
 
     /// Represents the en passant target piece (pawn).
     en_passant_target_piece: Option<Piece>,
+
+    /// The `Color` to move. Kept by the board for its Zobrist hash.
+    to_move: Color,
+
+    /// The files of the castling Rooks: White's King side and Queen side Rooks, then
+    /// Black's King side and Queen side Rooks.
+    castling_rook_files: [u8; 4],
+
+    /// Is this a Chess960 board? Castling moves on a Chess960 board are made by moving
+    /// the King onto the castling Rook.
+    chess960: bool,
 }
 
 impl Board {
@@ -45,6 +56,18 @@ impl Board {
 
     /// Return a new [`Board`] given a [`ChessVariant`].
     pub fn new(variant: Variant) -> Board {
+        let mut castling_rook_files: [u8; 4] = [7, 0, 7, 0];
+
+        if let Some(back_rank) = variant.get_back_rank() {
+            let rooks: Vec<u8> = (0..8)
+                .filter(|file| back_rank[usize::from(*file)] == PieceKind::Rook)
+                .collect();
+
+            if let [queenside, kingside] = rooks.as_slice() {
+                castling_rook_files = [*kingside, *queenside, *kingside, *queenside];
+            }
+        }
+
         Board {
             position: BitPosition::new(variant),
             white_can_castle_kingside: true,
@@ -54,69 +77,555 @@ impl Board {
             en_passant_target_square: None,
             true_en_passant_target_square: None,
             en_passant_target_piece: None,
+            to_move: Color::White,
+            castling_rook_files,
+            chess960: matches!(variant, Variant::Chess960(_)),
         }
     }
 
+    /// Return a new [`Board`] given a FEN string. All six fields of the FEN string are
+    /// validated, but only the piece placement, castling rights and en passant target
+    /// square are kept by the board. See `Game::from_fen()` for the rest.
+    ///
+    /// The castling rights `KQkq` require the King and Rooks on their Standard Chess
+    /// initial squares. Castling rights given as the files of the castling Rooks (as with
+    /// Shredder-FEN, e.g., `HAha`) make this a Chess960 board. See `from_chess960_fen()`.
+    ///
+    /// # Errors
+    ///
+    /// * Errors with [`ChuiError::InvalidFen`] if the FEN string does not have six fields,
+    ///   or if the side to move or the clocks are malformed.
+    /// * Errors with [`ChuiError::InvalidPiecePlacement`] if the piece placement is
+    ///   malformed, or if the side that is not to move is in check.
+    /// * Errors with [`ChuiError::InvalidCastlingRights`] if the castling rights are
+    ///   malformed or do not agree with the piece placement.
+    /// * Errors with [`ChuiError::InvalidEnPassant`] if the en passant target square is
+    ///   malformed or does not agree with the piece placement.
+    pub fn from_fen(fen: &str) -> ChuiResult<Board> {
+        Board::parse_fen(fen, false)
+    }
+
+    /// Return a new Chess960 [`Board`] given an X-FEN or Shredder-FEN string. The castling
+    /// rights `K` and `Q` (or `k` and `q`) name the outermost Rook on that side of the King,
+    /// and a file (e.g., `F` or `f`) names the Rook on that file.
+    ///
+    /// # Errors
+    ///
+    /// * Errors as with `from_fen()`.
+    pub fn from_chess960_fen(fen: &str) -> ChuiResult<Board> {
+        Board::parse_fen(fen, true)
+    }
+
+    /// Parse a FEN string, given whether the board is a Chess960 board.
+    fn parse_fen(fen: &str, chess960: bool) -> ChuiResult<Board> {
+        let fields = Fen::split_fields(fen)?;
+        let mut board = Board::new(Variant::Empty);
+        board.chess960 = chess960;
+
+        board.position = Fen::parse_piece_placement(fields[0])?;
+        let to_move = Fen::parse_to_move(fields[1])?;
+        board.to_move = to_move;
+        Fen::parse_castling(fields[2], &mut board)?;
+        let (coord, piece) = Fen::parse_en_passant(fields[3], &board, to_move)?;
+        Fen::parse_half_move_clock(fields[4])?;
+        Fen::parse_full_move_counter(fields[5])?;
+
+        board.set_en_passant(coord, piece);
+        board.update_true_en_passant_target_square();
+
+        // The side that just moved cannot have left its King in check.
+        let king = board
+            .position
+            .get_pieces_for_color(to_move.opposite())
+            .into_iter()
+            .find(|piece| piece.is_king());
+
+        if let Some(king) = king {
+            if !board
+                .position
+                .get_pieces_attacking_coord(&board, king, Some(king.get_coord()))
+                .is_empty()
+            {
+                return Err(ChuiError::InvalidPiecePlacement(format!(
+                    "{} is to move but the {} King is in check",
+                    to_move,
+                    to_move.opposite()
+                )));
+            }
+        }
+
+        Ok(board)
+    }
+
     //
     // Conditionals.
     //
 
-    // /// Apply the passed in move onto the chessboard.
-    // ///
-    // /// # Errors
-    // ///
-    // /// Errors if the piece we're moving is `None`.
-    // ///
-    // /// # Panics
-    // ///
-    // /// Panics when the `move_obj`'s piece is None after checking that it is Some.
-    // pub fn apply_move(&mut self, move_obj: &ChessMove) -> ChuiResult<Option<Piece>> {
-    //     if move_obj.get_piece().is_none() {
-    //         return Err(ChuiError::InvalidMove(
-    //             "No piece to apply move.".to_string(),
-    //         ));
-    //     }
-
-    //     let pieces = self.position.get_pieces(move_obj.get_piece().unwrap());
-
-    //     // println!("Pieces: {:?}", pieces);
-
-    //     let mut pieces_can_move = Vec::<Piece>::new();
-
-    //     for piece in pieces.iter() {
-    //         if piece.get_move_coords(self, None).iter().any(|&coord| {
-    //             coord.get_file() == move_obj.to_coord.get_file()
-    //                 && coord.get_rank() == move_obj.to_coord.get_rank()
-    //         }) {
-    //             pieces_can_move.push(*piece);
-    //         }
-    //     }
-
-    //     // println!("Pieces can move: {:?}", pieces_can_move);
-
-    //     let (file, rank) = move_obj.to_coord.to_u8_index();
-
-    //     if pieces_can_move.is_empty() {
-    //         Err(ChuiError::InvalidMove(format!(
-    //             "No {} can move to target square {}",
-    //             move_obj.get_piece().unwrap(),
-    //             move_obj.to_coord
-    //         )))
-    //     } else if pieces_can_move.len() == 1 {
-    //         let piece = pieces_can_move.get(0).unwrap();
-    //         self.get_position_mut().replace_piece(*piece, move_obj)
-    //     } else {
-    //         Err(ChuiError::InvalidMove(format!(
-    //             "Ambiguous move. More than one piece can move to target square {}{}",
-    //             file, rank
-    //         )))
-    //     }
-    // }
+    /// Apply the passed in move onto the chessboard. The move must have been validated for
+    /// this board first (see `ChessMove::validate_move_for_board()`). Captures (including en
+    /// passant), the Rook's side of castling, promotion, the castling rights and the en
+    /// passant target square are all handled here. Returns the captured piece, if any.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the move is missing its coordinates or moving piece, or if the moving piece
+    /// is not on the board.
+    pub fn apply_move(&mut self, move_obj: &ChessMove) -> ChuiResult<Option<Piece>> {
+        let (from_coord, to_coord, from_piece) =
+            match (move_obj.from_coord, move_obj.to_coord, move_obj.from_piece) {
+                (Some(from_coord), Some(to_coord), Some(from_piece)) => {
+                    (from_coord, to_coord, from_piece)
+                }
+                _ => {
+                    return Err(ChuiError::InvalidMove(
+                        "The move must be validated for the board before it can be applied"
+                            .to_string(),
+                    ))
+                }
+            };
+
+        if self.position.get_piece(Some(from_coord)) != Some(from_piece) {
+            return Err(ChuiError::InvalidMove(format!(
+                "There is no {} on `{}` to move",
+                from_piece.get_text(),
+                from_coord
+            )));
+        }
+
+        let mut piece: Piece = from_piece;
+        let mut captured_piece: Option<Piece> = None;
+
+        if let Some(castling) = self.get_castling(from_piece, to_coord) {
+            // Castling moves both the King and the Rook. Either one may land on the other's
+            // initial square, so both are taken off of the board first.
+            let color: Color = from_piece.get_color();
+            let rook_from: Coord = self.get_castling_rook_coord(color, castling)?;
+            let (king_to, rook_to) = Board::get_castled_coords(color, castling)?;
+            let mut rook: Piece = self.position.take_piece(Some(rook_from)).ok_or_else(|| {
+                ChuiError::InvalidMove(format!("There is no Rook on `{}` to castle", rook_from))
+            })?;
+
+            self.position.take_piece(Some(from_coord));
+            piece.set_coord(Some(king_to));
+            piece.set_has_moved(true);
+            rook.set_coord(Some(rook_to));
+            rook.set_has_moved(true);
+            self.position.put_piece(Some(piece), Some(king_to));
+            self.position.put_piece(Some(rook), Some(rook_to));
+        } else {
+            // Take the captured piece off of the board first. For an en passant capture
+            // this piece is not on `to_coord`.
+            captured_piece = move_obj
+                .to_piece
+                .and_then(|to_piece| self.position.take_piece(Some(to_piece.get_coord())));
+
+            // Move (or promote) the piece.
+            self.position.take_piece(Some(from_coord));
+            piece = move_obj.promotion.map_or(from_piece, |promotion| {
+                Piece::new(promotion.get_kind(), from_piece.get_color(), to_coord)
+            });
+            piece.set_coord(Some(to_coord));
+            piece.set_has_moved(true);
+            captured_piece =
+                captured_piece.or(self.position.put_piece(Some(piece), Some(to_coord)));
+        }
+
+        // Moving the King or a Rook, or capturing a Rook on its initial square, loses the
+        // right to castle on that side.
+        if from_piece.is_king() {
+            self.set_castling_rights(from_piece.get_color(), Castling::King, false);
+            self.set_castling_rights(from_piece.get_color(), Castling::Queen, false);
+        }
+
+        self.update_castling_rights(from_coord);
+        self.update_castling_rights(to_coord);
+
+        // A Pawn advancing two squares sets the en passant target square to the square it
+        // passed over.
+        self.set_en_passant(None, None);
+
+        if from_piece.is_pawn()
+            && (to_coord.get_rank() as i8 - from_coord.get_rank() as i8).abs() == 2
+        {
+            let target = Coord::new(
+                to_coord.get_file(),
+                (from_coord.get_rank() + to_coord.get_rank()) / 2,
+            )?;
+            self.set_en_passant(Some(target), Some(piece));
+            self.update_true_en_passant_target_square();
+        }
+
+        self.to_move = from_piece.get_color().opposite();
+
+        Ok(captured_piece)
+    }
+
+    /// The true (X-FEN) en passant target square is only kept when an opposing Pawn can
+    /// actually capture the en passant target piece.
+    fn update_true_en_passant_target_square(&mut self) {
+        let can_capture = self.en_passant_target_piece.is_some_and(|piece| {
+            [-1, 1].iter().any(|file_delta| {
+                self.position
+                    .get_piece(piece.get_coord().offset(*file_delta, 0))
+                    .is_some_and(|p| p.is_pawn() && !p.is_same_color(piece))
+            })
+        });
+
+        if !can_capture {
+            self.true_en_passant_target_square = None;
+        }
+    }
+
+    /// Get all of the legal moves for `color`. Every pseudo-legal move from the
+    /// [`Position`] move helpers is played on a copy of the board and kept only if it does
+    /// not leave `color`'s King in check, which covers pins, (double) checks and en passant
+    /// captures that expose the King. A Pawn moving to the last rank yields one move per
+    /// promotion piece. The moves are fully resolved, as with
+    /// `ChessMove::validate_move_for_board()`, and can be applied with `apply_move()`.
+    pub fn legal_moves(&self, color: Color) -> Vec<ChessMove> {
+        let mut moves = Vec::<ChessMove>::new();
+
+        for piece in self.position.get_pieces_for_color(color) {
+            for to_coord in piece.get_move_coords(self, None) {
+                if !self.is_king_safe_after_move(piece, to_coord) {
+                    continue;
+                }
+
+                let chess_move = self.new_move(piece, to_coord);
+                let last_rank: u8 = if color == Color::White { 7 } else { 0 };
+
+                if piece.is_pawn() && to_coord.get_rank() == last_rank {
+                    for kind in [
+                        PieceKind::Queen,
+                        PieceKind::Rook,
+                        PieceKind::Bishop,
+                        PieceKind::Knight,
+                    ] {
+                        let mut promotion = chess_move.clone();
+                        promotion.promotion = Some(Piece::new(kind, color, to_coord));
+                        moves.push(promotion);
+                    }
+                } else {
+                    moves.push(chess_move);
+                }
+            }
+        }
+
+        moves
+    }
+
+    /// Is `color`'s King in check?
+    pub fn is_in_check(&self, color: Color) -> bool {
+        self.position
+            .get_pieces_for_color(color)
+            .into_iter()
+            .find(|piece| piece.is_king())
+            .is_some_and(|king| {
+                !self
+                    .position
+                    .get_pieces_attacking_coord(self, king, Some(king.get_coord()))
+                    .is_empty()
+            })
+    }
+
+    /// Is there too little material on the board for either side to checkmate? This is the
+    /// case with King versus King, King and Bishop versus King, King and Knight versus King,
+    /// and when the only other pieces are Bishops that all stand on squares of the same
+    /// color.
+    pub fn has_insufficient_material(&self) -> bool {
+        let pieces: Vec<Piece> = self
+            .position
+            .get_pieces_for_color(Color::White)
+            .into_iter()
+            .chain(self.position.get_pieces_for_color(Color::Black))
+            .filter(|piece| !piece.is_king())
+            .collect();
+
+        match pieces.as_slice() {
+            [] => true,
+            [piece] => piece.is_knight() || piece.is_bishop(),
+            [first, ..] => {
+                let square_color = |piece: &Piece| (piece.get_file() + piece.get_rank()) % 2;
+
+                pieces
+                    .iter()
+                    .all(|piece| piece.is_bishop() && square_color(piece) == square_color(first))
+            }
+        }
+    }
+
+    /// Would moving `piece` to `to_coord` keep its King out of check?
+    pub(crate) fn is_king_safe_after_move(&self, piece: Piece, to_coord: Coord) -> bool {
+        let mut board: Board = *self;
+
+        board.apply_move(&self.new_move(piece, to_coord)).is_ok()
+            && !board.is_in_check(piece.get_color())
+    }
+
+    /// Get the piece that moving `piece` to `to_coord` would capture, including a Pawn
+    /// captured en passant.
+    pub(crate) fn get_captured_piece(&self, piece: Piece, to_coord: Coord) -> Option<Piece> {
+        let to_piece = self.position.get_piece(Some(to_coord));
+
+        if to_piece.is_none()
+            && piece.is_pawn()
+            && piece.get_file() != to_coord.get_file()
+            && self.en_passant_target_square == Some(to_coord)
+        {
+            return self
+                .position
+                .get_piece(Coord::new(to_coord.get_file(), piece.get_rank()).ok())
+                .filter(|p| p.is_pawn() && !p.is_same_color(piece));
+        }
+
+        to_piece
+    }
+
+    /// Build a resolved (but unpromoted) [`ChessMove`] moving `piece` to `to_coord`.
+    fn new_move(&self, piece: Piece, to_coord: Coord) -> ChessMove {
+        let from_coord: Coord = piece.get_coord();
+        let castling: Option<Castling> = self.get_castling(piece, to_coord);
+        let to_piece: Option<Piece> = self
+            .get_captured_piece(piece, to_coord)
+            .filter(|_| castling.is_none());
+        let mut chess_move = ChessMove::new(piece.get_color());
+
+        chess_move.from_piece = Some(piece);
+        chess_move.to_piece = to_piece;
+        chess_move.set_from_coord(Some(from_coord));
+        chess_move.set_to_coord(Some(to_coord));
+        chess_move.from_coord_file = NonMaxU8::try_from(from_coord.get_file()).ok();
+        chess_move.from_coord_rank = NonMaxU8::try_from(from_coord.get_rank()).ok();
+        chess_move.to_coord_file = NonMaxU8::try_from(to_coord.get_file()).ok();
+        chess_move.to_coord_rank = NonMaxU8::try_from(to_coord.get_rank()).ok();
+
+        if castling.is_some() {
+            chess_move.castling = castling;
+            chess_move.set_move_type(MoveType::Castle);
+        } else {
+            chess_move.set_move_type(match (piece.is_pawn(), to_piece.is_some()) {
+                (true, false) => MoveType::PawnMove,
+                (true, true) => MoveType::PawnCapture,
+                (false, false) => MoveType::PieceMove,
+                (false, true) => MoveType::PieceCapture,
+            });
+        }
+
+        if piece.is_pawn() && (to_coord.get_rank() as i8 - from_coord.get_rank() as i8).abs() == 2 {
+            chess_move.set_en_passant(self).ok();
+        }
+
+        chess_move
+    }
+
+    /// Take back a move applied with `apply_move()`, given the [`MoveRecord`] of the state
+    /// of the board from before the move was applied. The moved piece (or promoted Pawn),
+    /// the Rook's side of castling, the captured piece, the castling rights and the en
+    /// passant target square are all restored.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the move is missing its coordinates or moving piece, or if there is no
+    /// piece on the square the move was made to.
+    pub fn unmake_move(&mut self, record: &MoveRecord) -> ChuiResult<()> {
+        let move_obj: &ChessMove = &record.chess_move;
+        let (from_coord, to_coord, from_piece) =
+            match (move_obj.from_coord, move_obj.to_coord, move_obj.from_piece) {
+                (Some(from_coord), Some(to_coord), Some(from_piece)) => {
+                    (from_coord, to_coord, from_piece)
+                }
+                _ => {
+                    return Err(ChuiError::InvalidMove(
+                        "The move must have been applied before it can be taken back".to_string(),
+                    ))
+                }
+            };
+
+        // Restore the castling rights first, so that a castling move is recognized.
+        self.white_can_castle_kingside = record.white_can_castle_kingside;
+        self.white_can_castle_queenside = record.white_can_castle_queenside;
+        self.black_can_castle_kingside = record.black_can_castle_kingside;
+        self.black_can_castle_queenside = record.black_can_castle_queenside;
+
+        if let Some(castling) = self.get_castling(from_piece, to_coord) {
+            // Move the King and the Rook back to their initial squares after castling.
+            let color: Color = from_piece.get_color();
+            let rook_from: Coord = self.get_castling_rook_coord(color, castling)?;
+            let (king_to, rook_to) = Board::get_castled_coords(color, castling)?;
+            let king: Option<Piece> = self.position.take_piece(Some(king_to));
+            let rook: Option<Piece> = self.position.take_piece(Some(rook_to));
+
+            if let (Some(_), Some(mut rook)) = (king, rook) {
+                rook.set_coord(Some(rook_from));
+                self.position.put_piece(Some(rook), Some(rook_from));
+            } else {
+                return Err(ChuiError::InvalidMove(
+                    "There is no castled King and Rook to take back".to_string(),
+                ));
+            }
+        } else if self.position.take_piece(Some(to_coord)).is_none() {
+            return Err(ChuiError::InvalidMove(format!(
+                "There is no piece on `{}` to take back",
+                to_coord
+            )));
+        }
+
+        self.position.put_piece(Some(from_piece), Some(from_coord));
+
+        if let Some(captured_piece) = record.captured_piece {
+            self.position
+                .put_piece(Some(captured_piece), Some(captured_piece.get_coord()));
+        }
+
+        self.en_passant_target_square = record.en_passant_target_square;
+        self.true_en_passant_target_square = record.true_en_passant_target_square;
+        self.en_passant_target_piece = record.en_passant_target_piece;
+        self.to_move = from_piece.get_color();
+
+        Ok(())
+    }
+
+    /// Remove any castling rights that depend on a castling Rook being on `coord`.
+    fn update_castling_rights(&mut self, coord: Coord) {
+        for color in [Color::White, Color::Black] {
+            for castling in [Castling::King, Castling::Queen] {
+                if self.get_castling_rook_coord(color, castling).ok() == Some(coord) {
+                    self.set_castling_rights(color, castling, false);
+                }
+            }
+        }
+    }
+
+    /// Get the side `piece` castles on by moving to `to_coord`, if the move is a castling
+    /// move. A King castles by moving onto its castling Rook (as on a Chess960 board), or
+    /// by moving two or more files to the square it castles to (as on a Standard Chess
+    /// board).
+    pub(crate) fn get_castling(&self, piece: Piece, to_coord: Coord) -> Option<Castling> {
+        let color: Color = piece.get_color();
+
+        if !piece.is_king() || piece.get_rank() != Board::get_back_rank(color) {
+            return None;
+        }
+
+        [Castling::King, Castling::Queen]
+            .into_iter()
+            .filter(|castling| self.can_castle(color, *castling))
+            .find(|castling| {
+                let rook_coord = self.get_castling_rook_coord(color, *castling).ok();
+                let king_to = Board::get_castled_coords(color, *castling)
+                    .ok()
+                    .map(|(king_to, _)| king_to);
+                let file_distance = (to_coord.get_file() as i8 - piece.get_file() as i8).abs();
+
+                Some(to_coord) == rook_coord || (Some(to_coord) == king_to && file_distance >= 2)
+            })
+    }
+
+    /// Get the squares the King and the Rook land on when `color` castles.
+    ///
+    /// # Errors
+    ///
+    /// This method does not error in practice, since the squares are always on the board.
+    fn get_castled_coords(color: Color, castling: Castling) -> ChuiResult<(Coord, Coord)> {
+        let rank: u8 = Board::get_back_rank(color);
+
+        match castling {
+            Castling::King => Ok((Coord::new(6, rank)?, Coord::new(5, rank)?)),
+            Castling::Queen => Ok((Coord::new(2, rank)?, Coord::new(3, rank)?)),
+        }
+    }
+
+    /// Get the index of `color`'s back rank.
+    const fn get_back_rank(color: Color) -> u8 {
+        match color {
+            Color::White => 0,
+            Color::Black => 7,
+        }
+    }
+
+    /// Get the index of the castling right (and castling Rook file) of `color` on the
+    /// `castling` side.
+    const fn get_castling_idx(color: Color, castling: Castling) -> usize {
+        match (color, castling) {
+            (Color::White, Castling::King) => 0,
+            (Color::White, Castling::Queen) => 1,
+            (Color::Black, Castling::King) => 2,
+            (Color::Black, Castling::Queen) => 3,
+        }
+    }
 
     //
     // Getters.
     //
 
+    /// Get the Zobrist hash of the board. Two boards have the same hash when the pieces, the
+    /// side to move, the castling rights, and the file of any en passant capture that can be
+    /// made are the same. The hash of the pieces is updated incrementally by the position.
+    pub fn hash(&self) -> u64 {
+        let mut hash: u64 = self.position.get_hash() ^ Zobrist::castling(self);
+
+        if self.to_move == Color::Black {
+            hash ^= Zobrist::black_to_move();
+        }
+
+        if let Some(coord) = self.true_en_passant_target_square {
+            hash ^= Zobrist::en_passant(coord);
+        }
+
+        hash
+    }
+
+    /// Get the `Color` to move.
+    pub const fn get_to_move(&self) -> Color {
+        self.to_move
+    }
+
+    /// Is this a Chess960 board? Castling moves on a Chess960 board are made by moving the
+    /// King onto the castling Rook (e.g., `e1h1`), since the King may otherwise move to the
+    /// square it castles to.
+    pub const fn is_chess960(&self) -> bool {
+        self.chess960
+    }
+
+    /// Can `color` castle on the `castling` side? This only reports the castling rights,
+    /// not whether castling is a legal move.
+    pub const fn can_castle(&self, color: Color, castling: Castling) -> bool {
+        match (color, castling) {
+            (Color::White, Castling::King) => self.white_can_castle_kingside,
+            (Color::White, Castling::Queen) => self.white_can_castle_queenside,
+            (Color::Black, Castling::King) => self.black_can_castle_kingside,
+            (Color::Black, Castling::Queen) => self.black_can_castle_queenside,
+        }
+    }
+
+    /// Get the initial square of `color`'s castling Rook on the `castling` side.
+    ///
+    /// # Errors
+    ///
+    /// This method does not error in practice, since the castling Rook files are always on
+    /// the board.
+    pub fn get_castling_rook_coord(&self, color: Color, castling: Castling) -> ChuiResult<Coord> {
+        Coord::new(
+            self.castling_rook_files[Board::get_castling_idx(color, castling)],
+            Board::get_back_rank(color),
+        )
+    }
+
+    /// Get the square `color`'s King moves to when castling on the `castling` side: the
+    /// castling Rook's square on a Chess960 board, or the square the King lands on
+    /// otherwise.
+    ///
+    /// # Errors
+    ///
+    /// This method does not error in practice, since the squares are always on the board.
+    pub fn get_castling_coord(&self, color: Color, castling: Castling) -> ChuiResult<Coord> {
+        if self.chess960 {
+            self.get_castling_rook_coord(color, castling)
+        } else {
+            Board::get_castled_coords(color, castling).map(|(king_to, _)| king_to)
+        }
+    }
+
     /// Get a reference to the position.
     pub const fn get_position(&self) -> BitPosition {
         self.position
@@ -132,6 +641,12 @@ impl Board {
         self.en_passant_target_square
     }
 
+    /// Get the true (X-FEN) en passant target square coordinate. This is only set if an en
+    /// passant capture is possible.
+    pub const fn get_true_en_passant_coord(&self) -> Option<Coord> {
+        self.true_en_passant_target_square
+    }
+
     /// Get the en passant target piece.
     pub const fn get_en_passant_piece(&self) -> Option<Piece> {
         self.en_passant_target_piece
@@ -146,6 +661,31 @@ impl Board {
     // Setters.
     //
 
+    /// Set the `Color` to move.
+    pub fn set_to_move(&mut self, color: Color) {
+        self.to_move = color;
+    }
+
+    /// Set whether this is a Chess960 board.
+    pub fn set_chess960(&mut self, chess960: bool) {
+        self.chess960 = chess960;
+    }
+
+    /// Set the castling right of `color` on the `castling` side.
+    pub fn set_castling_rights(&mut self, color: Color, castling: Castling, can_castle: bool) {
+        match (color, castling) {
+            (Color::White, Castling::King) => self.white_can_castle_kingside = can_castle,
+            (Color::White, Castling::Queen) => self.white_can_castle_queenside = can_castle,
+            (Color::Black, Castling::King) => self.black_can_castle_kingside = can_castle,
+            (Color::Black, Castling::Queen) => self.black_can_castle_queenside = can_castle,
+        }
+    }
+
+    /// Set the file of `color`'s castling Rook on the `castling` side.
+    pub fn set_castling_rook_file(&mut self, color: Color, castling: Castling, file: u8) {
+        self.castling_rook_files[Board::get_castling_idx(color, castling)] = file;
+    }
+
     /// Set the en passant target square coordinate.
     pub fn set_en_passant_coord(&mut self, coord: Option<Coord>) {
         self.en_passant_target_square = coord;
@@ -183,7 +723,7 @@ impl Board {
     }
 
     /// Print move coordinates in a pretty way.
-    pub fn print_coords(coords: &Vec<Coord>) {
+    pub fn print_coords(coords: &[Coord]) {
         let mut c_string = String::new();
         for c in coords.iter() {
             if c_string.is_empty() {
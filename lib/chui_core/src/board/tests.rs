@@ -1,4 +1,6 @@
 #![cfg(test)]
+// The board tests predate the lints below, and are left as they were written.
+#![allow(unused_imports, clippy::ptr_arg, clippy::manual_contains)]
 
 mod test_standard_chess;
 pub use test_standard_chess::standard_chess;
@@ -8,3 +10,11 @@ pub use test_piece_coords::piece_coords;
 
 mod test_bitmask_coords;
 pub use test_bitmask_coords::bitmask_coords;
+
+mod test_legal_moves;
+
+mod test_zobrist;
+
+mod test_chess960;
+
+mod test_san;
@@ -0,0 +1,177 @@
+//! Test Chess960 start positions and castling.
+
+use crate::prelude::*;
+
+pub mod chess960 {
+    use super::*;
+
+    fn find_move(board: &Board, color: Color, coordinate_text: &str) -> Option<ChessMove> {
+        board
+            .legal_moves(color)
+            .into_iter()
+            .find(|chess_move| chess_move.get_coordinate_text() == coordinate_text)
+    }
+
+    fn piece_on(board: &Board, coord: &str) -> Option<Piece> {
+        board.get_position().get_piece(Coord::try_from(coord).ok())
+    }
+
+    #[test]
+    fn test_start_position() -> ChuiResult<()> {
+        let board = Board::new(Variant::Chess960(518));
+        assert!(board.is_chess960());
+        assert_eq!(board.get_position(), Board::default().get_position());
+        assert_eq!(board.legal_moves(Color::White).len(), 20);
+
+        for idx in [0, 1, 959] {
+            let board = Board::new(Variant::Chess960(idx));
+            assert_eq!(
+                board
+                    .get_position()
+                    .get_pieces_for_color(Color::White)
+                    .len(),
+                16
+            );
+            assert_eq!(
+                board
+                    .get_position()
+                    .get_pieces_for_color(Color::Black)
+                    .len(),
+                16
+            );
+            assert_eq!(
+                board.get_position().get_hash(),
+                board.get_position().compute_hash()
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_castling_rook_files() -> ChuiResult<()> {
+        // BBQNNRKR
+        let board = Board::new(Variant::Chess960(0));
+        assert_eq!(
+            board.get_castling_rook_coord(Color::White, Castling::King)?,
+            Coord::try_from("h1")?
+        );
+        assert_eq!(
+            board.get_castling_rook_coord(Color::Black, Castling::Queen)?,
+            Coord::try_from("f8")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_castle_king_onto_rook() -> ChuiResult<()> {
+        let board = Board::from_chess960_fen("4k3/8/8/8/8/8/8/RK5R w KQ - 0 1")?;
+
+        // The King may both step to c1 and castle to c1, so castling is given as the
+        // King moving onto the Rook.
+        assert!(find_move(&board, Color::White, "b1c1").is_some());
+        let chess_move = find_move(&board, Color::White, "b1a1").unwrap();
+        assert_eq!(chess_move.castling, Some(Castling::Queen));
+
+        let mut castled = board;
+        castled.apply_move(&chess_move)?;
+        assert_eq!(piece_on(&castled, "c1"), Some(Piece::white_king(('c', 1))?));
+        assert_eq!(piece_on(&castled, "d1"), Some(Piece::white_rook(('d', 1))?));
+        assert_eq!(piece_on(&castled, "a1"), None);
+        assert_eq!(piece_on(&castled, "b1"), None);
+        assert!(!castled.can_castle(Color::White, Castling::King));
+
+        let chess_move = find_move(&board, Color::White, "b1h1").unwrap();
+        assert_eq!(chess_move.castling, Some(Castling::King));
+        let mut castled = board;
+        castled.apply_move(&chess_move)?;
+        assert_eq!(piece_on(&castled, "g1"), Some(Piece::white_king(('g', 1))?));
+        assert_eq!(piece_on(&castled, "f1"), Some(Piece::white_rook(('f', 1))?));
+        assert_eq!(piece_on(&castled, "h1"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_castle_onto_occupied_squares() -> ChuiResult<()> {
+        // The King and the Rook swap squares.
+        let board = Board::from_chess960_fen("4k3/8/8/8/8/8/8/R4KR1 w KQ - 0 1")?;
+        let chess_move = find_move(&board, Color::White, "f1g1").unwrap();
+        assert_eq!(chess_move.castling, Some(Castling::King));
+
+        let mut castled = board;
+        castled.apply_move(&chess_move)?;
+        assert_eq!(piece_on(&castled, "g1"), Some(Piece::white_king(('g', 1))?));
+        assert_eq!(piece_on(&castled, "f1"), Some(Piece::white_rook(('f', 1))?));
+        assert_eq!(
+            castled.get_position().get_hash(),
+            castled.get_position().compute_hash()
+        );
+
+        // The King stays on its square.
+        let board = Board::from_chess960_fen("4k3/8/8/8/8/8/8/R5KR w K - 0 1")?;
+        let chess_move = find_move(&board, Color::White, "g1h1").unwrap();
+        let mut castled = board;
+        castled.apply_move(&chess_move)?;
+        assert_eq!(piece_on(&castled, "g1"), Some(Piece::white_king(('g', 1))?));
+        assert_eq!(piece_on(&castled, "f1"), Some(Piece::white_rook(('f', 1))?));
+        assert_eq!(piece_on(&castled, "h1"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_castling_blocked_or_attacked() -> ChuiResult<()> {
+        // The Queen side Rook has to cross b1 to get to d1.
+        let board = Board::from_chess960_fen("4k3/8/8/8/8/8/8/RN1K3R w KQ - 0 1")?;
+        assert!(find_move(&board, Color::White, "d1a1").is_none());
+        assert!(find_move(&board, Color::White, "d1h1").is_some());
+
+        // The King passes over an attacked square.
+        let board = Board::from_chess960_fen("4kr2/8/8/8/8/8/8/RK5R w KQ - 0 1")?;
+        assert!(find_move(&board, Color::White, "b1h1").is_none());
+        assert!(find_move(&board, Color::White, "b1a1").is_some());
+
+        // The castling Rook shields the King on its square from the attacking Rook.
+        let board = Board::from_chess960_fen("4k3/8/8/8/8/8/8/rRK4R w Q - 0 1")?;
+        assert!(find_move(&board, Color::White, "c1b1").is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_fen_castling_rights() -> ChuiResult<()> {
+        // Shredder-FEN castling rights make a Chess960 board.
+        let board = Board::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w HAha - 0 1")?;
+        assert!(board.is_chess960());
+        assert_eq!(board, Board::new(Variant::Chess960(518)));
+        assert!(
+            !Board::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1")?
+                .is_chess960()
+        );
+
+        // X-FEN names the outermost Rook by its side, and any other Rook by its file.
+        let board = Board::from_chess960_fen("4k3/8/8/8/8/8/8/R1R1K2R w KC - 0 1")?;
+        assert_eq!(
+            board.get_castling_rook_coord(Color::White, Castling::Queen)?,
+            Coord::try_from("c1")?
+        );
+        assert_eq!(
+            board.get_castling_rook_coord(Color::White, Castling::King)?,
+            Coord::try_from("h1")?
+        );
+
+        for fen in [
+            "4k3/8/8/8/8/8/8/RK5R w AH - 0 1",
+            "4k3/8/8/8/8/8/8/RK5R w BA - 0 1",
+            "4k3/8/8/8/8/8/8/RK5R w k - 0 1",
+            "4k3/8/8/8/8/8/8/RK5R w KQK - 0 1",
+        ] {
+            assert!(
+                matches!(
+                    Board::from_chess960_fen(fen),
+                    Err(ChuiError::InvalidCastlingRights(_))
+                ),
+                "{}",
+                fen
+            );
+        }
+        Ok(())
+    }
+}
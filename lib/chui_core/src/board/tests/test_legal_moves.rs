@@ -0,0 +1,162 @@
+//! Test legal move generation.
+
+use crate::prelude::*;
+
+pub mod legal_moves {
+    use super::*;
+
+    fn get_moves(fen: &str) -> ChuiResult<Vec<ChessMove>> {
+        let board = Board::from_fen(fen)?;
+        let to_move = if fen.split(' ').nth(1) == Some("w") {
+            Color::White
+        } else {
+            Color::Black
+        };
+        Ok(board.legal_moves(to_move))
+    }
+
+    fn find_moves<'a>(moves: &'a [ChessMove], from: &str, to: &str) -> Vec<&'a ChessMove> {
+        moves
+            .iter()
+            .filter(|m| {
+                m.from_coord == Coord::try_from(from).ok() && m.to_coord == Coord::try_from(to).ok()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_start_position() -> ChuiResult<()> {
+        let moves = Board::default().legal_moves(Color::White);
+        assert_eq!(20, moves.len());
+        assert_eq!(20, Board::default().legal_moves(Color::Black).len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_kiwipete() -> ChuiResult<()> {
+        let moves =
+            get_moves("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1")?;
+        assert_eq!(48, moves.len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_pinned_piece() -> ChuiResult<()> {
+        let moves = get_moves("4k3/8/8/8/4r3/8/4N3/4K3 w - - 0 1")?;
+        assert!(moves
+            .iter()
+            .all(|m| m.from_piece.is_some_and(|p| p.is_king())));
+        assert_eq!(4, moves.len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_must_be_answered() -> ChuiResult<()> {
+        // The Rook can block or the King can step aside.
+        let moves = get_moves("4r1k1/8/8/8/8/8/R7/4K3 w - - 0 1")?;
+        assert_eq!(1, find_moves(&moves, "a2", "e2").len());
+        assert!(moves
+            .iter()
+            .filter(|m| m.from_piece.is_some_and(|p| p.is_rook()))
+            .all(|m| m.to_coord == Coord::try_from("e2").ok()));
+        assert_eq!(5, moves.len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_double_check() -> ChuiResult<()> {
+        // Only the King can move out of a double check.
+        let moves = get_moves("4r1k1/8/8/8/1b6/8/R7/4K3 w - - 0 1")?;
+        assert!(moves
+            .iter()
+            .all(|m| m.from_piece.is_some_and(|p| p.is_king())));
+        assert_eq!(3, moves.len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_castling_through_attacked_square() -> ChuiResult<()> {
+        let moves = get_moves("4k3/8/8/8/8/8/5r2/R3K2R w KQ - 0 1")?;
+        assert!(find_moves(&moves, "e1", "g1").is_empty());
+        assert_eq!(
+            Some(Castling::Queen),
+            find_moves(&moves, "e1", "c1")[0].castling
+        );
+
+        // Only the squares the King crosses must be safe.
+        let moves = get_moves("1r2k3/8/8/8/8/8/8/R3K3 w Q - 0 1")?;
+        assert_eq!(1, find_moves(&moves, "e1", "c1").len());
+
+        // The King cannot castle out of check.
+        let moves = get_moves("4k3/8/8/8/8/8/4r3/R3K2R w KQ - 0 1")?;
+        assert!(find_moves(&moves, "e1", "g1").is_empty());
+        assert!(find_moves(&moves, "e1", "c1").is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_en_passant() -> ChuiResult<()> {
+        let moves = get_moves("4k3/8/8/1Pp5/8/8/8/4K3 w - c6 0 1")?;
+        let en_passant = find_moves(&moves, "b5", "c6");
+        assert_eq!(1, en_passant.len());
+        assert_eq!(Some(MoveType::PawnCapture), en_passant[0].move_type);
+        assert_eq!(
+            Coord::try_from("c5").ok(),
+            en_passant[0].to_piece.map(|p| p.get_coord())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_en_passant_discovered_check() -> ChuiResult<()> {
+        // Capturing en passant would take both Pawns off of the 5th rank.
+        let moves = get_moves("8/8/8/KPp4r/8/8/8/4k3 w - c6 0 1")?;
+        assert!(find_moves(&moves, "b5", "c6").is_empty());
+        assert_eq!(1, find_moves(&moves, "b5", "b6").len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_promotions() -> ChuiResult<()> {
+        let moves = get_moves("1r6/P7/8/8/8/8/8/k1K5 w - - 0 1")?;
+        let promotions = find_moves(&moves, "a7", "a8");
+        assert_eq!(4, promotions.len());
+        assert_eq!(4, find_moves(&moves, "a7", "b8").len());
+        assert!(promotions
+            .iter()
+            .all(|m| m.promotion.is_some_and(|p| p.is_white())));
+
+        let moves = get_moves("4k3/8/8/8/8/8/6p1/K6R b - - 0 1")?;
+        assert_eq!(4, find_moves(&moves, "g2", "g1").len());
+        assert_eq!(4, find_moves(&moves, "g2", "h1").len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_moves_apply() -> ChuiResult<()> {
+        let board = Board::from_fen(
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        )?;
+
+        for chess_move in board.legal_moves(Color::White) {
+            let mut copy = board;
+            copy.apply_move(&chess_move)?;
+            assert!(!copy.is_in_check(Color::White));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_move_leaving_king_in_check() -> ChuiResult<()> {
+        let board = Board::from_fen("4k3/8/8/8/4r3/8/4N3/4K3 w - - 0 1")?;
+        let mut chess_move = ChessMove::new(Color::White);
+        chess_move.set_from_coord(Coord::try_from("e2").ok());
+        chess_move.set_to_coord(Coord::try_from("c3").ok());
+        assert!(matches!(
+            chess_move.validate_move_for_board(&board),
+            Err(ChuiError::InvalidMove(_))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,157 @@
+//! Test Standard Algebraic Notation (SAN) move generation.
+
+use crate::prelude::*;
+
+pub mod san {
+    use super::*;
+
+    /// Get the SAN of the legal move given in coordinate notation (e.g., `e7e8q`).
+    fn get_san(fen: &str, coordinate_move: &str) -> ChuiResult<String> {
+        let board = Board::from_fen(fen)?;
+        let to_move = if fen.split(' ').nth(1) == Some("w") {
+            Color::White
+        } else {
+            Color::Black
+        };
+        let chess_move = board
+            .legal_moves(to_move)
+            .into_iter()
+            .find(|m| m.get_coordinate_text() == coordinate_move)
+            .ok_or_else(|| ChuiError::InvalidMove(coordinate_move.to_string()))?;
+        chess_move.to_san(&board)
+    }
+
+    #[test]
+    fn test_pawn_and_piece_moves() -> ChuiResult<()> {
+        let fen = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+        assert_eq!("e4", get_san(fen, "e2e4")?);
+        assert_eq!("Nf3", get_san(fen, "g1f3")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_captures() -> ChuiResult<()> {
+        let fen = "rnbqkbnr/ppp1pppp/8/3p4/4P3/2N5/PPPP1PPP/R1BQKBNR w KQkq - 0 2";
+        assert_eq!("exd5", get_san(fen, "e4d5")?);
+        assert_eq!("Nxd5", get_san(fen, "c3d5")?);
+        // En passant.
+        assert_eq!(
+            "exd6",
+            get_san("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1", "e5d6")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_disambiguation() -> ChuiResult<()> {
+        // By file.
+        assert_eq!("Nbd2", get_san("4k3/8/8/8/8/8/8/1N3N1K w - - 0 1", "b1d2")?);
+        // By rank.
+        assert_eq!("R1a3", get_san("4k3/8/8/R7/8/8/8/R3K3 w - - 0 1", "a1a3")?);
+        assert_eq!("R5a3", get_san("4k3/8/8/R7/8/8/8/R3K3 w - - 0 1", "a5a3")?);
+        // By file and rank.
+        let fen = "8/8/1k6/8/4Q2Q/8/8/K6Q w - - 0 1";
+        assert_eq!("Qh4e1", get_san(fen, "h4e1")?);
+        assert_eq!("Qee1", get_san(fen, "e4e1")?);
+        assert_eq!("Q1e1", get_san(fen, "h1e1")?);
+        // A pinned Knight cannot move, so the other Knight needs no disambiguation.
+        assert_eq!(
+            "Ne2",
+            get_san("4k3/8/8/b7/8/2N5/8/4K1N1 w - - 0 1", "g1e2")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_promotion() -> ChuiResult<()> {
+        let fen = "7k/4P3/8/8/8/8/8/4K3 w - - 0 1";
+        assert_eq!("e8=Q+", get_san(fen, "e7e8q")?);
+        assert_eq!("e8=R+", get_san(fen, "e7e8r")?);
+        assert_eq!("e8=N", get_san(fen, "e7e8n")?);
+        assert_eq!(
+            "exd1=Q+",
+            get_san("4k3/8/8/8/8/8/4p3/3RK3 b - - 0 1", "e2d1q")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_castling() -> ChuiResult<()> {
+        let fen = "r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1";
+        assert_eq!("O-O", get_san(fen, "e1g1")?);
+        assert_eq!("O-O-O", get_san(fen, "e1c1")?);
+        assert_eq!(
+            "O-O-O+",
+            get_san("r3k2r/8/8/8/8/8/8/3K3R b kq - 0 1", "e8c8")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_and_mate() -> ChuiResult<()> {
+        assert_eq!(
+            "Ra8#",
+            get_san("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1", "a1a8")?
+        );
+        assert_eq!("Ra7", get_san("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1", "a1a7")?);
+        assert_eq!(
+            "Rxf7+",
+            get_san("5k2/5p2/8/8/8/8/8/5RK1 w - - 0 1", "f1f7")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_chess960_castling() -> ChuiResult<()> {
+        // The King on b1 castles onto its Rook on a1.
+        let board = Board::from_chess960_fen("rk5r/8/8/8/8/8/8/RK5R w HAha - 0 1")?;
+        let san: Vec<String> = board
+            .legal_moves(Color::White)
+            .into_iter()
+            .filter(|m| m.is_castling())
+            .map(|m| m.to_san(&board))
+            .collect::<ChuiResult<_>>()?;
+        assert!(san.contains(&"O-O".to_string()));
+        assert!(san.contains(&"O-O-O".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_figurine_notation() -> ChuiResult<()> {
+        let board = Board::from_fen("3qk3/8/8/8/8/8/8/3QK1N1 w - - 0 1")?;
+        let chess_move = board
+            .legal_moves(Color::White)
+            .into_iter()
+            .find(|m| m.get_coordinate_text() == "g1f3")
+            .ok_or_else(|| ChuiError::InvalidMove("g1f3".to_string()))?;
+        assert_eq!("♘f3", chess_move.to_fan(&board)?);
+
+        assert_eq!("♕xd7#", ChessMove::san_to_fan("Qxd7#", Color::White));
+        assert_eq!("exd8=♛+", ChessMove::san_to_fan("exd8=Q+", Color::Black));
+        assert_eq!("O-O", ChessMove::san_to_fan("O-O", Color::White));
+        assert_eq!("Qxd7#", ChessMove::fan_to_san("♕xd7#"));
+        assert_eq!("Nf6", ChessMove::fan_to_san("♞f6"));
+        assert_eq!("e4", ChessMove::fan_to_san("♙e4"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_figurines() -> ChuiResult<()> {
+        let mut game = Game::new(
+            Player::new(Color::White, None, None, None),
+            Player::new(Color::Black, None, None, None),
+            ParserEngine::Algebraic,
+        )?;
+
+        for the_move in ["e4", "♟e5", "♗c4", "♞c6", "♕h5", "♘f6", "♛xf7#"] {
+            let chess_move = game.parse(the_move.to_string(), game.to_move)?;
+            game.set_current_move(Some(chess_move));
+            game.apply_move()?;
+        }
+        assert_eq!(
+            vec!["e4", "e5", "♗c4", "♞c6", "♕h5", "♞f6", "♕xf7#"],
+            game.get_fan_move_list()
+        );
+        Ok(())
+    }
+}
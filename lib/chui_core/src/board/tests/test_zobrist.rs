@@ -0,0 +1,99 @@
+//! Test Zobrist hashing of the board.
+
+use crate::prelude::*;
+
+pub mod zobrist {
+    use super::*;
+
+    fn hash(fen: &str) -> ChuiResult<u64> {
+        Ok(Board::from_fen(fen)?.hash())
+    }
+
+    #[test]
+    fn test_start_position() -> ChuiResult<()> {
+        assert_eq!(
+            Board::default().hash(),
+            hash("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_clocks_are_ignored() -> ChuiResult<()> {
+        assert_eq!(
+            hash("4k3/8/8/8/8/8/8/4K3 w - - 0 1")?,
+            hash("4k3/8/8/8/8/8/8/4K3 w - - 12 40")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_side_to_move() -> ChuiResult<()> {
+        assert_ne!(
+            hash("4k3/8/8/8/8/8/8/4K3 w - - 0 1")?,
+            hash("4k3/8/8/8/8/8/8/4K3 b - - 0 1")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_castling_rights() -> ChuiResult<()> {
+        let hashes = [
+            hash("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1")?,
+            hash("r3k2r/8/8/8/8/8/8/R3K2R w Qkq - 0 1")?,
+            hash("r3k2r/8/8/8/8/8/8/R3K2R w Kkq - 0 1")?,
+            hash("r3k2r/8/8/8/8/8/8/R3K2R w KQq - 0 1")?,
+            hash("r3k2r/8/8/8/8/8/8/R3K2R w KQk - 0 1")?,
+            hash("r3k2r/8/8/8/8/8/8/R3K2R w - - 0 1")?,
+        ];
+
+        for (idx, a) in hashes.iter().enumerate() {
+            for b in hashes.iter().skip(idx + 1) {
+                assert_ne!(a, b);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_en_passant() -> ChuiResult<()> {
+        // No pawn can capture en passant, so the square does not change the position.
+        assert_eq!(
+            hash("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1")?,
+            hash("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1")?
+        );
+
+        // The pawn on e5 can capture en passant on f6.
+        assert_ne!(
+            hash("rnbqkbnr/ppppp1pp/8/4Pp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3")?,
+            hash("rnbqkbnr/ppppp1pp/8/4Pp2/8/8/PPPP1PPP/RNBQKBNR w KQkq - 0 3")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_incremental_hash() -> ChuiResult<()> {
+        for fen in [
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+            "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+        ] {
+            let board = Board::from_fen(fen)?;
+            assert_eq!(
+                board.get_position().get_hash(),
+                board.get_position().compute_hash()
+            );
+
+            for chess_move in board.legal_moves(Color::White) {
+                let mut after = board;
+                after.apply_move(&chess_move)?;
+                assert_eq!(
+                    after.get_position().get_hash(),
+                    after.get_position().compute_hash()
+                );
+                assert_ne!(after.hash(), board.hash());
+            }
+        }
+        Ok(())
+    }
+}
@@ -10,6 +10,7 @@ use crate::prelude::{coord::*, *};
 
 /// Represents the type of castling to be performed.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Castling {
     /// Castling King side.
     King,
@@ -20,6 +21,7 @@ pub enum Castling {
 
 /// Represents the type of check that is flagged.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Check {
     /// Check.
     Check,
@@ -30,6 +32,7 @@ pub enum Check {
 
 /// Represents the type of move to be performed.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MoveType {
     /// This move is a pawn move.
     PawnMove,
@@ -49,6 +52,7 @@ pub enum MoveType {
 
 /// Represents a chess move.
 #[derive(PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChessMove {
     /// The color to move.
     pub to_move: Color,
@@ -57,9 +61,17 @@ pub struct ChessMove {
     pub from_coord: Option<Coord>,
 
     /// Represents the from move's file index (0..=7).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serialization::option_non_max_u8")
+    )]
     pub from_coord_file: Option<NonMaxU8>,
 
     /// Represents the from move's rank index (0..=7).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serialization::option_non_max_u8")
+    )]
     pub from_coord_rank: Option<NonMaxU8>,
 
     /// The chess piece to move.
@@ -69,9 +81,17 @@ pub struct ChessMove {
     pub to_coord: Option<Coord>,
 
     /// Represents the to move's file index (0..=7).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serialization::option_non_max_u8")
+    )]
     pub to_coord_file: Option<NonMaxU8>,
 
     /// Represents the to move's rank index (0..=7).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serialization::option_non_max_u8")
+    )]
     pub to_coord_rank: Option<NonMaxU8>,
 
     /// The chess piece to capture.
@@ -99,7 +119,8 @@ pub struct ChessMove {
     /// TODO: Is this necessary?
     pub is_parsed: bool,
 
-    /// Any validation errors.
+    /// Any validation errors. Not serialized.
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub validation_errors: Vec<ChuiError>,
 }
 
@@ -117,16 +138,12 @@ impl fmt::Debug for ChessMove {
             errors = String::from("[]");
         }
         // Format `from_coord` and `to_coord`.
-        let from_coord: String = if self.from_coord.is_some() {
-            format!("{}", self.from_coord.unwrap())
-        } else {
-            "<None>".to_string()
-        };
-        let to_coord: String = if self.to_coord.is_some() {
-            format!("{}", self.to_coord.unwrap())
-        } else {
-            "<None>".to_string()
-        };
+        let from_coord: String = self
+            .from_coord
+            .map_or_else(|| "<None>".to_string(), |coord| format!("{}", coord));
+        let to_coord: String = self
+            .to_coord
+            .map_or_else(|| "<None>".to_string(), |coord| format!("{}", coord));
         // Format output.
         let output: String = format!(
             "{{
@@ -405,6 +422,183 @@ impl ChessMove {
         move_text
     }
 
+    /// Return the move as the "from" and "to" squares followed by any promotion piece in
+    /// lowercase (e.g., `e2e4` or `e7e8q`). Returns an empty `String` if either square is
+    /// not set.
+    pub fn get_coordinate_text(&self) -> String {
+        match (self.from_coord, self.to_coord) {
+            (Some(from_coord), Some(to_coord)) => format!(
+                "{}{}{}",
+                from_coord,
+                to_coord,
+                self.promotion
+                    .map_or_else(String::new, |piece| piece.repr().0.to_lowercase())
+            ),
+            _ => String::new(),
+        }
+    }
+
+    /// Return the move in Standard Algebraic Notation (SAN), as required by the PGN
+    /// standard, given the `board` the move is played on (e.g., `Nbd7`, `exd6`, `e8=Q+` or
+    /// `O-O-O#`). The moving piece is disambiguated by its file, otherwise by its rank,
+    /// otherwise by both, only when another legal move of the same kind of piece reaches
+    /// the same square.
+    ///
+    /// # Errors
+    ///
+    /// Errors when the move is not valid for the board. See `validate_move_for_board()`.
+    pub fn to_san(&self, board: &Board) -> ChuiResult<String> {
+        let mut chess_move: ChessMove = self.clone();
+        chess_move.validate_move_for_board(board)?;
+
+        let from_piece: Piece = chess_move
+            .from_piece
+            .expect("`from_piece` was validated above");
+        let from_coord: Coord = chess_move
+            .from_coord
+            .expect("`from_coord` was validated above");
+        let to_coord: Coord = chess_move.to_coord.expect("`to_coord` was validated above");
+        let capture: &str = if chess_move.to_piece.is_some() {
+            "x"
+        } else {
+            ""
+        };
+
+        let mut san: String = match chess_move.castling {
+            Some(Castling::King) => "O-O".to_string(),
+            Some(Castling::Queen) => "O-O-O".to_string(),
+            None if from_piece.is_pawn() => {
+                let file: String = if capture.is_empty() {
+                    String::new()
+                } else {
+                    from_coord.to_char_u8_coord().0.to_string()
+                };
+                let promotion: String = chess_move.promotion.map_or_else(String::new, |piece| {
+                    format!("={}", piece.repr().0.to_uppercase())
+                });
+
+                format!("{}{}{}{}", file, capture, to_coord, promotion)
+            }
+            None => format!(
+                "{}{}{}{}",
+                from_piece.repr().0.to_uppercase(),
+                ChessMove::get_san_disambiguation(board, from_piece, to_coord),
+                capture,
+                to_coord
+            ),
+        };
+
+        match chess_move.get_check(board)? {
+            Some(Check::Check) => san.push('+'),
+            Some(Check::Mate) => san.push('#'),
+            None => (),
+        }
+
+        Ok(san)
+    }
+
+    /// Return the move in Standard Algebraic Notation with the piece letters of `locale`
+    /// (e.g., `Sf3` in German). See `to_san()`.
+    ///
+    /// # Errors
+    ///
+    /// Errors when the move is not valid for the board.
+    pub fn to_san_for_locale(&self, board: &Board, locale: Locale) -> ChuiResult<String> {
+        Locale::English.translate(&self.to_san(board)?, locale)
+    }
+
+    /// Return the move in Figurine Algebraic Notation, with the figurines of the color to
+    /// move (e.g., `♘f3` or `♕xd7#`). See `to_san()`.
+    ///
+    /// # Errors
+    ///
+    /// Errors when the move is not valid for the board.
+    pub fn to_fan(&self, board: &Board) -> ChuiResult<String> {
+        Ok(ChessMove::san_to_fan(&self.to_san(board)?, self.to_move))
+    }
+
+    /// Replace the English piece letters of a move in algebraic notation with the
+    /// figurines of `color` (e.g., `Nf3` is `♘f3` for White and `♞f3` for Black).
+    pub fn san_to_fan(san: &str, color: Color) -> String {
+        san.chars()
+            .map(|c| {
+                Locale::English
+                    .get_piece_kind(c)
+                    .map_or(c, |kind| kind.get_figurine(color))
+            })
+            .collect()
+    }
+
+    /// Replace the figurines of a move in Figurine Algebraic Notation, of either color,
+    /// with English piece letters (e.g., `♘f3` and `♞f3` are `Nf3`). Pawns have no letter,
+    /// so Pawn figurines are dropped (e.g., `♙e4` is `e4`).
+    pub fn fan_to_san(fan: &str) -> String {
+        fan.chars()
+            .filter_map(|c| match Piece::try_from(c) {
+                Ok(piece) if !c.is_ascii() => Locale::English.get_piece_letter(piece.get_kind()),
+                _ => Some(c),
+            })
+            .collect()
+    }
+
+    /// Play the move on a copy of `board` to find out whether it checks or mates the
+    /// opponent. The move must be validated for the board first (see
+    /// `validate_move_for_board()`).
+    ///
+    /// # Errors
+    ///
+    /// Errors when the move cannot be applied to the board. See `Board::apply_move()`.
+    pub fn get_check(&self, board: &Board) -> ChuiResult<Option<Check>> {
+        let mut next_board: Board = *board;
+        let opponent: Color = self.to_move.opposite();
+        next_board.apply_move(self)?;
+
+        if !next_board.is_in_check(opponent) {
+            Ok(None)
+        } else if next_board.legal_moves(opponent).is_empty() {
+            Ok(Some(Check::Mate))
+        } else {
+            Ok(Some(Check::Check))
+        }
+    }
+
+    /// Return the shortest SAN prefix of the square of `piece` (its file, rank, or both)
+    /// that tells it apart from the other pieces of the same kind that can legally move to
+    /// `to_coord`. Returns an empty `String` when no other such piece exists.
+    fn get_san_disambiguation(board: &Board, piece: Piece, to_coord: Coord) -> String {
+        let from_coord: Coord = piece.get_coord();
+        let others: Vec<Coord> = board
+            .legal_moves(piece.get_color())
+            .into_iter()
+            .filter(|chess_move| {
+                chess_move.castling.is_none()
+                    && chess_move.to_coord == Some(to_coord)
+                    && chess_move.from_coord != Some(from_coord)
+                    && chess_move
+                        .from_piece
+                        .is_some_and(|other| other.is_same_kind(piece))
+            })
+            .filter_map(|chess_move| chess_move.from_coord)
+            .collect();
+        let (file, rank) = from_coord.to_char_u8_coord();
+
+        if others.is_empty() {
+            String::new()
+        } else if others
+            .iter()
+            .all(|coord| coord.get_file() != from_coord.get_file())
+        {
+            file.to_string()
+        } else if others
+            .iter()
+            .all(|coord| coord.get_rank() != from_coord.get_rank())
+        {
+            rank.to_string()
+        } else {
+            format!("{}{}", file, rank)
+        }
+    }
+
     #[allow(clippy::unused_self)]
     /// Match the given file (`char`) to its index (`u8`).
     const fn match_file_to_index(&self, file: char) -> Option<u8> {
@@ -702,12 +896,8 @@ impl ChessMove {
 
         // Get the two possible Pawns that would enable en passant.
         let (ep_pawn1, ep_pawn2) = (
-            board
-                .get_position()
-                .get_piece(Coord::try_from((to_coord.get_file() - 1, to_coord.get_rank())).ok()),
-            board
-                .get_position()
-                .get_piece(Coord::try_from((to_coord.get_file() + 1, to_coord.get_rank())).ok()),
+            board.get_position().get_piece(to_coord.offset(-1, 0)),
+            board.get_position().get_piece(to_coord.offset(1, 0)),
         );
 
         // If either piece is a pawn, then this is a valid en passant situation.
@@ -744,31 +934,12 @@ impl ChessMove {
     ///
     /// # Errors
     ///
-    /// Returns a [`ChuiError`] result when `self.move_type` is None.
+    /// Returns a [`ChuiError`] result when the move could not be validated for the `Game`'s
+    /// [`Board`]. See `validate_move_for_board()`.
     pub fn process_move(&mut self, game: &mut Game) -> ChuiResult<()> {
+        self.to_move = game.to_move;
         self.set_color(game.to_move);
-        match self.move_type {
-            Some(MoveType::PawnMove) => {
-                println!("Move Type is Pawn Move");
-            }
-            Some(MoveType::PawnCapture) => {
-                println!("Move Type is Pawn Capture.");
-            }
-            Some(MoveType::PieceMove) => {
-                println!("Move Type is Piece Move.");
-            }
-            Some(MoveType::PieceCapture) => {
-                println!("Move Type is Piece Capture.");
-            }
-            Some(MoveType::Castle) => {
-                println!("Move Type is Castle.");
-            }
-            None => {
-                println!("Move Type is Invalid.");
-            }
-        }
-
-        Ok(())
+        self.validate_move_for_board(&game.board)
     }
 
     /// Validate `to_coord`.
@@ -864,86 +1035,377 @@ impl ChessMove {
                 .collect::<Vec<&Coord>>()
                 .is_empty()
             {
-                return Err(ChuiError::InvalidMove(format!(
-                    "The piece `{}` cannot move to or capture the square `{}`",
-                    from_piece,
-                    self.to_coord.unwrap()
-                )));
+                let to_coord: Coord = self.to_coord.unwrap();
+
+                return Err(ChuiError::InvalidMove(
+                    match board
+                        .get_position()
+                        .get_piece(Some(to_coord))
+                        .filter(|piece| piece.is_same_color(from_piece))
+                    {
+                        Some(piece) => format!(
+                            "The {} on `{}` cannot move to `{}`, which is occupied by the {}",
+                            from_piece.get_text(),
+                            from_piece.get_coord(),
+                            to_coord,
+                            piece.get_text()
+                        ),
+                        None => format!(
+                            "The {} on `{}` cannot move to or capture on `{}`",
+                            from_piece.get_text(),
+                            from_piece.get_coord(),
+                            to_coord
+                        ),
+                    },
+                ));
             }
         }
         Ok(())
     }
 
+    /// Validate that the piece this move captures is of the `captured` kind (`None` when
+    /// nothing is captured) and that the move is an en passant capture only if
+    /// `en_passant` is `true`. Used by parsers whose notation names the captured piece
+    /// (e.g., `SmithParser`). The move must be validated for the board first (see
+    /// `ChessMove::validate_move_for_board()`).
+    ///
+    /// # Errors
+    ///
+    /// When the captured piece or en passant does not match the move.
+    pub fn validate_captured_piece(
+        &self,
+        captured: Option<PieceKind>,
+        en_passant: bool,
+    ) -> ChuiResult<()> {
+        let input_move: &str = self.input_move.trim();
+        let to_coord: Coord = self.to_coord.ok_or_else(|| {
+            ChuiError::InvalidMove(format!("`{}` has no \"to\" square", input_move))
+        })?;
+
+        if self.to_piece.map(|piece| piece.get_kind()) != captured {
+            return Err(ChuiError::InvalidCapture(match (captured, self.to_piece) {
+                (Some(kind), Some(piece)) => format!(
+                    "`{}` captures a {:?}, but the piece on `{}` is a {:?}",
+                    input_move,
+                    kind,
+                    piece.get_coord(),
+                    piece.get_kind()
+                ),
+                (Some(kind), None) => format!(
+                    "`{}` captures a {:?}, but there is no piece to capture on `{}`",
+                    input_move, kind, to_coord
+                ),
+                _ => format!(
+                    "`{}` does not capture, but there is a piece to capture on `{}`",
+                    input_move, to_coord
+                ),
+            }));
+        }
+
+        let is_en_passant: bool = self
+            .to_piece
+            .is_some_and(|piece| piece.get_coord() != to_coord);
+
+        if is_en_passant != en_passant {
+            return Err(ChuiError::InvalidMove(format!(
+                "`{}` {} an en passant capture",
+                input_move,
+                if is_en_passant { "is" } else { "is not" }
+            )));
+        }
+
+        Ok(())
+    }
+
     /// Validate the chess move for the given `Position`.
     ///
     /// Depending on the parser, certain coordinates and attributes will
     /// bet set. Do not assume that this chess move has been previously modified by a `Parser`.
     /// For example, with an `ICCFParser`, the `from_coord` and `to_coord` will be
     /// set, but for an `AlgebraicParser` the move "e4" won't have `from_coord` set,
-    /// only the `to_coord` attribute will be set properly, as well as the
-    /// `move_type` attribute.
+    /// only the `to_coord_file` and `to_coord_rank` attributes will be set properly, as well as
+    /// the `move_type` attribute.
+    ///
+    /// On success the move is fully resolved against the board: `from_coord`, `from_piece`,
+    /// `to_coord`, `to_piece` (the captured piece, if any), `move_type`, `castling`,
+    /// `promotion` and `en_passant` are normalized so that the move can be applied with
+    /// `Board::apply_move()`.
     ///
     /// # Errors
     ///
     /// When an invalid move is found.
-    ///
-    /// # Panics
-    ///
-    /// Should not panic.
     pub fn validate_move_for_board(&mut self, board: &Board) -> ChuiResult<()> {
-        let _position = board.get_position();
+        let position = board.get_position();
 
-        // 1) let's validate that this object instance has the necessary information to
-        // determine that a move can at all be unambiguously played given the `Board`'s
-        // `Position`.
+        // 1) Castling moves (e.g., `0-0`) only name the side of the board, so find the King
+        // and the square it lands on.
+        if self.is_castling() && self.from_coord.is_none() {
+            self.resolve_castling(board)?;
+        }
+
+        // 2) Parsers that record the file and rank separately (e.g., `AlgebraicParser`) leave
+        // `to_coord` unset.
+        if self.to_coord.is_none() {
+            if let (Some(file), Some(rank)) = (self.to_coord_file, self.to_coord_rank) {
+                self.to_coord = Coord::try_from((file, rank)).ok();
+            }
+        }
+
+        // 3) Validate that this object instance has the necessary information to determine
+        // that a move can at all be unambiguously played given the `Board`'s `Position`.
         self.validate_to_coord()?;
         self.validate_other_attributes()?;
+        let mut to_coord: Coord = self.to_coord.expect("`to_coord` was validated above");
+
+        // 4) Find the piece to move, either from the given `from_coord` or by searching the
+        // board for the only piece that can reach `to_coord`.
+        let from_piece: Piece = match self.from_coord {
+            Some(from_coord) => self.find_piece_on_coord(board, from_coord)?,
+            None => self.find_piece_for_coord(board, to_coord)?,
+        };
+        let from_coord: Coord = from_piece.get_coord();
+        self.set_from_coord(Some(from_coord));
+        self.from_piece = Some(from_piece);
+
+        // A castling move may be given either as the King moving onto its Rook or as the
+        // King moving to the square it castles to. Use the one the board expects.
+        let castling: Option<Castling> = board.get_castling(from_piece, to_coord);
+
+        if let Some(castling) = castling {
+            to_coord = board.get_castling_coord(self.to_move, castling)?;
+            self.set_to_coord(Some(to_coord));
+        }
 
-        // 2) If the `from_coord` and `to_coord` are set, we can process the board position and
-        // normalize any values improperly set by any parser or via manual entry. If instead the
-        // `from_piece` is set and the `move_type` is valid, we can determine if there is an
-        // unambiguous `from_coord` for which to move the piece from.
-        //
-        // TODO: `from_piece` has its coordinate set. Program logic around not caring what a
-        //       `Piece`'s coordinates are.
         self.validate_opposite_color()?;
         self.validate_move_or_capture(board)?;
 
-        // // Validate that the move is even possible.
-        // if let Err(e) = self.from_coord.validate_possible_move(self.to_coord) {
-        //     self.validation_errors.push(e.clone());
-        //     return Err(e); // This error will block validation, return this error.
-        // }
-
-        // // This is usually true if the input parser is an `ICCFParser`.
-        // if self.from_coord != Coord::zero() && self.to_coord != Coord::zero() {
-        //     if let Some(piece_to_move) = position.get_piece(Some(self.from_coord)) {
-        //         self.set_piece(piece_to_move);
-
-        //         if let Some(opponent_piece) = position.get_piece(Some(self.to_coord)) {
-        //             let move_coords = piece_to_move.get_move_coords(board, None);
-        //             if move_coords.contains(&self.to_coord) {
-        //                 self.set_capture()?;
-        //             }
-
-        //             return Err(ChuiError::InvalidCapture(format!(
-        //                 "Cannot capture piece from {} to {}", self.from_coord, self.to_coord
-        //             )))
-        //         }
-
-        //         self.set_en_passant(board);
-        //         self.set_check();
-
-        //         return Ok(());
-        //     };
-        // }
-
-        // if self.from_coord == self.to_coord {
-        //     return self
-        //         .invalidate("The move's from coordinate cannot be the same as its to coordinate.");
-        // }
-
-        self.invalidate("Could not validate move")
+        if !board.is_king_safe_after_move(from_piece, to_coord) {
+            return Err(ChuiError::InvalidMove(format!(
+                "Moving the {} to `{}` would leave the {} King in check",
+                from_piece.get_text(),
+                to_coord,
+                self.to_move
+            )));
+        }
+
+        // 5) Find the piece to capture. An en passant capture takes the Pawn beside the
+        // moving Pawn rather than a piece on `to_coord`.
+        let mut to_piece: Option<Piece> = position
+            .get_piece(Some(to_coord))
+            .filter(|_| castling.is_none());
+        let is_en_passant = from_piece.is_pawn()
+            && from_coord.get_file() != to_coord.get_file()
+            && to_piece.is_none()
+            && board.get_en_passant_coord() == Some(to_coord);
+
+        if is_en_passant {
+            to_piece = position
+                .get_piece(Coord::new(to_coord.get_file(), from_coord.get_rank()).ok())
+                .filter(|piece| piece.is_pawn() && piece.is_opposite_color(self.to_move));
+        }
+
+        if to_piece.is_none() && (self.is_pawn_capture() || self.is_piece_capture()) {
+            return Err(ChuiError::InvalidCapture(format!(
+                "There is no piece to capture on `{}`",
+                to_coord
+            )));
+        }
+
+        self.to_piece = to_piece;
+
+        // 6) Normalize the move type and castling.
+        if castling.is_some() {
+            self.castling = castling;
+            self.set_move_type(MoveType::Castle);
+        } else {
+            self.castling = None;
+            self.set_move_type(match (from_piece.is_pawn(), to_piece.is_some()) {
+                (true, false) => MoveType::PawnMove,
+                (true, true) => MoveType::PawnCapture,
+                (false, false) => MoveType::PieceMove,
+                (false, true) => MoveType::PieceCapture,
+            });
+        }
+
+        // 7) Pawns reaching the last rank must promote, and nothing else may.
+        self.validate_promotion(from_piece, to_coord)?;
+
+        // 8) Record whether this move enables an en passant capture next turn.
+        self.en_passant = None;
+        if from_piece.is_pawn()
+            && (to_coord.get_rank() as i8 - from_coord.get_rank() as i8).abs() == 2
+        {
+            self.set_en_passant(board).ok();
+        }
+
+        self.from_coord_file = NonMaxU8::try_from(from_coord.get_file()).ok();
+        self.from_coord_rank = NonMaxU8::try_from(from_coord.get_rank()).ok();
+        self.to_coord_file = NonMaxU8::try_from(to_coord.get_file()).ok();
+        self.to_coord_rank = NonMaxU8::try_from(to_coord.get_rank()).ok();
+
+        Ok(())
+    }
+
+    /// Resolve the `from_coord`, `from_piece` and `to_coord` of a castling move from the
+    /// position of the King to move.
+    ///
+    /// # Errors
+    ///
+    /// When there is no King to castle.
+    fn resolve_castling(&mut self, board: &Board) -> ChuiResult<()> {
+        let king: Piece = board
+            .get_position()
+            .get_pieces(Piece::new(PieceKind::King, self.to_move, Coord::zero()))
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                ChuiError::InvalidMove(format!("There is no {} King to castle", self.to_move))
+            })?;
+
+        let castling: Castling = if self.is_castling_king() {
+            Castling::King
+        } else {
+            Castling::Queen
+        };
+
+        self.from_piece = Some(king);
+        self.from_coord = Some(king.get_coord());
+        self.to_coord = Some(board.get_castling_coord(self.to_move, castling)?);
+
+        Ok(())
+    }
+
+    /// Find the piece to move on the given `from_coord`.
+    ///
+    /// # Errors
+    ///
+    /// When there is no piece on `from_coord`, the piece is not the color to move, or the
+    /// piece is not the kind of piece given by the parser.
+    fn find_piece_on_coord(&self, board: &Board, from_coord: Coord) -> ChuiResult<Piece> {
+        let piece: Piece = board
+            .get_position()
+            .get_piece(Some(from_coord))
+            .ok_or_else(|| {
+                ChuiError::InvalidMove(format!("There is no piece on `{}` to move", from_coord))
+            })?;
+
+        if piece.is_opposite_color(self.to_move) {
+            return Err(ChuiError::InvalidMove(format!(
+                "The piece on `{}` does not belong to {:?}",
+                from_coord, self.to_move
+            )));
+        }
+
+        if let Some(from_piece) = self.from_piece {
+            if !piece.is_same_kind(from_piece) {
+                return Err(ChuiError::InvalidMove(format!(
+                    "The piece on `{}` is a {}, not a {}",
+                    from_coord,
+                    piece.get_text(),
+                    from_piece.get_text()
+                )));
+            }
+        }
+
+        Ok(piece)
+    }
+
+    /// Find the only piece of the kind given by the parser that can move to `to_coord`,
+    /// honoring any `from_coord_file` and `from_coord_rank` disambiguation.
+    ///
+    /// # Errors
+    ///
+    /// When no piece, or more than one piece, can move to `to_coord`.
+    fn find_piece_for_coord(&self, board: &Board, to_coord: Coord) -> ChuiResult<Piece> {
+        let from_piece: Piece = self.from_piece.ok_or_else(|| {
+            ChuiError::InvalidMove("The piece to move could not be determined".to_string())
+        })?;
+
+        let pieces: Vec<Piece> = board
+            .get_position()
+            .get_pieces(Piece::new(
+                from_piece.get_kind(),
+                self.to_move,
+                from_piece.get_coord(),
+            ))
+            .into_iter()
+            .filter(|piece| {
+                self.from_coord_file
+                    .map_or(true, |file| file.get() == piece.get_file())
+            })
+            .filter(|piece| {
+                self.from_coord_rank
+                    .map_or(true, |rank| rank.get() == piece.get_rank())
+            })
+            // A Pawn only changes files when capturing.
+            .filter(|piece| {
+                !piece.is_pawn()
+                    || piece.get_file() == to_coord.get_file()
+                    || self.is_pawn_capture()
+            })
+            .filter(|piece| piece.get_move_coords(board, None).contains(&to_coord))
+            // A pinned piece does not need to be disambiguated.
+            .filter(|piece| board.is_king_safe_after_move(*piece, to_coord))
+            .collect();
+
+        match pieces.len() {
+            1 => Ok(pieces[0]),
+            0 => Err(ChuiError::InvalidMove(format!(
+                "No {:?} {:?} can move to `{}`",
+                self.to_move,
+                from_piece.get_kind(),
+                to_coord
+            ))),
+            _ => Err(ChuiError::InvalidMove(format!(
+                "Ambiguous move: the {:?} {:?}s on {} can all move to `{}`; name the file or \
+                rank of the one to move",
+                self.to_move,
+                from_piece.get_kind(),
+                pieces
+                    .iter()
+                    .map(|piece| format!("`{}`", piece.get_coord()))
+                    .collect::<Vec<String>>()
+                    .join(", "),
+                to_coord
+            ))),
+        }
+    }
+
+    /// Validate the promotion piece, if any. A Pawn moving to the last rank must promote to
+    /// a Queen, Rook, Bishop or Knight, and no other move may promote.
+    ///
+    /// # Errors
+    ///
+    /// When a promotion is missing, invalid, or not allowed.
+    fn validate_promotion(&mut self, from_piece: Piece, to_coord: Coord) -> ChuiResult<()> {
+        let last_rank: u8 = match self.to_move {
+            Color::White => 7,
+            Color::Black => 0,
+        };
+
+        if !(from_piece.is_pawn() && to_coord.get_rank() == last_rank) {
+            if self.promotion.is_some() {
+                return Err(ChuiError::InvalidMove(
+                    "Only a Pawn moving to the last rank can promote".to_string(),
+                ));
+            }
+
+            return Ok(());
+        }
+
+        match self.promotion {
+            Some(piece) if piece.is_pawn() || piece.is_king() => Err(ChuiError::InvalidPiece(
+                format!("A Pawn cannot promote to a {:?}", piece.get_kind()),
+            )),
+            Some(piece) => {
+                self.promotion = Some(Piece::new(piece.get_kind(), self.to_move, to_coord));
+                Ok(())
+            }
+            None => Err(ChuiError::InvalidMove(format!(
+                "The Pawn moving to `{}` must promote",
+                to_coord
+            ))),
+        }
     }
 
     //
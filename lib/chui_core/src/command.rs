@@ -4,7 +4,7 @@
 
 use std::collections::HashMap;
 
-use crate::Game;
+use crate::{Game, PieceStyle};
 
 /// The kind of command.
 #[non_exhaustive]
@@ -58,9 +58,18 @@ pub enum CommandKind {
     /// Switch to Smith Parser.
     SwitchToSmithParser,
 
+    /// Switch to UCI Parser.
+    SwitchToUciParser,
+
     /// Display the FEN layout of the board.
     DisplayFEN,
 
+    /// Display the game as PGN text.
+    DisplayPGN,
+
+    /// Display the EPD record of the position.
+    DisplayEPD,
+
     /// Display the list of moves.
     DisplayMoveList,
 
@@ -73,11 +82,32 @@ pub enum CommandKind {
     /// Black resigns.
     BlackResigns,
 
+    /// Claim a draw by threefold repetition or the fifty move rule.
+    ClaimDraw,
+
     /// Display the board for `White` after each move.
     DisplayForWhiteEachMove,
 
     /// Display the board for `Black` after each move.
     DisplayForBlackEachMove,
+
+    /// Count the leaf nodes of the legal move tree of the current position.
+    Perft,
+
+    /// Take back the last move.
+    Undo,
+
+    /// Play the last move taken back again.
+    Redo,
+
+    /// Toggle lenient move input.
+    ToggleLenient,
+
+    /// Switch the language of the piece letters.
+    SwitchLocale,
+
+    /// Toggle figurines for the board and the move list.
+    ToggleFigurines,
 }
 
 /// The context of the command.
@@ -246,11 +276,45 @@ impl Command {
                     description: "Black resigns".to_string(),
                     command_kind: CommandKind::BlackResigns,
                 },
+                CommandPart {
+                    commands: vec!["cd".to_string(), "claim draw".to_string()],
+                    description: "Claim a draw by threefold repetition or the fifty move rule"
+                        .to_string(),
+                    command_kind: CommandKind::ClaimDraw,
+                },
                 CommandPart {
                     commands: vec!["sw".to_string(), "switch parser".to_string()],
                     description: "Switch the current parser engine".to_string(),
                     command_kind: CommandKind::SwitchParser,
                 },
+                CommandPart {
+                    commands: vec!["lenient".to_string()],
+                    description: format!(
+                        "Toggle lenient move input (e.g., `nf3`, `0-0`, `Ng1-f3`), now {}",
+                        if game.lenient { "on" } else { "off" }
+                    ),
+                    command_kind: CommandKind::ToggleLenient,
+                },
+                CommandPart {
+                    commands: vec!["locale".to_string()],
+                    description: format!(
+                        "Switch the language of the piece letters (now {:?})",
+                        game.locale
+                    ),
+                    command_kind: CommandKind::SwitchLocale,
+                },
+                CommandPart {
+                    commands: vec!["fan".to_string()],
+                    description: format!(
+                        "Toggle figurines (e.g., `♘f3`) for the board and the move list, now {}",
+                        if game.piece_style == PieceStyle::Figurine {
+                            "on"
+                        } else {
+                            "off"
+                        }
+                    ),
+                    command_kind: CommandKind::ToggleFigurines,
+                },
                 CommandPart {
                     commands: vec![
                         "d".to_string(),
@@ -295,6 +359,31 @@ impl Command {
                     description: "Display the FEN layout of the board".to_string(),
                     command_kind: CommandKind::DisplayFEN,
                 },
+                CommandPart {
+                    commands: vec!["pgn".to_string()],
+                    description: "Display the game as PGN text".to_string(),
+                    command_kind: CommandKind::DisplayPGN,
+                },
+                CommandPart {
+                    commands: vec!["epd".to_string()],
+                    description: "Display the EPD record of the position".to_string(),
+                    command_kind: CommandKind::DisplayEPD,
+                },
+                CommandPart {
+                    commands: vec!["u".to_string(), "undo".to_string()],
+                    description: "Take back the last move".to_string(),
+                    command_kind: CommandKind::Undo,
+                },
+                CommandPart {
+                    commands: vec!["r".to_string(), "redo".to_string()],
+                    description: "Play the last move taken back again".to_string(),
+                    command_kind: CommandKind::Redo,
+                },
+                CommandPart {
+                    commands: vec!["perft".to_string()],
+                    description: "Count the legal move tree nodes to a depth (divide)".to_string(),
+                    command_kind: CommandKind::Perft,
+                },
                 CommandPart {
                     commands: vec![
                         "h".to_string(),
@@ -324,17 +413,17 @@ impl Command {
                 },
                 CommandPart {
                     commands: vec!["2".to_string(), "concise reversible".to_string()],
-                    description: "Concise Reversible Parser (Not Implemented)".to_string(),
+                    description: "Concise Reversible Parser".to_string(),
                     command_kind: CommandKind::SwitchToConciseReversibleParser,
                 },
                 CommandPart {
-                    commands: vec!["3".to_string(), "Coordinate".to_string()],
-                    description: "Coordinate Parser (Not Implemented)".to_string(),
+                    commands: vec!["3".to_string(), "coordinate".to_string()],
+                    description: "Coordinate Parser".to_string(),
                     command_kind: CommandKind::SwitchToCoordinateParser,
                 },
                 CommandPart {
                     commands: vec!["4".to_string(), "descriptive".to_string()],
-                    description: "Descriptive Parser (Not Implemented)".to_string(),
+                    description: "Descriptive Parser".to_string(),
                     command_kind: CommandKind::SwitchToDescriptiveParser,
                 },
                 CommandPart {
@@ -344,19 +433,24 @@ impl Command {
                 },
                 CommandPart {
                     commands: vec!["6".to_string(), "long algebraic".to_string()],
-                    description: "Long Algebraic Parser (Not Implemented)".to_string(),
+                    description: "Long Algebraic Parser".to_string(),
                     command_kind: CommandKind::SwitchToLongAlgebraicParser,
                 },
                 CommandPart {
                     commands: vec!["7".to_string(), "reversible algebraic".to_string()],
-                    description: "Reversible Algebraic Parser (Not Implemented)".to_string(),
+                    description: "Reversible Algebraic Parser".to_string(),
                     command_kind: CommandKind::SwitchToReversibleAlgebraicParser,
                 },
                 CommandPart {
                     commands: vec!["8".to_string(), "smith".to_string()],
-                    description: "Smith Parser (Not Implemented)".to_string(),
+                    description: "Smith Parser".to_string(),
                     command_kind: CommandKind::SwitchToSmithParser,
                 },
+                CommandPart {
+                    commands: vec!["9".to_string(), "uci".to_string()],
+                    description: "UCI Parser".to_string(),
+                    command_kind: CommandKind::SwitchToUciParser,
+                },
                 CommandPart {
                     commands: vec![
                         "h".to_string(),
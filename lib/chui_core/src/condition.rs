@@ -2,6 +2,7 @@
 
 /// The win condition.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WinCondition {
     /// Checkmate.
     #[default]
@@ -16,6 +17,7 @@ pub enum WinCondition {
 
 /// The draw condition.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DrawCondition {
     /// Both players agree to a draw.
     #[default]
@@ -41,8 +43,16 @@ pub enum DrawCondition {
     FifthRepetition,
 
     /// 50 moves have been made with no piece capture or pawn move.
+    ///
+    /// According to FIDE rules, a player must flag this condition.
     FiftyMoveRule,
 
+    /// 75 moves have been made with no piece capture or pawn move.
+    ///
+    /// According to FIDE rules, a game is automatically drawn after 75 moves have been made
+    /// by each player with no piece capture or pawn move, unless the last move checkmates.
+    SeventyFiveMoveRule,
+
     /// Both players have insufficient material to check mate. Do note that it is still possible
     /// to checkmate an opponent King with just a bishop or a knight provided that the opponent
     /// has a blocking piece to make this possible.
@@ -32,6 +32,24 @@ pub const STR_PROMOTION_PIECES: [&str; 8] = ["Q", "q", "R", "r", "B", "b", "N",
 /// An array of chessboard pieces in &str format 5 elements long.
 pub const CHAR_PROMOTION_PIECES: [char; 8] = ['Q', 'q', 'R', 'r', 'B', 'b', 'N', 'n'];
 
+/// The (file, rank) step for each of the four orthogonal directions.
+pub const ORTHOGONALS: [(i8, i8); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+
+/// The (file, rank) step for each of the four diagonal directions.
+pub const DIAGONALS: [(i8, i8); 4] = [(-1, 1), (1, 1), (1, -1), (-1, -1)];
+
+/// The (file, rank) offsets of each square a Knight can jump to.
+pub const KNIGHT_OFFSETS: [(i8, i8); 8] = [
+    (1, 2),
+    (2, 1),
+    (2, -1),
+    (1, -2),
+    (-1, -2),
+    (-2, -1),
+    (-2, 1),
+    (-1, 2),
+];
+
 /// Compile in the version of this crate.
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -134,7 +134,7 @@ impl Coord {
 
     /// Return true if the given coordinate is equal to this coordinate.
     pub fn is_eq(&self, coord: (char, u8)) -> bool {
-        Coord::try_from(coord).map_or(false, |new_coord| *self == new_coord)
+        Coord::try_from(coord) == Ok(*self)
     }
 
     /// Get a zero-based index in the range of (0..=63).
@@ -147,6 +147,19 @@ impl Coord {
         idx
     }
 
+    /// Return the [`Coord`] found by stepping `file_delta` files and `rank_delta` ranks away
+    /// from this coordinate, or `None` if that step leaves the board.
+    pub fn offset(&self, file_delta: i8, rank_delta: i8) -> Option<Coord> {
+        let file = self.get_file() as i8 + file_delta;
+        let rank = self.get_rank() as i8 + rank_delta;
+
+        if !(0..8).contains(&file) || !(0..8).contains(&rank) {
+            return None;
+        }
+
+        Coord::new(file as u8, rank as u8).ok()
+    }
+
     /// Check to see if the from coord and to coord are on the same orthogonal.
     pub const fn is_orthogonal(&self, to_coord: Coord) -> bool {
         self.get_file() == to_coord.get_file() || self.get_rank() == to_coord.get_rank()
@@ -340,37 +353,37 @@ impl From<Coord> for u64 {
 
 impl PartialEq<(NonMaxU8, NonMaxU8)> for Coord {
     fn eq(&self, coord: &(NonMaxU8, NonMaxU8)) -> bool {
-        Coord::try_from(*coord).map_or(false, |new_coord| *self == new_coord)
+        Coord::try_from(*coord) == Ok(*self)
     }
 }
 
 impl PartialEq<(char, u8)> for Coord {
     fn eq(&self, coord: &(char, u8)) -> bool {
-        Coord::try_from(*coord).map_or(false, |new_coord| *self == new_coord)
+        Coord::try_from(*coord) == Ok(*self)
     }
 }
 
 impl PartialEq<(&str, u8)> for Coord {
     fn eq(&self, coord: &(&str, u8)) -> bool {
-        Coord::try_from(*coord).map_or(false, |new_coord| *self == new_coord)
+        Coord::try_from(*coord) == Ok(*self)
     }
 }
 
 impl PartialEq<&str> for Coord {
     fn eq(&self, coord: &&str) -> bool {
-        Coord::try_from(*coord).map_or(false, |new_coord| *self == new_coord)
+        Coord::try_from(*coord) == Ok(*self)
     }
 }
 
 impl PartialEq<(&str, &str)> for Coord {
     fn eq(&self, coord: &(&str, &str)) -> bool {
-        Coord::try_from(*coord).map_or(false, |new_coord| *self == new_coord)
+        Coord::try_from(*coord) == Ok(*self)
     }
 }
 
 impl PartialEq<(char, char)> for Coord {
     fn eq(&self, coord: &(char, char)) -> bool {
-        Coord::try_from(*coord).map_or(false, |new_coord| *self == new_coord)
+        Coord::try_from(*coord) == Ok(*self)
     }
 }
 
@@ -0,0 +1,513 @@
+//! EPD notation module.
+//!
+//! EPD stands for Extended Position Description.
+
+use std::convert::TryFrom;
+use std::fmt;
+
+use crate::prelude::*;
+
+/// An operation of an EPD record: an opcode and its operands (e.g., `bm Nf3;`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EpdOperation {
+    /// `bm`: the best moves of the position, in Standard Algebraic Notation.
+    BestMoves(Vec<String>),
+
+    /// `am`: the moves to avoid in the position, in Standard Algebraic Notation.
+    AvoidMoves(Vec<String>),
+
+    /// `id`: the identifier of the position (e.g., `"WAC.001"`).
+    Id(String),
+
+    /// `c0` to `c9`: a comment, numbered 0 to 9.
+    Comment(u8, String),
+
+    /// `acd`: the depth of the analysis, in plies.
+    AnalysisCountDepth(u32),
+
+    /// `ce`: the evaluation of the position, in centipawns, for the side to move.
+    CentipawnEvaluation(i32),
+
+    /// `pv`: the predicted variation, in Standard Algebraic Notation.
+    PredictedVariation(Vec<String>),
+
+    /// `hmvc`: the half-move clock.
+    HalfMoveClock(usize),
+
+    /// `fmvn`: the full-move number.
+    FullMoveNumber(usize),
+
+    /// Any other opcode, with its operands.
+    Other(String, Vec<String>),
+}
+
+impl EpdOperation {
+    /// Get the opcode of the operation (e.g., `bm`).
+    pub fn get_opcode(&self) -> String {
+        match self {
+            EpdOperation::BestMoves(_) => "bm".to_string(),
+            EpdOperation::AvoidMoves(_) => "am".to_string(),
+            EpdOperation::Id(_) => "id".to_string(),
+            EpdOperation::Comment(number, _) => format!("c{}", number),
+            EpdOperation::AnalysisCountDepth(_) => "acd".to_string(),
+            EpdOperation::CentipawnEvaluation(_) => "ce".to_string(),
+            EpdOperation::PredictedVariation(_) => "pv".to_string(),
+            EpdOperation::HalfMoveClock(_) => "hmvc".to_string(),
+            EpdOperation::FullMoveNumber(_) => "fmvn".to_string(),
+            EpdOperation::Other(opcode, _) => opcode.clone(),
+        }
+    }
+
+    /// Get the operands of the operation, as written in an EPD record. String operands
+    /// are quoted (e.g., `"WAC.001"`).
+    pub fn get_operands(&self) -> Vec<String> {
+        let quote = |string: &str| -> String { format!("\"{}\"", string) };
+
+        match self {
+            EpdOperation::BestMoves(moves)
+            | EpdOperation::AvoidMoves(moves)
+            | EpdOperation::PredictedVariation(moves) => moves.clone(),
+            EpdOperation::Id(string) | EpdOperation::Comment(_, string) => vec![quote(string)],
+            EpdOperation::AnalysisCountDepth(depth) => vec![depth.to_string()],
+            EpdOperation::CentipawnEvaluation(evaluation) => vec![evaluation.to_string()],
+            EpdOperation::HalfMoveClock(number) | EpdOperation::FullMoveNumber(number) => {
+                vec![number.to_string()]
+            }
+            EpdOperation::Other(_, operands) => operands
+                .iter()
+                .map(|operand| {
+                    if operand.is_empty()
+                        || operand.contains(|c: char| c.is_whitespace() || c == ';')
+                    {
+                        quote(operand)
+                    } else {
+                        operand.clone()
+                    }
+                })
+                .collect(),
+        }
+    }
+
+    /// Return the operation for an opcode and its operands, unquoted.
+    ///
+    /// # Errors
+    ///
+    /// Errors when the operands are not what the opcode takes (e.g., `acd` takes one
+    /// number).
+    pub fn new(opcode: &str, operands: Vec<String>) -> ChuiResult<EpdOperation> {
+        let invalid = |reason: &str| -> ChuiError {
+            ChuiError::InvalidEpd(format!("the `{}` operation {}", opcode, reason))
+        };
+        let single = || -> ChuiResult<&str> {
+            match operands.as_slice() {
+                [operand] => Ok(operand.as_str()),
+                _ => Err(invalid("takes one operand")),
+            }
+        };
+        let moves = || -> ChuiResult<Vec<String>> {
+            if operands.is_empty() {
+                Err(invalid("takes at least one move"))
+            } else {
+                Ok(operands.clone())
+            }
+        };
+        let number = |minimum: usize| -> ChuiResult<usize> {
+            single()?
+                .parse::<usize>()
+                .ok()
+                .filter(|number| *number >= minimum)
+                .ok_or_else(|| invalid(&format!("takes a number of at least {}", minimum)))
+        };
+
+        Ok(match opcode {
+            "bm" => EpdOperation::BestMoves(moves()?),
+            "am" => EpdOperation::AvoidMoves(moves()?),
+            "pv" => EpdOperation::PredictedVariation(moves()?),
+            "id" => EpdOperation::Id(single()?.to_string()),
+            "acd" => EpdOperation::AnalysisCountDepth(
+                single()?
+                    .parse::<u32>()
+                    .map_err(|_| invalid("takes a number of plies"))?,
+            ),
+            "ce" => EpdOperation::CentipawnEvaluation(
+                single()?
+                    .parse::<i32>()
+                    .map_err(|_| invalid("takes a number of centipawns"))?,
+            ),
+            "hmvc" => EpdOperation::HalfMoveClock(number(0)?),
+            "fmvn" => EpdOperation::FullMoveNumber(number(1)?),
+            _ => match opcode.strip_prefix('c').and_then(|n| n.parse::<u8>().ok()) {
+                Some(number) if opcode.len() == 2 => {
+                    EpdOperation::Comment(number, single()?.to_string())
+                }
+                _ => EpdOperation::Other(opcode.to_string(), operands),
+            },
+        })
+    }
+}
+
+impl fmt::Display for EpdOperation {
+    /// Write the operation as in an EPD record (e.g., `bm Nf3 e4;`).
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.get_opcode())?;
+        for operand in self.get_operands() {
+            write!(f, " {}", operand)?;
+        }
+        write!(f, ";")
+    }
+}
+
+/// An EPD record: a position, given by the first four fields of a FEN string, followed by
+/// operations (e.g., `r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - bm Bb5;
+/// id "Ruy Lopez";`). Test suites, puzzle sets and analysis exports are written in EPD.
+///
+/// Example:
+///
+/// ```
+/// use std::convert::TryFrom;
+///
+/// use chui_core::prelude::*;
+///
+/// let epd = Epd::try_from("4k3/8/4K3/8/8/8/8/R7 w - - bm Ra8#; id \"Mate in one\";").unwrap();
+///
+/// assert_eq!(epd.get_id(), Some("Mate in one"));
+/// assert_eq!(epd.get_best_moves(), vec!["Ra8#"]);
+/// assert_eq!(epd.get_fen(), "4k3/8/4K3/8/8/8/8/R7 w - - 0 1");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Epd {
+    /// The piece placement, side to move, castling rights and en passant target square
+    /// fields, separated by spaces.
+    pub position: String,
+
+    /// The operations, in the order written.
+    pub operations: Vec<EpdOperation>,
+}
+
+impl Epd {
+    /// Return the EPD record of the current position of the game, with the half-move
+    /// clock (`hmvc`) and full-move number (`fmvn`) operations. The castling rights are
+    /// written as X-FEN for a Chess960 game.
+    pub fn from_game(game: &Game) -> Epd {
+        let fen: String = if game.board.is_chess960() {
+            Fen::get_x_fen(game)
+        } else {
+            Fen::get_fen(game)
+        };
+
+        Epd {
+            position: fen.split(' ').take(4).collect::<Vec<&str>>().join(" "),
+            operations: vec![
+                EpdOperation::HalfMoveClock(game.half_move_clock),
+                EpdOperation::FullMoveNumber(game.move_counter),
+            ],
+        }
+    }
+
+    /// Get the FEN string of the position, with the half-move clock of the `hmvc`
+    /// operation (or 0) and the full-move number of the `fmvn` operation (or 1).
+    pub fn get_fen(&self) -> String {
+        let mut half_move_clock: usize = 0;
+        let mut move_counter: usize = 1;
+
+        for operation in self.operations.iter() {
+            match operation {
+                EpdOperation::HalfMoveClock(number) => half_move_clock = *number,
+                EpdOperation::FullMoveNumber(number) => move_counter = *number,
+                _ => (),
+            }
+        }
+
+        format!("{} {} {}", self.position, half_move_clock, move_counter)
+    }
+
+    /// Get the board of the position. Castling rights that only agree with the placement
+    /// of the Kings and Rooks on a Chess960 board make this a Chess960 board.
+    ///
+    /// # Errors
+    ///
+    /// Errors when the position is invalid (see `Board::from_fen()`).
+    pub fn get_board(&self) -> ChuiResult<Board> {
+        let fen: String = self.get_fen();
+
+        Board::from_fen(&fen).or_else(|error| Board::from_chess960_fen(&fen).map_err(|_| error))
+    }
+
+    /// Get the first operation with the given opcode (e.g., `bm`).
+    pub fn get_operation(&self, opcode: &str) -> Option<&EpdOperation> {
+        self.operations
+            .iter()
+            .find(|operation| operation.get_opcode() == opcode)
+    }
+
+    /// Get the identifier of the position (`id`).
+    pub fn get_id(&self) -> Option<&str> {
+        match self.get_operation("id") {
+            Some(EpdOperation::Id(id)) => Some(id.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Get the best moves of the position (`bm`), if any.
+    pub fn get_best_moves(&self) -> Vec<String> {
+        match self.get_operation("bm") {
+            Some(EpdOperation::BestMoves(moves)) => moves.clone(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Get the moves to avoid in the position (`am`), if any.
+    pub fn get_avoid_moves(&self) -> Vec<String> {
+        match self.get_operation("am") {
+            Some(EpdOperation::AvoidMoves(moves)) => moves.clone(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Get the comment numbered 0 to 9 (`c0` to `c9`).
+    pub fn get_comment(&self, number: u8) -> Option<&str> {
+        self.operations
+            .iter()
+            .find_map(|operation| match operation {
+                EpdOperation::Comment(n, comment) if *n == number => Some(comment.as_str()),
+                _ => None,
+            })
+    }
+
+    /// Parse the operations of an EPD record, after the position.
+    ///
+    /// # Errors
+    ///
+    /// Errors when an operation is malformed or is not ended by `;`.
+    fn parse_operations(text: &str) -> ChuiResult<Vec<EpdOperation>> {
+        let mut operations: Vec<EpdOperation> = Vec::new();
+        let mut chars = text.chars().peekable();
+
+        loop {
+            while chars.next_if(|c| c.is_whitespace()).is_some() {}
+            if chars.peek().is_none() {
+                return Ok(operations);
+            }
+
+            let opcode: String =
+                std::iter::from_fn(|| chars.next_if(|c| c.is_ascii_alphanumeric() || *c == '_'))
+                    .collect();
+            if !opcode.starts_with(|c: char| c.is_ascii_alphabetic()) || opcode.len() > 15 {
+                return Err(ChuiError::InvalidEpd(format!(
+                    "`{}` is not an opcode (an opcode is a letter followed by up to 14 \
+                     letters, digits or underscores)",
+                    opcode
+                        + &chars
+                            .clone()
+                            .take_while(|c| !c.is_whitespace())
+                            .collect::<String>()
+                )));
+            }
+
+            let mut operands: Vec<String> = Vec::new();
+            loop {
+                while chars.next_if(|c| c.is_whitespace()).is_some() {}
+
+                match chars.next() {
+                    Some(';') => break,
+                    Some('"') => {
+                        let operand: String =
+                            std::iter::from_fn(|| chars.next_if(|c| *c != '"')).collect();
+                        if chars.next().is_none() {
+                            return Err(ChuiError::InvalidEpd(format!(
+                                "the string operand of the `{}` operation is not closed",
+                                opcode
+                            )));
+                        }
+                        operands.push(operand);
+                    }
+                    Some(c) => {
+                        let rest: String = std::iter::from_fn(|| {
+                            chars.next_if(|c| !c.is_whitespace() && *c != ';')
+                        })
+                        .collect();
+                        operands.push(format!("{}{}", c, rest));
+                    }
+                    None => {
+                        return Err(ChuiError::InvalidEpd(format!(
+                            "the `{}` operation is not ended by `;`",
+                            opcode
+                        )))
+                    }
+                }
+            }
+
+            operations.push(EpdOperation::new(&opcode, operands)?);
+        }
+    }
+}
+
+impl TryFrom<&str> for Epd {
+    type Error = ChuiError;
+
+    /// Parse an EPD record: the four position fields of a FEN string, then any number of
+    /// operations, each ended by `;`.
+    ///
+    /// # Errors
+    ///
+    /// * Errors with [`ChuiError::InvalidEpd`] when a field is missing or an operation is
+    ///   malformed.
+    /// * Errors as with `Board::from_fen()` when the position is invalid.
+    fn try_from(record: &str) -> ChuiResult<Epd> {
+        let mut rest: &str = record.trim();
+        let mut fields: Vec<&str> = Vec::new();
+
+        while fields.len() < 4 {
+            if rest.is_empty() {
+                return Err(ChuiError::InvalidEpd(format!(
+                    "`{}` does not have the four position fields",
+                    record.trim()
+                )));
+            }
+
+            let end: usize = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            fields.push(&rest[..end]);
+            rest = rest[end..].trim_start();
+        }
+
+        let epd = Epd {
+            position: fields.join(" "),
+            operations: Epd::parse_operations(rest)?,
+        };
+        epd.get_board()?;
+
+        Ok(epd)
+    }
+}
+
+impl fmt::Display for Epd {
+    /// Write the EPD record (e.g., `4k3/8/4K3/8/8/8/8/R7 w - - bm Ra8#;`).
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.position)?;
+        for operation in self.operations.iter() {
+            write!(f, " {}", operation)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WAC_001: &str = "2rr3k/pp3pp1/1nnqbN1p/3pN3/2pP4/2P3Q1/PPB4P/R4RK1 w - - \
+                           bm Qg6; id \"WAC.001\";";
+
+    #[test]
+    fn parse_records() -> ChuiResult<()> {
+        let epd = Epd::try_from(WAC_001)?;
+        assert_eq!(
+            epd.position,
+            "2rr3k/pp3pp1/1nnqbN1p/3pN3/2pP4/2P3Q1/PPB4P/R4RK1 w - -"
+        );
+        assert_eq!(epd.get_best_moves(), vec!["Qg6"]);
+        assert!(epd.get_avoid_moves().is_empty());
+        assert_eq!(epd.get_id(), Some("WAC.001"));
+        assert_eq!(
+            epd.to_string(),
+            WAC_001.split_whitespace().collect::<Vec<&str>>().join(" ")
+        );
+
+        let epd = Epd::try_from(
+            "r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - hmvc 2; fmvn 3; \
+             bm Bb5 d4; am Ba6; acd 24; ce -15; pv Bb5 a6 Ba4; c0 \"Ruy Lopez; Morphy\"; \
+             c7 \"The main line\"; noop; xyz abc \"two words\";",
+        )?;
+        assert_eq!(
+            epd.get_fen(),
+            "r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 2 3"
+        );
+        assert_eq!(epd.get_best_moves(), vec!["Bb5", "d4"]);
+        assert_eq!(epd.get_avoid_moves(), vec!["Ba6"]);
+        assert_eq!(
+            epd.get_operation("acd"),
+            Some(&EpdOperation::AnalysisCountDepth(24))
+        );
+        assert_eq!(
+            epd.get_operation("ce"),
+            Some(&EpdOperation::CentipawnEvaluation(-15))
+        );
+        assert_eq!(
+            epd.get_operation("pv"),
+            Some(&EpdOperation::PredictedVariation(vec![
+                "Bb5".to_string(),
+                "a6".to_string(),
+                "Ba4".to_string()
+            ]))
+        );
+        assert_eq!(epd.get_comment(0), Some("Ruy Lopez; Morphy"));
+        assert_eq!(epd.get_comment(7), Some("The main line"));
+        assert_eq!(epd.get_comment(1), None);
+        assert_eq!(
+            epd.get_operation("xyz"),
+            Some(&EpdOperation::Other(
+                "xyz".to_string(),
+                vec!["abc".to_string(), "two words".to_string()]
+            ))
+        );
+
+        // The record is written back as read.
+        assert_eq!(Epd::try_from(epd.to_string().as_str())?, epd);
+        assert!(epd.to_string().ends_with(
+            "c0 \"Ruy Lopez; Morphy\"; c7 \"The main line\"; noop; xyz abc \"two words\";"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn parse_invalid_records() {
+        for record in [
+            "",
+            "4k3/8/8/8/8/8/8/4K3 w -",
+            "4k3/8/8/8/8/8/8/4K3 x - -",
+            "4k3/8/8/8/8/8/8/4K3 w KQ -",
+            "4k3/8/8/8/8/8/8/4K3 w - - bm Kd2",
+            "4k3/8/8/8/8/8/8/4K3 w - - bm;",
+            "4k3/8/8/8/8/8/8/4K3 w - - id;",
+            "4k3/8/8/8/8/8/8/4K3 w - - id \"unclosed;",
+            "4k3/8/8/8/8/8/8/4K3 w - - acd deep;",
+            "4k3/8/8/8/8/8/8/4K3 w - - ce 1.5;",
+            "4k3/8/8/8/8/8/8/4K3 w - - fmvn 0;",
+            "4k3/8/8/8/8/8/8/4K3 w - - c0 \"one\" \"two\";",
+            "4k3/8/8/8/8/8/8/4K3 w - - 1bm Kd2;",
+            "4k3/8/8/8/8/8/8/4K3 w - - ;",
+        ] {
+            assert!(
+                Epd::try_from(record).is_err(),
+                "{:?} should not parse",
+                record
+            );
+        }
+    }
+
+    #[test]
+    fn write_game_positions() -> ChuiResult<()> {
+        let mut game = Game::default();
+        let epd = Epd::from_game(&game);
+        assert_eq!(
+            epd.to_string(),
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - hmvc 0; fmvn 1;"
+        );
+        assert_eq!(epd.get_fen(), Fen::STARTING_POSITION);
+
+        game.set_fen("4k3/8/8/3pP3/8/8/8/4K3 w - d6 4 30")?;
+        let epd = Epd::from_game(&game);
+        assert_eq!(
+            epd.to_string(),
+            "4k3/8/8/3pP3/8/8/8/4K3 w - d6 hmvc 4; fmvn 30;"
+        );
+        assert_eq!(epd.get_fen(), Fen::get_fen(&game));
+
+        game.set_variant(Variant::Chess960(0));
+        let epd = Epd::from_game(&game);
+        let board = Epd::try_from(epd.to_string().as_str())?.get_board()?;
+        assert!(board.is_chess960());
+        assert_eq!(board.get_position(), game.board.get_position());
+        Ok(())
+    }
+}
@@ -9,6 +9,15 @@ use crate::prelude::*;
 pub struct Fen;
 
 impl Fen {
+    /// The FEN of the standard starting position.
+    pub const STARTING_POSITION: &'static str =
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+
+    /// The largest full-move counter accepted in a FEN string. The plies of the game are
+    /// counted from the full-move counter, so this leaves room to count the plies played
+    /// after the position as well.
+    pub(crate) const MAX_FULL_MOVE_COUNTER: usize = usize::MAX / 4;
+
     /// Get the FEN layout of the board.
     pub fn get_fen(game: &Game) -> String {
         let mut fen = Fen::get_board_fen(game);
@@ -39,7 +48,7 @@ impl Fen {
         fen = format!("{} {}", fen, game.get_fen_to_move());
 
         // Castling.
-        fen = format!("{} {}", fen, game.get_fen_castle());
+        fen = format!("{} {}", fen, game.get_shredder_fen_castle());
 
         // En passant target sqaure.
         fen = format!("{} {}", fen, game.get_fen_en_passant());
@@ -77,49 +86,641 @@ impl Fen {
 
     /// Get FEN layout of the board only without the other
     /// attributes.
+    pub fn get_board_fen(game: &Game) -> String {
+        Fen::get_piece_placement(&game.board)
+    }
+
+    /// Get the piece placement field of the FEN layout of a [`Board`], from the 8th rank down
+    /// to the 1st rank.
+    pub fn get_piece_placement(board: &Board) -> String {
+        let position: BitPosition = board.get_position();
+        let mut ranks = Vec::<String>::new();
+
+        for rank in (0..8).rev() {
+            let mut fen = String::new();
+            let mut empty_squares = 0;
+
+            for file in 0..8 {
+                match position.get_piece(Coord::new(file, rank).ok()) {
+                    Some(piece) => {
+                        if empty_squares > 0 {
+                            fen = format!("{}{}", fen, empty_squares);
+                            empty_squares = 0;
+                        }
+
+                        fen = format!("{}{}", fen, piece.repr().0);
+                    }
+                    None => empty_squares += 1,
+                }
+            }
+
+            // Write out the number of empty squares at the end of the rank.
+            if empty_squares > 0 {
+                fen = format!("{}{}", fen, empty_squares);
+            }
+
+            ranks.push(fen);
+        }
+
+        ranks.join("/")
+    }
+
+    //
+    // Parsing.
+    //
+
+    /// Split a FEN string into its six fields. The fields must be separated by a single
+    /// space with no leading or trailing whitespace.
+    ///
+    /// # Errors
+    ///
+    /// * Errors with [`ChuiError::InvalidFen`] if there are not exactly six fields.
+    pub(crate) fn split_fields(fen: &str) -> ChuiResult<Vec<&str>> {
+        let fields: Vec<&str> = fen.split(' ').collect();
+
+        if fields.len() != 6 || fields.iter().any(|field| field.is_empty()) {
+            return Err(ChuiError::InvalidFen(format!(
+                "`{}` must have exactly six fields separated by a single space",
+                fen
+            )));
+        }
+
+        Ok(fields)
+    }
+
+    /// Parse the piece placement field of a FEN string into a [`BitPosition`]. Each side must
+    /// have exactly one King and no Pawns may be on the 1st or 8th ranks.
+    ///
+    /// # Errors
+    ///
+    /// * Errors with [`ChuiError::InvalidPiecePlacement`] if the field is malformed.
+    pub(crate) fn parse_piece_placement(field: &str) -> ChuiResult<BitPosition> {
+        let position = Fen::parse_pieces(field)?;
+
+        for color in [Color::White, Color::Black] {
+            let kings = position
+                .get_pieces_for_color(color)
+                .into_iter()
+                .filter(|piece| piece.is_king())
+                .count();
+
+            if kings != 1 {
+                return Err(ChuiError::InvalidPiecePlacement(format!(
+                    "{} must have exactly one King, found {}",
+                    color, kings
+                )));
+            }
+        }
+
+        Ok(position)
+    }
+
+    /// Parse the pieces of the piece placement field of a FEN string into a [`BitPosition`],
+    /// without requiring a King for each side (e.g., `8/8/8/8/8/8/8/8` is the empty board).
+    ///
+    /// # Errors
+    ///
+    /// * Errors with [`ChuiError::InvalidPiecePlacement`] if the field is malformed.
+    pub(crate) fn parse_pieces(field: &str) -> ChuiResult<BitPosition> {
+        let mut position = BitPosition::new(Variant::Empty);
+        let ranks: Vec<&str> = field.split('/').collect();
+
+        if ranks.len() != 8 {
+            return Err(ChuiError::InvalidPiecePlacement(format!(
+                "`{}` must have exactly eight ranks separated by `/`",
+                field
+            )));
+        }
+
+        for (idx, rank_field) in ranks.iter().enumerate() {
+            // Ranks are listed from the 8th rank down to the 1st rank.
+            let rank = 7 - idx as u8;
+            let mut file: u8 = 0;
+            let mut last_was_digit = false;
+
+            for c in rank_field.chars() {
+                if let Some(empty_squares) = c.to_digit(10) {
+                    if !(1..=8).contains(&empty_squares) || last_was_digit {
+                        return Err(ChuiError::InvalidPiecePlacement(format!(
+                            "`{}` has an invalid empty square count on rank {}",
+                            rank_field,
+                            rank + 1
+                        )));
+                    }
+
+                    file += empty_squares as u8;
+                    last_was_digit = true;
+                } else {
+                    let piece = Piece::try_from(c)
+                        .ok()
+                        .filter(|_| c.is_ascii())
+                        .ok_or_else(|| {
+                            ChuiError::InvalidPiecePlacement(format!(
+                                "`{}` is an invalid piece on rank {}. Expected one of \
+                                [PRNBQKprnbqk]",
+                                c,
+                                rank + 1
+                            ))
+                        })?;
+
+                    if file > 7 {
+                        return Err(ChuiError::InvalidPiecePlacement(format!(
+                            "`{}` must describe exactly eight squares on rank {}",
+                            rank_field,
+                            rank + 1
+                        )));
+                    }
+
+                    if piece.is_pawn() && (rank == 0 || rank == 7) {
+                        return Err(ChuiError::InvalidPiecePlacement(format!(
+                            "a Pawn cannot be on rank {}",
+                            rank + 1
+                        )));
+                    }
+
+                    let coord = Coord::new(file, rank)?;
+                    let piece = Piece::new(piece.get_kind(), piece.get_color(), coord);
+                    position.put_piece(Some(piece), Some(coord));
+                    file += 1;
+                    last_was_digit = false;
+                }
+            }
+
+            if file != 8 {
+                return Err(ChuiError::InvalidPiecePlacement(format!(
+                    "`{}` must describe exactly eight squares on rank {}",
+                    rank_field,
+                    rank + 1
+                )));
+            }
+        }
+
+        Ok(position)
+    }
+
+    /// Parse the side to move field of a FEN string.
+    ///
+    /// # Errors
+    ///
+    /// * Errors with [`ChuiError::InvalidFen`] if the field is not `w` or `b`.
+    pub(crate) fn parse_to_move(field: &str) -> ChuiResult<Color> {
+        match field {
+            "w" => Ok(Color::White),
+            "b" => Ok(Color::Black),
+            _ => Err(ChuiError::InvalidFen(format!(
+                "`{}` is an invalid side to move. Expected one of [wb]",
+                field
+            ))),
+        }
+    }
+
+    /// Parse the castling field of a FEN string and set the castling rights on `board`. The
+    /// field must be `-` or a list of castling rights, White's before Black's and the King
+    /// side before the Queen side, e.g., `KQkq`. Every castling right must have its King on
+    /// the back rank and its Rook on its initial square.
     ///
-    /// # Panics
-    ///
-    /// * Panics if `piece` is None after checking that it is Some.
-    pub fn get_board_fen(_game: &Game) -> String {
-        // let mut fen = String::new();
-        // let mut empty_squares = 0;
-
-        // // Get board layout.
-        // for rank in game.board.get_position().iter().rev() {
-        //     for piece in rank.iter() {
-        //         if piece.is_some() {
-        //             if empty_squares > 0 {
-        //                 fen = format!(
-        //                     "{}{}{}",
-        //                     fen,
-        //                     empty_squares,
-        //                     piece.expect("Piece cannot be None.")
-        //                 );
-        //                 empty_squares = 0;
-        //             } else {
-        //                 fen = format!("{}{}", fen, piece.expect("Piece cannot be None."));
-        //             }
-        //         } else {
-        //             empty_squares += 1;
-        //         }
-        //     }
-
-        //     // Write out the number of empty squares between
-        //     // pieces on the same rank.
-        //     if empty_squares > 0 {
-        //         fen = format!("{}{}", fen, empty_squares);
-        //     }
-
-        //     // Separate ranks by '/'.
-        //     fen = format!("{}/", fen);
-        //     empty_squares = 0;
-        // }
-
-        // // Remove trailing '/'.
-        // fen.pop();
-
-        // fen
-        String::from("get_board_fen() is not implemented")
+    /// On a Standard Chess board, `K` and `Q` (or `k` and `q`) require the King on the
+    /// e-file and the Rook in the corner. On a Chess960 board (X-FEN), they name the
+    /// outermost Rook on that side of the King. A file (e.g., `H` or `h`, as with
+    /// Shredder-FEN) names the Rook on that file, and makes `board` a Chess960 board.
+    ///
+    /// # Errors
+    ///
+    /// * Errors with [`ChuiError::InvalidCastlingRights`] if the field is malformed or does
+    ///   not agree with the position.
+    pub(crate) fn parse_castling(field: &str, board: &mut Board) -> ChuiResult<()> {
+        for color in [Color::White, Color::Black] {
+            for castling in [Castling::King, Castling::Queen] {
+                board.set_castling_rights(color, castling, false);
+            }
+        }
+
+        if field == "-" {
+            return Ok(());
+        }
+
+        let invalid = || {
+            ChuiError::InvalidCastlingRights(format!(
+                "`{}` is an invalid castling field. Expected `-`, a subset of `KQkq`, or the \
+                files of the castling Rooks",
+                field
+            ))
+        };
+
+        if field.is_empty() || !field.chars().all(|c| "KQkqABCDEFGHabcdefgh".contains(c)) {
+            return Err(invalid());
+        }
+
+        if field
+            .chars()
+            .any(|c| c.is_ascii_alphabetic() && !"KQkq".contains(c))
+        {
+            board.set_chess960(true);
+        }
+
+        let mut last_right: Option<usize> = None;
+
+        for c in field.chars() {
+            let color = if c.is_ascii_uppercase() {
+                Color::White
+            } else {
+                Color::Black
+            };
+            let rank: u8 = match color {
+                Color::White => 0,
+                Color::Black => 7,
+            };
+            let position = board.get_position();
+            let has_piece = |file: u8, kind: PieceKind| {
+                position
+                    .get_piece(Coord::new(file, rank).ok())
+                    .is_some_and(|piece| piece.get_kind() == kind && piece.get_color() == color)
+            };
+            let king_file: Option<u8> = (0..8).find(|file| has_piece(*file, PieceKind::King));
+            let missing = || {
+                ChuiError::InvalidCastlingRights(format!(
+                    "`{}` requires the {} King and Rook on their initial squares",
+                    c, color
+                ))
+            };
+            let king_file: u8 = king_file.ok_or_else(missing)?;
+
+            let (castling, rook_file): (Castling, Option<u8>) =
+                match (c.to_ascii_uppercase(), board.is_chess960()) {
+                    ('K', false) => (Castling::King, Some(7).filter(|_| king_file == 4)),
+                    ('Q', false) => (Castling::Queen, Some(0).filter(|_| king_file == 4)),
+                    ('K', true) => (
+                        Castling::King,
+                        (king_file + 1..8)
+                            .rev()
+                            .find(|file| has_piece(*file, PieceKind::Rook)),
+                    ),
+                    ('Q', true) => (
+                        Castling::Queen,
+                        (0..king_file).find(|file| has_piece(*file, PieceKind::Rook)),
+                    ),
+                    (file, _) => {
+                        let file: u8 = file as u8 - b'A';
+                        let castling = if file > king_file {
+                            Castling::King
+                        } else {
+                            Castling::Queen
+                        };
+
+                        (castling, Some(file).filter(|file| *file != king_file))
+                    }
+                };
+
+            // Advance through the rights so that duplicates and out of order rights are
+            // rejected.
+            let right: usize = match (color, castling) {
+                (Color::White, Castling::King) => 0,
+                (Color::White, Castling::Queen) => 1,
+                (Color::Black, Castling::King) => 2,
+                (Color::Black, Castling::Queen) => 3,
+            };
+
+            if last_right.is_some_and(|last_right| right <= last_right) {
+                return Err(invalid());
+            }
+
+            last_right = Some(right);
+
+            let rook_file: u8 = rook_file
+                .filter(|file| has_piece(*file, PieceKind::Rook))
+                .ok_or_else(missing)?;
+
+            board.set_castling_rights(color, castling, true);
+            board.set_castling_rook_file(color, castling, rook_file);
+        }
+
+        Ok(())
+    }
+
+    /// Parse the en passant target square field of a FEN string. The target square must be
+    /// on the 6th rank with White to move (or the 3rd rank with Black to move), it must be
+    /// empty along with the square behind it, and the Pawn that just advanced two squares
+    /// must be in front of it. Returns the target square and that Pawn.
+    ///
+    /// # Errors
+    ///
+    /// * Errors with [`ChuiError::InvalidEnPassant`] if the field is malformed or does not
+    ///   agree with the position.
+    pub(crate) fn parse_en_passant(
+        field: &str,
+        board: &Board,
+        to_move: Color,
+    ) -> ChuiResult<(Option<Coord>, Option<Piece>)> {
+        if field == "-" {
+            return Ok((None, None));
+        }
+
+        let coord = Coord::try_from(field).map_err(|_| {
+            ChuiError::InvalidEnPassant(format!(
+                "`{}` is an invalid en passant target square",
+                field
+            ))
+        })?;
+
+        // The Pawn that advanced belongs to the side that is not to move.
+        let (target_rank, rank_delta) = match to_move {
+            Color::White => (5, -1),
+            Color::Black => (2, 1),
+        };
+
+        if coord.get_rank() != target_rank {
+            return Err(ChuiError::InvalidEnPassant(format!(
+                "`{}` must be on rank {} when {} is to move",
+                field,
+                target_rank + 1,
+                to_move
+            )));
+        }
+
+        let position = board.get_position();
+        let pawn = position
+            .get_piece(coord.offset(0, rank_delta))
+            .filter(|piece| piece.is_pawn() && piece.get_color() == to_move.opposite());
+
+        if pawn.is_none()
+            || position.get_piece(Some(coord)).is_some()
+            || position.get_piece(coord.offset(0, -rank_delta)).is_some()
+        {
+            return Err(ChuiError::InvalidEnPassant(format!(
+                "`{}` does not follow a {} Pawn advancing two squares",
+                field,
+                to_move.opposite()
+            )));
+        }
+
+        Ok((Some(coord), pawn))
+    }
+
+    /// Parse the half-move clock field of a FEN string.
+    ///
+    /// # Errors
+    ///
+    /// * Errors with [`ChuiError::InvalidFen`] if the field is not a non-negative integer.
+    pub(crate) fn parse_half_move_clock(field: &str) -> ChuiResult<usize> {
+        Fen::parse_number(field, "half-move clock")
+    }
+
+    /// Parse the full-move counter field of a FEN string.
+    ///
+    /// # Errors
+    ///
+    /// * Errors with [`ChuiError::InvalidFen`] if the field is not a positive integer, or is
+    ///   larger than `MAX_FULL_MOVE_COUNTER`.
+    pub(crate) fn parse_full_move_counter(field: &str) -> ChuiResult<usize> {
+        match Fen::parse_number(field, "full-move counter")? {
+            0 => Err(ChuiError::InvalidFen(
+                "the full-move counter must start at 1".to_string(),
+            )),
+            counter if counter > Fen::MAX_FULL_MOVE_COUNTER => Err(ChuiError::InvalidFen(format!(
+                "`{}` is an invalid full-move counter: it must be at most {}",
+                field,
+                Fen::MAX_FULL_MOVE_COUNTER
+            ))),
+            counter => Ok(counter),
+        }
+    }
+
+    /// Parse a FEN number field made up of ASCII digits only.
+    fn parse_number(field: &str, name: &str) -> ChuiResult<usize> {
+        if !field.chars().all(|c| c.is_ascii_digit()) {
+            return Err(ChuiError::InvalidFen(format!(
+                "`{}` is an invalid {}",
+                field, name
+            )));
+        }
+
+        field.parse::<usize>().map_err(|error| {
+            ChuiError::InvalidFen(format!("`{}` is an invalid {}: {}", field, name, error))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const START_FEN: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+    const KIWIPETE_FEN: &str =
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+
+    fn game_from_fen(fen: &str) -> ChuiResult<Game> {
+        let white = Player::new(Color::White, Some("Camina Drummer"), Some(37), None);
+        let black = Player::new(Color::Black, Some("Klaes Ashford"), Some(72), Some(1500));
+        Game::from_fen(white, black, ParserEngine::Algebraic, fen)
+    }
+
+    #[test]
+    fn get_fen_start_position() {
+        let game = Game::default();
+        assert_eq!(START_FEN, Fen::get_fen(&game));
+        assert_eq!(START_FEN, Fen::get_x_fen(&game));
+        assert_eq!(
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w HAha - 0 1",
+            Fen::get_shredder_fen(&game)
+        );
+    }
+
+    #[test]
+    fn get_fen_after_moves() -> ChuiResult<()> {
+        let mut game = game_from_fen(START_FEN)?;
+
+        for the_move in ["e4", "c5", "Nf3"] {
+            let chess_move = game.parse(the_move.to_string(), game.to_move)?;
+            game.set_current_move(Some(chess_move));
+            game.apply_move()?;
+        }
+
+        assert_eq!(
+            "rnbqkbnr/pp1ppppp/8/2p5/4P3/5N2/PPPP1PPP/RNBQKB1R b KQkq - 1 2",
+            Fen::get_fen(&game)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_x_fen_en_passant() -> ChuiResult<()> {
+        // FEN always records the en passant target square, X-FEN only when a capture is
+        // possible.
+        let game = game_from_fen("4k3/8/8/8/4P3/8/8/4K3 b - e3 0 1")?;
+        assert_eq!("4k3/8/8/8/4P3/8/8/4K3 b - e3 0 1", Fen::get_fen(&game));
+        assert_eq!("4k3/8/8/8/4P3/8/8/4K3 b - - 0 1", Fen::get_x_fen(&game));
+
+        let game = game_from_fen("4k3/8/8/8/3pP3/8/8/4K3 b - e3 0 1")?;
+        assert_eq!("4k3/8/8/8/3pP3/8/8/4K3 b - e3 0 1", Fen::get_x_fen(&game));
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_fen_round_trip() -> ChuiResult<()> {
+        for fen in [
+            START_FEN,
+            KIWIPETE_FEN,
+            "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+            "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+            "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+            "r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 10",
+            "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+        ] {
+            assert_eq!(fen, Fen::get_fen(&game_from_fen(fen)?));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_fen_sets_game_state() -> ChuiResult<()> {
+        let game = game_from_fen("rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8")?;
+        assert_eq!(Color::White, game.to_move);
+        assert_eq!(1, game.half_move_clock);
+        assert_eq!(8, game.move_counter);
+        assert_eq!(14, game.half_move_counter);
+        assert!(game.board.white_can_castle_kingside);
+        assert!(game.board.white_can_castle_queenside);
+        assert!(!game.board.black_can_castle_kingside);
+        assert!(!game.board.black_can_castle_queenside);
+
+        let board =
+            Board::from_fen("rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3")?;
+        assert_eq!(Coord::try_from("f6").ok(), board.get_en_passant_coord());
+        assert_eq!(
+            Coord::try_from("f6").ok(),
+            board.get_true_en_passant_coord()
+        );
+        assert_eq!(
+            Some(Piece::black_pawn(('f', 5))?),
+            board.get_en_passant_piece()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_fen_invalid_fields() {
+        for fen in [
+            "",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1 1",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR  w KQkq - 0 1",
+            " rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR x KQkq - 0 1",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - -1 1",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - +0 1",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 0",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 one",
+        ] {
+            assert!(
+                matches!(Board::from_fen(fen), Err(ChuiError::InvalidFen(_))),
+                "{}",
+                fen
+            );
+        }
+    }
+
+    #[test]
+    fn from_fen_full_move_counter_too_large() -> ChuiResult<()> {
+        for counter in [
+            (Fen::MAX_FULL_MOVE_COUNTER + 1).to_string(),
+            "9223372036854775809".to_string(),
+            "18446744073709551616".to_string(),
+        ] {
+            let fen = format!("4k3/8/8/8/8/8/8/4K3 w - - 0 {}", counter);
+            assert!(
+                matches!(Board::from_fen(&fen), Err(ChuiError::InvalidFen(_))),
+                "{}",
+                fen
+            );
+            assert!(
+                matches!(game_from_fen(&fen), Err(ChuiError::InvalidFen(_))),
+                "{}",
+                fen
+            );
+        }
+
+        let fen = format!("4k3/8/8/8/8/8/8/4K3 b - - 0 {}", Fen::MAX_FULL_MOVE_COUNTER);
+        let game = game_from_fen(&fen)?;
+        assert_eq!(Fen::MAX_FULL_MOVE_COUNTER, game.move_counter);
+        assert_eq!(
+            (Fen::MAX_FULL_MOVE_COUNTER - 1) * 2 + 1,
+            game.half_move_counter
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn from_fen_invalid_piece_placement() {
+        for fen in [
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP w KQkq - 0 1",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR/8 w KQkq - 0 1",
+            "rnbqkbnr/pppppppp/9/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "rnbqkbnr/pppppppp/44/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "rnbqkbnr/pppppppp/7/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "rnbqkbnr/ppppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "rnbqkbnr/pppppppp/0/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "rnbqkbnr/pppxpppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "rnbqkbnr/ppp♟pppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "rnbqqbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBKKBNR w KQkq - 0 1",
+            "rnbqkbnp/ppppppp1/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            // The side that is not to move is in check.
+            "4k3/8/8/8/8/8/8/4R1K1 w - - 0 1",
+            "4k3/8/8/8/8/8/8/r3K3 b - - 0 1",
+        ] {
+            assert!(
+                matches!(
+                    Board::from_fen(fen),
+                    Err(ChuiError::InvalidPiecePlacement(_))
+                ),
+                "{}",
+                fen
+            );
+        }
+    }
+
+    #[test]
+    fn from_fen_invalid_castling_rights() {
+        for fen in [
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w QK - 0 1",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KKQkq - 0 1",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkqx - 0 1",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w -K - 0 1",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBN1 w KQkq - 0 1",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQ1KNR w Q - 0 1",
+            "1nbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w q - 0 1",
+        ] {
+            assert!(
+                matches!(
+                    Board::from_fen(fen),
+                    Err(ChuiError::InvalidCastlingRights(_))
+                ),
+                "{}",
+                fen
+            );
+        }
+    }
+
+    #[test]
+    fn from_fen_invalid_en_passant() {
+        for fen in [
+            "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e4 0 1",
+            "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e6 0 1",
+            "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq d3 0 1",
+            "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e9 0 1",
+            "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3e 0 1",
+            "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR w KQkq e3 0 1",
+            "rnbqkbnr/pppppppp/8/8/4P3/4N3/PPPP1PPP/RNBQKB1R b KQkq e3 0 1",
+        ] {
+            assert!(
+                matches!(Board::from_fen(fen), Err(ChuiError::InvalidEnPassant(_))),
+                "{}",
+                fen
+            );
+        }
     }
 }
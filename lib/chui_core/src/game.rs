@@ -4,6 +4,7 @@ use std::collections::HashMap;
 use std::fmt;
 use std::io;
 
+use crate::parser::lenient::LenientParser;
 use crate::prelude::*;
 
 mod commands;
@@ -16,7 +17,7 @@ mod fen;
 /// Example:
 ///
 /// ```
-/// use chui_core::{Player, Color, Game, ParserEngine};
+/// use chui_core::prelude::*;
 ///
 /// let white = Player::new(
 ///     Color::White,
@@ -57,9 +58,10 @@ pub struct Game {
     /// The `Color` to move.
     pub to_move: Color,
 
-    /// A hashmap of FEN positions with their position count. Used to count position repetitions
-    /// via the Third Repetition and Fifth Repetition draw condition.
-    pub position_record: HashMap<String, u8>,
+    /// A hashmap of positions, keyed by their Zobrist hash (see `Board::hash()`), with their
+    /// position count. Used to count position repetitions via the Third Repetition and Fifth
+    /// Repetition draw condition.
+    pub position_record: HashMap<u64, u8>,
 
     /// Does White win?
     pub white_wins: bool,
@@ -90,11 +92,27 @@ pub struct Game {
     /*
     pub move_generator: MoveGenerator<'a>,
     */
+    /// The moves played from the start position to the current position: the line of
+    /// the move tree cursor (see `get_move_tree()`). The main line of the game is given by
+    /// `get_main_line()`.
     pub move_list: Vec<ChessMove>,
 
     /// The current move.
     current_move: Option<ChessMove>,
 
+    /// The record of each applied move, used to undo moves.
+    move_history: Vec<MoveRecord>,
+
+    /// The moves that have been undone, most recently undone last, used to redo moves.
+    redo_list: Vec<ChessMove>,
+
+    /// The moves of the game and their variations, with a cursor on the move of the
+    /// current position.
+    move_tree: MoveTree,
+
+    /// The FEN (X-FEN for a Chess960 game) of the position the game started from.
+    start_fen: String,
+
     /// The win condition.
     pub win_condition: Option<WinCondition>,
 
@@ -103,6 +121,17 @@ pub struct Game {
 
     /// Display the chessboard for a particular `Color`.
     pub display_for: Option<Color>,
+
+    /// Accept moves as people tend to type them (e.g., `nf3`, `0-0` or `Ng1-f3`) when the
+    /// current parser cannot parse them (see `LenientParser`).
+    pub lenient: bool,
+
+    /// The language of the piece letters of the moves parsed, and of the moves listed by
+    /// `get_san_move_list_for_locale()`.
+    pub locale: Locale,
+
+    /// Display the pieces on the board by their letters or by their figurines.
+    pub piece_style: PieceStyle,
 }
 
 impl Default for Game {
@@ -147,7 +176,7 @@ impl Game {
             (player_2, player_1)
         };
 
-        Ok(Game {
+        let mut game = Game {
             white,
             black,
             board: Board::new(Variant::StandardChess),
@@ -164,10 +193,103 @@ impl Game {
             parser: ParserEngine::new(parser_engine, Color::White),
             move_list: Vec::<ChessMove>::new(),
             current_move: None,
+            move_history: Vec::<MoveRecord>::new(),
+            redo_list: Vec::<ChessMove>::new(),
+            move_tree: MoveTree::new(),
+            start_fen: Fen::STARTING_POSITION.to_string(),
             win_condition: None,
             draw_condition: None,
             display_for: None,
-        })
+            lenient: false,
+            locale: Locale::default(),
+            piece_style: PieceStyle::default(),
+        };
+
+        // Count the initial position.
+        game.record_position();
+
+        Ok(game)
+    }
+
+    /// Return a new instance of [`ChuiResult<Game>`] given a white [`Player`], a black
+    /// [`Player`], and the position described by a FEN string.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a [`ChuiError`] when the engine was initialized with incompatible
+    ///   sides.
+    /// * Returns a [`ChuiError`] when the FEN string is invalid (see `set_fen()`).
+    pub fn from_fen(
+        player_1: Player,
+        player_2: Player,
+        parser_engine: ParserEngine,
+        fen: &str,
+    ) -> ChuiResult<Game> {
+        let mut game = Game::new(player_1, player_2, parser_engine)?;
+        game.set_fen(fen)?;
+
+        Ok(game)
+    }
+
+    /// Set up the game from the position described by a FEN string. The board, the side
+    /// to move, and the clocks and counters are all taken from the FEN string. The move
+    /// list and captured pieces are reset, and the game result is determined from the
+    /// position (e.g., a checkmated side to move ends the game). The FEN string is read
+    /// as X-FEN when the game is a Chess960 game (see `Board::from_chess960_fen()`).
+    ///
+    /// # Errors
+    ///
+    /// * Returns a [`ChuiError`] when the FEN string is invalid (see `Board::from_fen()`).
+    ///   The game is left untouched on error.
+    pub fn set_fen(&mut self, fen: &str) -> ChuiResult<()> {
+        let board = if self.board.is_chess960() {
+            Board::from_chess960_fen(fen)?
+        } else {
+            Board::from_fen(fen)?
+        };
+        let fields = Fen::split_fields(fen)?;
+        let to_move = Fen::parse_to_move(fields[1])?;
+        let half_move_clock = Fen::parse_half_move_clock(fields[4])?;
+        let move_counter = Fen::parse_full_move_counter(fields[5])?;
+
+        self.reset(board, to_move, half_move_clock, move_counter);
+
+        Ok(())
+    }
+
+    /// Set up the game from the start position of a chess variant, e.g.,
+    /// `Variant::Chess960(idx)`. The move list and captured pieces are reset.
+    pub fn set_variant(&mut self, variant: Variant) {
+        self.reset(Board::new(variant), Color::White, 0, 1);
+    }
+
+    /// Reset the game to the given board, side to move, half-move clock and full-move
+    /// counter, and determine the game result from the position.
+    fn reset(&mut self, board: Board, to_move: Color, half_move_clock: usize, move_counter: usize) {
+        self.board = board;
+        self.to_move = to_move;
+        self.half_move_clock = half_move_clock;
+        self.move_counter = move_counter;
+        self.half_move_counter = (move_counter - 1) * 2 + usize::from(to_move == Color::Black);
+        self.captured_pieces.clear();
+        self.position_record.clear();
+        self.move_list.clear();
+        self.move_history.clear();
+        self.redo_list.clear();
+        self.move_tree = MoveTree::new();
+        self.current_move = None;
+        self.white_wins = false;
+        self.black_wins = false;
+        self.is_draw = false;
+        self.win_condition = None;
+        self.draw_condition = None;
+        self.record_position();
+        self.update_game_result();
+        self.start_fen = if self.board.is_chess960() {
+            Fen::get_x_fen(self)
+        } else {
+            Fen::get_fen(self)
+        };
     }
 
     /// Switch the current move parser based on a `CommandKind`.
@@ -175,19 +297,62 @@ impl Game {
         commands::switch_parser(self, command);
     }
 
-    /// Parse the move. Returns an Ok(ChessMove) if the parsing of the
-    /// move is successful, otherwise a `ChuiError` will result.
+    /// Parse the move for the board, as played by `to_move`. Returns an Ok(ChessMove),
+    /// fully resolved for the board, if the parsing of the move is successful, otherwise a
+    /// `ChuiError` will result (see `Parser::parse()`).
     ///
     /// # Errors
     ///
     /// * Errors when the parser cannot parse the move.
+    /// * Errors when the move is not legal on the board.
+    /// * Errors when the game is over.
+    ///
+    /// When `lenient` is set and the parser cannot parse the move, the move is parsed with
+    /// the `LenientParser` instead, whose error suggests the legal moves that were likely
+    /// meant.
     pub fn parse(&mut self, the_move: String, to_move: Color) -> ChuiResult<ChessMove> {
-        self.parser.parse(the_move, to_move)
+        if self.is_game_over() {
+            return Err(ChuiError::GameOver(
+                "No more moves can be made once the game has ended".to_string(),
+            ));
+        }
+
+        let mut board: Board = self.board;
+        board.set_to_move(to_move);
+
+        match self.parser.parse(the_move.clone(), &board) {
+            Err(_) if self.lenient => {
+                let mut parser = LenientParser::new(to_move);
+                parser.set_locale(self.locale);
+                parser.parse(the_move, &board)
+            }
+            result => result,
+        }
+    }
+
+    /// Return the legal moves (in algebraic notation) that the input move likely means for
+    /// the color to move (see `LenientParser::suggest()`).
+    ///
+    /// # Errors
+    ///
+    /// Errors when a legal move cannot be written in algebraic notation.
+    pub fn suggest_moves(&self, the_move: &str) -> ChuiResult<Vec<String>> {
+        let mut board: Board = self.board;
+        board.set_to_move(self.to_move);
+
+        LenientParser::suggest(the_move, &board, self.locale)
     }
 
     /// Set a new parser based on `ParserEngine`.
     pub fn set_parser(&mut self, parser_engine: ParserEngine) {
         self.parser = ParserEngine::new(parser_engine, self.to_move);
+        self.parser.set_locale(self.locale);
+    }
+
+    /// Set the language of the piece letters of the moves parsed (see `Locale`).
+    pub fn set_locale(&mut self, locale: Locale) {
+        self.locale = locale;
+        self.parser.set_locale(locale);
     }
 
     /// Get input string from `io::stdin()`.
@@ -215,6 +380,11 @@ impl Game {
         fen::get_fen_castle(self)
     }
 
+    /// Get the Shredder-FEN castle characters.
+    pub fn get_shredder_fen_castle(&self) -> String {
+        fen::get_shredder_fen_castle(self)
+    }
+
     /// Get the FEN en passant square.
     pub fn get_fen_en_passant(&self) -> String {
         fen::get_fen_en_passant(self)
@@ -299,7 +469,9 @@ impl Game {
                     .get_piece(Coord::try_from((*j, *i)).ok())
                     .map_or_else(
                         || format!("{} ·", output),
-                        |piece| format!("{} {}", output, piece),
+                        |piece| {
+                            format!("{} {}", output, piece.repr_colored_style(self.piece_style))
+                        },
                     );
             }
             output = format!("{} │ {} ║\n", output.trim(), numeric_coords[*i as usize]);
@@ -352,35 +524,672 @@ impl Game {
         )
     }
 
-    /// Apply the move.
-    ///
-    /// TODO: Set more game state per the rules, such as en passant.
+    /// Apply the current move (see `set_current_move()`). The move is processed and validated
+    /// for the board, then played on the board. Any captured piece is recorded, the clocks
+    /// and counters are advanced, and the other player is set to move. The move is marked
+    /// as check or checkmate and added to the move list. Checkmate and stalemate end the
+    /// game.
     ///
     /// # Errors
     ///
-    /// * Errors if the piece we're moving is `None`.
+    /// * Errors if the game is over.
+    /// * Errors if there is no current move.
+    /// * Errors if the current move is not valid for the board.
     pub fn apply_move(&mut self) -> ChuiResult<()> {
-        // let current_move = self.process_move()?;
-        // if let Some(current_move) = current_move {
-        //     println!("Apply Move: {:?}", current_move);
-        //     println!("{}", current_move.get_move_text());
-        //     println!();
-        //     let apply = self.board.apply_move(&current_move);
-
-        //     if let Ok(captured_piece) = apply {
-        //         if let Some(captured_piece) = captured_piece {
-        //             self.captured_pieces.push(captured_piece);
-        //         }
-        //         self.toggle_to_move();
-        //     }
-
-        //     Ok(())
-        // } else {
-        //     Err(ChuiError::InvalidMove("No move to apply".to_string()))
-        // }
+        if self.is_game_over() {
+            return Err(ChuiError::GameOver(
+                "No more moves can be made once the game has ended".to_string(),
+            ));
+        }
+
+        let chess_move = self
+            .process_move()?
+            .ok_or_else(|| ChuiError::InvalidMove("No move to apply".to_string()))?;
+
+        self.play_move(chess_move)?;
+
+        // A new move replaces any moves that were undone.
+        self.redo_list.clear();
+
+        Ok(())
+    }
+
+    /// Play a move that has been validated for the board, and record it so that it can be
+    /// undone. The move tree cursor moves to the node of the move, which is added when the
+    /// move was not played from this position before: as the main line when no move was
+    /// played from this position before, otherwise as the last variation. The order of the
+    /// moves already in the tree is left as it is (see `promote_variation()`).
+    fn play_move(&mut self, mut chess_move: ChessMove) -> ChuiResult<()> {
+        let mut record = MoveRecord::new(self, chess_move.clone());
+        record.san = chess_move.to_san(&self.board)?;
+        let captured_piece = self.board.apply_move(&chess_move)?;
+        record.captured_piece = captured_piece;
+
+        if let Some(captured_piece) = captured_piece {
+            self.captured_pieces.push(captured_piece);
+        }
+
+        if chess_move.from_piece.is_some_and(|piece| piece.is_pawn()) || captured_piece.is_some() {
+            self.half_move_clock = 0;
+        } else {
+            self.half_move_clock += 1;
+        }
+
+        self.half_move_counter += 1;
+
+        if self.to_move == Color::Black {
+            self.move_counter += 1;
+        }
+
+        self.toggle_to_move();
+        self.record_position();
+        chess_move.check = self.update_game_result();
+        record.chess_move = chess_move.clone();
+
+        let cursor: NodeId = self.move_tree.get_cursor();
+        let node_id: NodeId = match self.move_tree.find_child(cursor, &record.san) {
+            Some(node_id) => {
+                // A resignation or a draw that was agreed to or claimed in this position
+                // does not follow from the moves, so is restored from the node.
+                if !self.is_game_over() {
+                    if let Some(node) = self.move_tree.get_node(node_id) {
+                        let (win_condition, draw_condition) = node.get_result();
+                        self.set_result(win_condition, draw_condition);
+                    }
+                }
+                node_id
+            }
+            None => self.move_tree.add_child(cursor, record.clone()),
+        };
+        self.move_tree.set_cursor(node_id);
+
+        self.move_list.push(chess_move);
+        self.move_history.push(record);
+        self.current_move = None;
+
         Ok(())
     }
 
+    /// Take back the last move. The board, the captured pieces, the clocks and counters,
+    /// the position record, and the result of the game are restored to what they were
+    /// before the move. Returns the move that was taken back, which can be played again
+    /// with `redo()`. The move stays in the move tree.
+    ///
+    /// # Errors
+    ///
+    /// * Errors if there are no moves to undo.
+    pub fn undo(&mut self) -> ChuiResult<ChessMove> {
+        let chess_move: ChessMove = self.take_back()?;
+        self.redo_list.push(chess_move.clone());
+
+        Ok(chess_move)
+    }
+
+    /// Take back the last move, and move the move tree cursor to the previous move (see
+    /// `undo()`).
+    ///
+    /// # Errors
+    ///
+    /// * Errors if there are no moves to take back.
+    fn take_back(&mut self) -> ChuiResult<ChessMove> {
+        let record: MoveRecord = self
+            .move_history
+            .last()
+            .cloned()
+            .ok_or_else(|| ChuiError::InvalidMove("There are no moves to undo".to_string()))?;
+
+        let mut board: Board = self.board;
+        board.unmake_move(&record)?;
+
+        // Keep the result of the position left, to restore it when the move is played again.
+        let cursor: NodeId = self.move_tree.get_cursor();
+        self.move_tree
+            .set_result(cursor, self.win_condition, self.draw_condition);
+
+        self.forget_position();
+        self.move_history.pop();
+        self.move_list.pop();
+        self.board = board;
+
+        if record.captured_piece.is_some() {
+            self.captured_pieces.pop();
+        }
+
+        self.toggle_to_move();
+        self.half_move_clock = record.half_move_clock;
+        self.half_move_counter = self.half_move_counter.saturating_sub(1);
+
+        if self.to_move == Color::Black {
+            self.move_counter = self.move_counter.saturating_sub(1);
+        }
+
+        self.white_wins = record.white_wins;
+        self.black_wins = record.black_wins;
+        self.is_draw = record.is_draw;
+        self.win_condition = record.win_condition;
+        self.draw_condition = record.draw_condition;
+        self.current_move = None;
+
+        if let Some(parent) = self
+            .move_tree
+            .get_node(cursor)
+            .and_then(MoveNode::get_parent)
+        {
+            self.move_tree.set_cursor(parent);
+        }
+
+        Ok(record.chess_move)
+    }
+
+    /// Play the last move taken back with `undo()` again. Returns the move that was played.
+    ///
+    /// # Errors
+    ///
+    /// * Errors if there are no moves to redo.
+    /// * Errors if the move is no longer valid for the board.
+    pub fn redo(&mut self) -> ChuiResult<ChessMove> {
+        let mut chess_move: ChessMove = self
+            .redo_list
+            .last()
+            .cloned()
+            .ok_or_else(|| ChuiError::InvalidMove("There are no moves to redo".to_string()))?;
+
+        chess_move.validate_move_for_board(&self.board)?;
+        self.play_move(chess_move)?;
+        self.redo_list.pop();
+
+        Ok(self
+            .move_list
+            .last()
+            .cloned()
+            .expect("The move was just played"))
+    }
+
+    /// Are there any moves to undo?
+    pub fn can_undo(&self) -> bool {
+        !self.move_history.is_empty()
+    }
+
+    /// Are there any moves to redo?
+    pub fn can_redo(&self) -> bool {
+        !self.redo_list.is_empty()
+    }
+
+    /// Get the record of each applied move, from the first move to the last.
+    pub fn get_move_history(&self) -> &[MoveRecord] {
+        &self.move_history
+    }
+
+    /// Get the move tree of the game: the moves played, their variations and their
+    /// annotations, with a cursor on the move of the current position.
+    pub fn get_move_tree(&self) -> &MoveTree {
+        &self.move_tree
+    }
+
+    /// Get a node of the move tree, to annotate it with comments, Numeric Annotation
+    /// Glyphs, arrows and highlighted squares.
+    pub fn get_node_mut(&mut self, node_id: NodeId) -> Option<&mut MoveNode> {
+        self.move_tree.get_node_mut(node_id)
+    }
+
+    /// Get the moves of the main line of the move tree, from the first move to the last.
+    pub fn get_main_line(&self) -> Vec<ChessMove> {
+        self.move_tree
+            .get_main_line()
+            .into_iter()
+            .filter_map(|node_id| self.move_tree.get_node(node_id)?.get_move().cloned())
+            .collect()
+    }
+
+    /// Get the moves of the main line of the move tree in Standard Algebraic Notation,
+    /// from the first move to the last.
+    pub fn get_main_line_san(&self) -> Vec<String> {
+        self.move_tree
+            .get_main_line()
+            .into_iter()
+            .filter_map(|node_id| Some(self.move_tree.get_node(node_id)?.get_san()?.to_string()))
+            .collect()
+    }
+
+    /// Go to the position after the move of a node of the move tree (or to the start
+    /// position for `MoveTree::ROOT`). The moves are taken back to the last move shared
+    /// with the line of the node, then the moves of that line are played. The moves that
+    /// were undone are forgotten.
+    ///
+    /// # Errors
+    ///
+    /// * Errors if the move tree has no such node.
+    pub fn goto(&mut self, node_id: NodeId) -> ChuiResult<()> {
+        let path: Vec<NodeId> = self.move_tree.get_path(node_id)?;
+        let cursor_path: Vec<NodeId> = self.move_tree.get_path(self.move_tree.get_cursor())?;
+        let shared: usize = path
+            .iter()
+            .zip(cursor_path.iter())
+            .take_while(|(node, cursor_node)| node == cursor_node)
+            .count();
+
+        for _ in shared..cursor_path.len() {
+            self.take_back()?;
+        }
+
+        for node_id in path[shared..].iter() {
+            let mut chess_move: ChessMove = self
+                .move_tree
+                .get_node(*node_id)
+                .and_then(MoveNode::get_move)
+                .cloned()
+                .expect("The node is on the path");
+            chess_move.validate_move_for_board(&self.board)?;
+            self.play_move(chess_move)?;
+        }
+
+        self.redo_list.clear();
+
+        Ok(())
+    }
+
+    /// Add `the_move` as a variation of the move of a node: played instead of it, after
+    /// the same previous move. The variation is added after the other variations of the
+    /// move, and the game goes to the position after it. Returns the node of the
+    /// variation, which is the node of the move already played when `the_move` was
+    /// played from this position before.
+    ///
+    /// # Errors
+    ///
+    /// * Errors if the move tree has no such node, or if it is the root node.
+    /// * Errors if `the_move` cannot be parsed or played (see `parse()`). The game stays
+    ///   at the current position.
+    pub fn add_variation(&mut self, node_id: NodeId, the_move: &str) -> ChuiResult<NodeId> {
+        let parent: NodeId = self
+            .move_tree
+            .get_node(node_id)
+            .ok_or_else(|| {
+                ChuiError::IndexOutOfRange(format!("there is no node {} in the move tree", node_id))
+            })?
+            .get_parent()
+            .ok_or_else(|| ChuiError::InvalidInput("the start position has no move".to_string()))?;
+        let cursor: NodeId = self.move_tree.get_cursor();
+        self.goto(parent)?;
+
+        let played: ChuiResult<()> =
+            self.parse(the_move.to_string(), self.to_move)
+                .and_then(|chess_move| {
+                    self.set_current_move(Some(chess_move));
+                    match self.process_move()? {
+                        Some(chess_move) => self.play_move(chess_move),
+                        None => Ok(()),
+                    }
+                });
+
+        if let Err(error) = played {
+            self.current_move = None;
+            self.goto(cursor)?;
+            return Err(error);
+        }
+
+        Ok(self.move_tree.get_cursor())
+    }
+
+    /// Make the variation of a node the main line after its previous move (see
+    /// `MoveTree::promote_variation()`).
+    ///
+    /// # Errors
+    ///
+    /// * Errors if the move tree has no such node, or if it is the root node.
+    pub fn promote_variation(&mut self, node_id: NodeId) -> ChuiResult<()> {
+        self.move_tree.promote_variation(node_id)
+    }
+
+    /// Delete a node of the move tree and the moves played after it. When the current
+    /// position is in the deleted line, the game goes to the position before the deleted
+    /// move first.
+    ///
+    /// # Errors
+    ///
+    /// * Errors if the move tree has no such node, or if it is the root node.
+    pub fn delete_variation(&mut self, node_id: NodeId) -> ChuiResult<()> {
+        let parent: Option<NodeId> = self
+            .move_tree
+            .get_node(node_id)
+            .and_then(MoveNode::get_parent);
+
+        if let Some(parent) = parent {
+            if self
+                .move_tree
+                .is_in_line_of(self.move_tree.get_cursor(), node_id)
+            {
+                self.goto(parent)?;
+            }
+        }
+
+        self.move_tree.delete_variation(node_id)
+    }
+
+    /// Get each applied move in Standard Algebraic Notation, from the first move to the
+    /// last.
+    pub fn get_san_move_list(&self) -> Vec<String> {
+        self.move_history
+            .iter()
+            .map(|record| record.san.clone())
+            .collect()
+    }
+
+    /// Get the FEN of the position the game started from (X-FEN for a Chess960 game).
+    pub fn get_start_fen(&self) -> &str {
+        &self.start_fen
+    }
+
+    /// Get the PGN result of the game at the end of the main line, wherever the current
+    /// position is: `1-0` when White wins, `0-1` when Black wins, `1/2-1/2` for a draw, or
+    /// `*` when the game is still going on.
+    pub fn get_pgn_result(&self) -> &'static str {
+        let (win_condition, draw_condition, to_move) = self.get_main_line_result();
+
+        match (win_condition, draw_condition) {
+            (Some(WinCondition::BlackResigns), _) => "1-0",
+            (Some(WinCondition::WhiteResigns), _) => "0-1",
+            (Some(WinCondition::Checkmate), _) => match to_move {
+                Color::White => "0-1",
+                Color::Black => "1-0",
+            },
+            (None, Some(_)) => "1/2-1/2",
+            (None, None) => "*",
+        }
+    }
+
+    /// Get the win and draw conditions at the end of the main line, wherever the current
+    /// position is, and the `Color` to move there.
+    pub(crate) fn get_main_line_result(
+        &self,
+    ) -> (Option<WinCondition>, Option<DrawCondition>, Color) {
+        let end: NodeId = self
+            .move_tree
+            .get_main_line()
+            .last()
+            .copied()
+            .unwrap_or(MoveTree::ROOT);
+
+        match self.move_tree.get_node(end) {
+            Some(node) if end != self.move_tree.get_cursor() => {
+                let (win_condition, draw_condition) = node.get_result();
+                let to_move = node
+                    .get_move()
+                    .map_or(self.to_move, |chess_move| chess_move.to_move.opposite());
+                (win_condition, draw_condition, to_move)
+            }
+            _ => (self.win_condition, self.draw_condition, self.to_move),
+        }
+    }
+
+    /// Get the PGN tags of the game: the Seven Tag Roster, with the names of the players
+    /// and the result, then the ratings of the players (`WhiteElo` and `BlackElo`), the
+    /// `Variant` of a Chess960 game, and the start position (`SetUp` and `FEN`) when the
+    /// game did not start from the standard starting position.
+    pub fn get_pgn_tags(&self) -> Vec<(String, String)> {
+        let name =
+            |player: &Player| -> String { player.name.clone().unwrap_or_else(|| "?".to_string()) };
+        let mut tags: Vec<(String, String)> = vec![
+            ("Event".to_string(), "?".to_string()),
+            ("Site".to_string(), "?".to_string()),
+            ("Date".to_string(), "????.??.??".to_string()),
+            ("Round".to_string(), "?".to_string()),
+            ("White".to_string(), name(&self.white)),
+            ("Black".to_string(), name(&self.black)),
+            ("Result".to_string(), self.get_pgn_result().to_string()),
+        ];
+
+        if let Some(rating) = self.white.rating {
+            tags.push(("WhiteElo".to_string(), rating.to_string()));
+        }
+        if let Some(rating) = self.black.rating {
+            tags.push(("BlackElo".to_string(), rating.to_string()));
+        }
+        if self.board.is_chess960() {
+            tags.push(("Variant".to_string(), "Chess960".to_string()));
+        }
+        if self.start_fen != Fen::STARTING_POSITION {
+            tags.push(("SetUp".to_string(), "1".to_string()));
+            tags.push(("FEN".to_string(), self.start_fen.clone()));
+        }
+
+        tags
+    }
+
+    /// Write the game as PGN text in export format (see `PgnWriter`): the tags of
+    /// `get_pgn_tags()`, then the main line of the move tree with its annotations and
+    /// variations, wrapped at 80 columns, and the result at the end of the main line.
+    pub fn to_pgn(&self) -> String {
+        let (move_number, to_move) = PgnWriter::get_first_move(self);
+
+        PgnWriter::write(
+            &self.get_pgn_tags(),
+            self.move_tree
+                .get_node(MoveTree::ROOT)
+                .map_or(&[][..], |root| &root.pre_comments),
+            &PgnWriter::get_moves(&self.move_tree),
+            move_number,
+            to_move,
+            self.get_pgn_result(),
+        )
+    }
+
+    /// Get the list of moves played, in Figurine Algebraic Notation (e.g., `♘f3`), with the
+    /// figurines of the color that played each move.
+    pub fn get_fan_move_list(&self) -> Vec<String> {
+        self.move_history
+            .iter()
+            .map(|record| ChessMove::san_to_fan(&record.san, record.chess_move.to_move))
+            .collect()
+    }
+
+    /// Get the list of moves played, in Standard Algebraic Notation with the piece letters
+    /// of `locale` (e.g., `Sf3` in German).
+    pub fn get_san_move_list_for_locale(&self, locale: Locale) -> Vec<String> {
+        self.move_history
+            .iter()
+            .map(|record| {
+                Locale::English
+                    .translate(&record.san, locale)
+                    .expect("the move list has English piece letters")
+            })
+            .collect()
+    }
+
+    /// Count the current position in the position record.
+    fn record_position(&mut self) {
+        let count = self.position_record.entry(self.board.hash()).or_insert(0);
+        *count = count.saturating_add(1);
+    }
+
+    /// Remove one count of the current position from the position record.
+    fn forget_position(&mut self) {
+        let key = self.board.hash();
+
+        if let Some(count) = self.position_record.get_mut(&key) {
+            *count = count.saturating_sub(1);
+
+            if *count == 0 {
+                self.position_record.remove(&key);
+            }
+        }
+    }
+
+    /// Get the number of times the current position has occurred in the game.
+    pub fn get_position_count(&self) -> u8 {
+        self.position_record
+            .get(&self.board.hash())
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Determine whether the `Color` to move is in check, checkmated, or stalemated, and
+    /// record a checkmate or stalemate as the result of the game. Otherwise, record a draw
+    /// by fivefold repetition, the seventy-five move rule, or insufficient material. Returns
+    /// the check (or mate) to record with the move that was just played.
+    fn update_game_result(&mut self) -> Option<Check> {
+        let is_in_check = self.is_in_check();
+
+        if !self.legal_moves().is_empty() {
+            if let Some(draw_condition) = self.get_automatic_draw() {
+                self.is_draw = true;
+                self.draw_condition = Some(draw_condition);
+            }
+
+            return is_in_check.then_some(Check::Check);
+        }
+
+        if is_in_check {
+            match self.to_move {
+                Color::White => self.black_wins = true,
+                Color::Black => self.white_wins = true,
+            }
+            self.win_condition = Some(WinCondition::Checkmate);
+
+            Some(Check::Mate)
+        } else {
+            self.is_draw = true;
+            self.draw_condition = Some(DrawCondition::Stalemate);
+
+            None
+        }
+    }
+
+    /// Get the draw condition that ends the game without either player needing to claim
+    /// it, if any.
+    fn get_automatic_draw(&self) -> Option<DrawCondition> {
+        if self.get_position_count() >= 5 {
+            Some(DrawCondition::FifthRepetition)
+        } else if self.half_move_clock >= 150 {
+            Some(DrawCondition::SeventyFiveMoveRule)
+        } else if self.board.has_insufficient_material() {
+            Some(DrawCondition::InsufficientMaterial)
+        } else {
+            None
+        }
+    }
+
+    /// Get the draw condition that the `Color` to move may claim, if any. A draw may be
+    /// claimed when the current position has occurred three times, or when 50 moves have
+    /// been made by each player with no piece capture or pawn move.
+    pub fn get_claimable_draw(&self) -> Option<DrawCondition> {
+        if self.is_game_over() {
+            None
+        } else if self.get_position_count() >= 3 {
+            Some(DrawCondition::ThirdRepitition)
+        } else if self.half_move_clock >= 100 {
+            Some(DrawCondition::FiftyMoveRule)
+        } else {
+            None
+        }
+    }
+
+    /// Claim a draw by threefold repetition or the fifty move rule. When the claim is
+    /// valid, the game ends in a draw and the draw condition is returned.
+    ///
+    /// # Errors
+    ///
+    /// * Errors if the game is over.
+    /// * Errors if the position has not occurred three times and fewer than 50 moves have
+    ///   been made by each player since the last piece capture or pawn move.
+    pub fn claim_draw(&mut self) -> ChuiResult<DrawCondition> {
+        if self.is_game_over() {
+            return Err(ChuiError::GameOver(
+                "A draw cannot be claimed once the game has ended".to_string(),
+            ));
+        }
+
+        let draw_condition = self.get_claimable_draw().ok_or_else(|| {
+            ChuiError::InvalidDrawClaim(format!(
+                "The position has occurred {} time(s) and the half-move clock is {}",
+                self.get_position_count(),
+                self.half_move_clock
+            ))
+        })?;
+
+        self.is_draw = true;
+        self.draw_condition = Some(draw_condition);
+
+        Ok(draw_condition)
+    }
+
+    /// Is the `Color` to move in check?
+    pub fn is_in_check(&self) -> bool {
+        self.board.is_in_check(self.to_move)
+    }
+
+    /// Has the game ended in a win, loss, or draw?
+    pub const fn is_game_over(&self) -> bool {
+        self.white_wins || self.black_wins || self.is_draw
+    }
+
+    /// End the game with a resignation, or else a draw, that does not follow from the
+    /// moves played. The game is left as it is when both conditions are `None`.
+    pub(crate) fn set_result(
+        &mut self,
+        win_condition: Option<WinCondition>,
+        draw_condition: Option<DrawCondition>,
+    ) {
+        match (win_condition, draw_condition) {
+            (Some(WinCondition::WhiteResigns), _) => self.resign(Color::White),
+            (Some(WinCondition::BlackResigns), _) => self.resign(Color::Black),
+            (_, Some(draw_condition)) => {
+                self.is_draw = true;
+                self.draw_condition = Some(draw_condition);
+            }
+            _ => {}
+        }
+    }
+
+    /// The player of the given `Color` resigns, and the other player wins.
+    pub fn resign(&mut self, color: Color) {
+        match color {
+            Color::White => {
+                self.black_wins = true;
+                self.win_condition = Some(WinCondition::WhiteResigns);
+            }
+            Color::Black => {
+                self.white_wins = true;
+                self.win_condition = Some(WinCondition::BlackResigns);
+            }
+        }
+        self.draw_condition = None;
+    }
+
+    /// Get all of the legal moves for the `Color` to move. See `Board::legal_moves()`.
+    pub fn legal_moves(&self) -> Vec<ChessMove> {
+        self.board.legal_moves(self.to_move)
+    }
+
+    /// Count the leaf nodes of the legal move tree of the current position to `depth`
+    /// plies. See `Perft::perft()`.
+    ///
+    /// # Errors
+    ///
+    /// * Errors if a legal move cannot be played on the board.
+    pub fn perft(&self, depth: usize) -> ChuiResult<u64> {
+        Perft::perft(&self.board, self.to_move, depth)
+    }
+
+    /// Count the leaf nodes of the legal move tree of the current position to `depth`
+    /// plies for each legal move. See `Perft::divide()`.
+    ///
+    /// # Errors
+    ///
+    /// * Errors if a legal move cannot be played on the board.
+    pub fn divide(&self, depth: usize) -> ChuiResult<Vec<(ChessMove, u64)>> {
+        Perft::divide(&self.board, self.to_move, depth)
+    }
+
+    /// Get the report of the perft divide of the current position to `depth`. See
+    /// `Perft::divide_report()`.
+    ///
+    /// # Errors
+    ///
+    /// * Errors if a legal move cannot be played on the board.
+    pub fn divide_report(&self, depth: usize) -> ChuiResult<String> {
+        Perft::divide_report(&self.board, self.to_move, depth)
+    }
+
     /// Process the chess move.
     ///
     /// # Errors
@@ -402,6 +1211,7 @@ impl Game {
         } else {
             self.to_move = Color::White;
         }
+        self.board.set_to_move(self.to_move);
     }
 
     /// Set the current move.
@@ -413,6 +1223,14 @@ impl Game {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::coord::*;
+    use crate::testing::{new_game, play};
+
+    fn piece_on(game: &Game, coord: (char, u8)) -> Option<Piece> {
+        game.board
+            .get_position()
+            .get_piece(Coord::try_from(coord).ok())
+    }
 
     #[test]
     #[should_panic]
@@ -436,4 +1254,560 @@ mod tests {
             panic!("{}", error);
         }
     }
+
+    #[test]
+    fn apply_move_plays_moves() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Algebraic);
+        play(&mut game, &["e4", "e5", "Nf3", "Nc6"])?;
+
+        assert_eq!(piece_on(&game, E4), Some(Piece::white_pawn(E4)?));
+        assert_eq!(piece_on(&game, F3), Some(Piece::white_knight(F3)?));
+        assert_eq!(piece_on(&game, C6), Some(Piece::black_knight(C6)?));
+        assert_eq!(piece_on(&game, E2), None);
+        assert_eq!(piece_on(&game, G1), None);
+        assert_eq!(game.to_move, Color::White);
+        assert_eq!(game.move_list.len(), 4);
+        assert_eq!(game.half_move_counter, 4);
+        assert_eq!(game.half_move_clock, 2);
+        assert_eq!(game.move_counter, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn apply_move_rejects_invalid_move() {
+        let mut game = new_game(ParserEngine::Algebraic);
+        let board = game.board;
+
+        assert!(play(&mut game, &["e5"]).is_err());
+        assert!(play(&mut game, &["Nd2"]).is_err());
+        assert_eq!(game.board, board);
+        assert_eq!(game.to_move, Color::White);
+        assert!(game.move_list.is_empty());
+    }
+
+    #[test]
+    fn apply_move_captures() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Algebraic);
+        play(&mut game, &["e4", "d5", "Nc3", "Nf6", "exd5"])?;
+
+        assert_eq!(piece_on(&game, D5), Some(Piece::white_pawn(D5)?));
+        assert_eq!(game.captured_pieces, vec![Piece::black_pawn(D5)?]);
+        assert_eq!(game.half_move_clock, 0);
+
+        // Both the Knight on c3 and the Knight on f6 can reach d5, only one of them is Black's.
+        play(&mut game, &["Nxd5"])?;
+        assert_eq!(piece_on(&game, D5), Some(Piece::black_knight(D5)?));
+        assert_eq!(game.captured_pieces.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn apply_move_castles() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Algebraic);
+        play(&mut game, &["e4", "e5", "Nf3", "Nc6", "Bc4", "Bc5", "O-O"])?;
+
+        assert_eq!(piece_on(&game, G1), Some(Piece::white_king(G1)?));
+        assert_eq!(piece_on(&game, F1), Some(Piece::white_rook(F1)?));
+        assert_eq!(piece_on(&game, E1), None);
+        assert_eq!(piece_on(&game, H1), None);
+        assert!(!game.board.white_can_castle_kingside);
+        assert!(!game.board.white_can_castle_queenside);
+        assert!(game.board.black_can_castle_kingside);
+        assert!(game.move_list.last().unwrap().is_castling_king());
+        Ok(())
+    }
+
+    #[test]
+    fn apply_move_rook_move_loses_castling_right() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Algebraic);
+        play(&mut game, &["a4", "h5", "Ra3", "Rh6"])?;
+
+        assert!(!game.board.white_can_castle_queenside);
+        assert!(game.board.white_can_castle_kingside);
+        assert!(!game.board.black_can_castle_kingside);
+        assert!(game.board.black_can_castle_queenside);
+        Ok(())
+    }
+
+    #[test]
+    fn apply_move_en_passant() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Algebraic);
+        play(&mut game, &["e4", "a6", "e5", "d5"])?;
+
+        assert_eq!(game.board.get_en_passant_coord(), Coord::try_from(D6).ok());
+
+        play(&mut game, &["exd6"])?;
+
+        assert_eq!(piece_on(&game, D6), Some(Piece::white_pawn(D6)?));
+        assert_eq!(piece_on(&game, D5), None);
+        assert_eq!(game.captured_pieces, vec![Piece::black_pawn(D5)?]);
+        assert_eq!(game.board.get_en_passant_coord(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn apply_move_promotes() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Algebraic);
+        game.board = Board::new(Variant::Empty);
+        for piece in [
+            Piece::white_king(E1)?,
+            Piece::black_king(E8)?,
+            Piece::white_pawn(B7)?,
+            Piece::black_rook(A8)?,
+        ] {
+            game.board
+                .get_position_mut()
+                .put_piece(Some(piece), Some(piece.get_coord()));
+        }
+
+        assert!(play(&mut game, &["b8"]).is_err());
+        play(&mut game, &["bxa8Q"])?;
+
+        assert_eq!(piece_on(&game, A8), Some(Piece::white_queen(A8)?));
+        assert_eq!(piece_on(&game, B7), None);
+        assert_eq!(game.captured_pieces, vec![Piece::black_rook(A8)?]);
+        assert!(!game.board.black_can_castle_queenside);
+        Ok(())
+    }
+
+    #[test]
+    fn apply_move_detects_check() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Algebraic);
+        play(&mut game, &["e4", "f6", "Qh5"])?;
+
+        assert!(game.is_in_check());
+        assert_eq!(game.move_list.last().unwrap().check, Some(Check::Check));
+        assert!(!game.is_game_over());
+
+        play(&mut game, &["g6"])?;
+        assert!(!game.is_in_check());
+        assert_eq!(game.move_list.last().unwrap().check, None);
+        Ok(())
+    }
+
+    #[test]
+    fn apply_move_detects_checkmate() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Algebraic);
+        play(&mut game, &["f3", "e5", "g4", "Qh4"])?;
+
+        assert_eq!(game.move_list.last().unwrap().check, Some(Check::Mate));
+        assert!(game.is_game_over());
+        assert!(game.black_wins);
+        assert!(!game.white_wins);
+        assert_eq!(game.win_condition, Some(WinCondition::Checkmate));
+
+        let board = game.board;
+        assert!(matches!(
+            play(&mut game, &["a3"]),
+            Err(ChuiError::GameOver(_))
+        ));
+        assert_eq!(game.board, board);
+        Ok(())
+    }
+
+    #[test]
+    fn apply_move_detects_stalemate() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Algebraic);
+        game.set_fen("7k/8/8/6Q1/8/8/8/K7 w - - 0 1")?;
+        play(&mut game, &["Qg6"])?;
+
+        assert_eq!(game.move_list.last().unwrap().check, None);
+        assert!(game.is_game_over());
+        assert!(game.is_draw);
+        assert_eq!(game.draw_condition, Some(DrawCondition::Stalemate));
+        assert!(matches!(
+            play(&mut game, &["Kh7"]),
+            Err(ChuiError::GameOver(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn set_fen_detects_checkmate() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Algebraic);
+        game.set_fen("rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3")?;
+
+        assert!(game.black_wins);
+        assert!(game.is_game_over());
+        Ok(())
+    }
+
+    #[test]
+    fn resign_ends_game() {
+        let mut game = new_game(ParserEngine::Algebraic);
+        game.resign(Color::White);
+
+        assert!(game.black_wins);
+        assert_eq!(game.win_condition, Some(WinCondition::WhiteResigns));
+        assert!(matches!(
+            play(&mut game, &["e4"]),
+            Err(ChuiError::GameOver(_))
+        ));
+    }
+
+    #[test]
+    fn undo_restores_game() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Algebraic);
+        let board = game.board;
+        let position_record = game.position_record.clone();
+        play(&mut game, &["e4", "d5", "exd5"])?;
+
+        assert_eq!(game.undo()?.to_coord, Coord::try_from(D5).ok());
+        assert!(game.captured_pieces.is_empty());
+        assert_eq!(piece_on(&game, D5), Some(Piece::black_pawn(D5)?));
+        assert_eq!(game.to_move, Color::White);
+        assert_eq!(game.move_counter, 2);
+        assert_eq!(game.board.get_en_passant_coord(), Coord::try_from(D6).ok());
+
+        game.undo()?;
+        game.undo()?;
+        assert_eq!(game.board, board);
+        assert_eq!(game.position_record, position_record);
+        assert_eq!(game.half_move_counter, 0);
+        assert_eq!(game.move_counter, 1);
+        assert!(game.move_list.is_empty());
+        assert!(!game.can_undo());
+        assert!(matches!(game.undo(), Err(ChuiError::InvalidMove(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn undo_every_legal_move() -> ChuiResult<()> {
+        for fen in [
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+            "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+        ] {
+            let mut game = new_game(ParserEngine::Algebraic);
+            game.set_fen(fen)?;
+            let board = game.board;
+
+            for chess_move in game.legal_moves() {
+                game.set_current_move(Some(chess_move));
+                game.apply_move()?;
+                game.undo()?;
+
+                assert_eq!(game.board, board);
+                assert_eq!(Fen::get_fen(&game), fen);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn undo_checkmate() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Algebraic);
+        play(&mut game, &["f3", "e5", "g4", "Qh4"])?;
+        assert!(game.is_game_over());
+
+        game.undo()?;
+        assert!(!game.is_game_over());
+        assert!(!game.black_wins);
+        assert_eq!(game.win_condition, None);
+        play(&mut game, &["Qg5"])?;
+        Ok(())
+    }
+
+    #[test]
+    fn redo_replays_moves() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Algebraic);
+        play(&mut game, &["e4", "e5", "Nf3"])?;
+        let board = game.board;
+        let fen = Fen::get_fen(&game);
+
+        game.undo()?;
+        game.undo()?;
+        assert!(game.can_redo());
+        assert_eq!(game.redo()?.to_coord, Coord::try_from(E5).ok());
+        assert_eq!(game.redo()?.to_coord, Coord::try_from(F3).ok());
+        assert_eq!(game.board, board);
+        assert_eq!(Fen::get_fen(&game), fen);
+        assert!(matches!(game.redo(), Err(ChuiError::InvalidMove(_))));
+
+        // A new move replaces the moves that were undone.
+        game.undo()?;
+        play(&mut game, &["Nc3"])?;
+        assert!(!game.can_redo());
+        Ok(())
+    }
+
+    #[test]
+    fn position_record_counts_transpositions() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Algebraic);
+        let hash = game.board.hash();
+        play(&mut game, &["Nf3", "Nf6", "Ng1", "Ng8"])?;
+
+        assert_eq!(game.board.hash(), hash);
+        assert_eq!(game.position_record.get(&hash), Some(&2));
+
+        play(&mut game, &["Nc3"])?;
+        assert_ne!(game.board.hash(), hash);
+        game.undo()?;
+        assert_eq!(game.board.hash(), hash);
+        assert_eq!(game.position_record.get(&hash), Some(&2));
+        Ok(())
+    }
+
+    #[test]
+    fn hash_matches_fen() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Algebraic);
+
+        for the_move in [
+            "e4", "d5", "e5", "f5", "exf6", "Nc6", "Nf3", "Bd7", "Bb5", "e6", "O-O",
+        ] {
+            play(&mut game, &[the_move])?;
+            assert_eq!(
+                game.board.hash(),
+                Board::from_fen(&Fen::get_fen(&game))?.hash()
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn repetition_draws() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Algebraic);
+        let cycle = ["Nf3", "Nf6", "Ng1", "Ng8"];
+        assert!(matches!(
+            game.claim_draw(),
+            Err(ChuiError::InvalidDrawClaim(_))
+        ));
+
+        play(&mut game, &cycle)?;
+        assert_eq!(game.get_position_count(), 2);
+        assert_eq!(game.get_claimable_draw(), None);
+
+        play(&mut game, &cycle)?;
+        assert_eq!(game.get_position_count(), 3);
+        assert_eq!(
+            game.get_claimable_draw(),
+            Some(DrawCondition::ThirdRepitition)
+        );
+        assert!(!game.is_game_over());
+
+        play(&mut game, &cycle)?;
+        play(&mut game, &cycle[..3])?;
+        assert!(!game.is_game_over());
+        play(&mut game, &cycle[3..])?;
+        assert!(game.is_draw);
+        assert_eq!(game.draw_condition, Some(DrawCondition::FifthRepetition));
+
+        game.undo()?;
+        assert!(!game.is_game_over());
+        assert_eq!(game.claim_draw()?, DrawCondition::ThirdRepitition);
+        assert!(game.is_draw);
+        assert!(matches!(game.claim_draw(), Err(ChuiError::GameOver(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn move_rule_draws() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Algebraic);
+        game.set_fen("4k3/8/8/8/8/8/4P3/R3K3 w - - 98 80")?;
+        play(&mut game, &["Ra2"])?;
+        assert_eq!(game.get_claimable_draw(), None);
+        play(&mut game, &["Kd7"])?;
+        assert_eq!(
+            game.get_claimable_draw(),
+            Some(DrawCondition::FiftyMoveRule)
+        );
+
+        // A pawn move resets the clock.
+        play(&mut game, &["e4"])?;
+        assert_eq!(game.get_claimable_draw(), None);
+
+        game.set_fen("4k3/8/8/8/8/8/4P3/R3K3 w - - 149 100")?;
+        play(&mut game, &["Ra2"])?;
+        assert!(game.is_draw);
+        assert_eq!(
+            game.draw_condition,
+            Some(DrawCondition::SeventyFiveMoveRule)
+        );
+
+        // Checkmate takes precedence over the seventy-five move rule.
+        game.set_fen("6k1/5ppp/8/8/8/8/8/R5K1 w - - 149 100")?;
+        play(&mut game, &["Ra8"])?;
+        assert!(game.white_wins);
+        assert!(!game.is_draw);
+        Ok(())
+    }
+
+    #[test]
+    fn insufficient_material_draws() -> ChuiResult<()> {
+        for (fen, insufficient) in [
+            ("4k3/8/8/8/8/8/8/4K3 w - - 0 1", true),
+            ("4k3/8/8/8/8/8/8/2B1K3 w - - 0 1", true),
+            ("4k3/8/8/8/8/8/8/1n2K3 w - - 0 1", true),
+            ("4kb2/8/8/8/8/8/8/2B1K3 w - - 0 1", true),
+            ("4k3/8/8/8/8/8/8/2BBK3 w - - 0 1", false),
+            ("2b1k3/8/8/8/8/8/8/2B1K3 w - - 0 1", false),
+            ("4k3/8/8/8/8/8/8/1NN1K3 w - - 0 1", false),
+            ("1n2k3/8/8/8/8/8/8/1N2K3 w - - 0 1", false),
+            ("4k3/8/8/8/8/8/8/3RK3 w - - 0 1", false),
+            ("4k3/8/8/8/8/8/4P3/4K3 w - - 0 1", false),
+        ] {
+            assert_eq!(
+                Board::from_fen(fen)?.has_insufficient_material(),
+                insufficient,
+                "{}",
+                fen
+            );
+        }
+
+        let mut game = new_game(ParserEngine::Algebraic);
+        game.set_fen("4k3/8/8/8/8/8/8/4K3 w - - 0 1")?;
+        assert_eq!(
+            game.draw_condition,
+            Some(DrawCondition::InsufficientMaterial)
+        );
+
+        game.set_fen("4k3/8/8/8/8/8/3r4/2N1K3 w - - 0 1")?;
+        assert!(!game.is_game_over());
+        play(&mut game, &["Kxd2"])?;
+        assert!(game.is_draw);
+        assert_eq!(
+            game.draw_condition,
+            Some(DrawCondition::InsufficientMaterial)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn chess960_game() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Algebraic);
+        game.set_variant(Variant::Chess960(0));
+
+        assert!(game.board.is_chess960());
+        assert_eq!(
+            Fen::get_x_fen(&game),
+            "bbqnnrkr/pppppppp/8/8/8/8/PPPPPPPP/BBQNNRKR w KQkq - 0 1"
+        );
+        assert_eq!(
+            Fen::get_shredder_fen(&game),
+            "bbqnnrkr/pppppppp/8/8/8/8/PPPPPPPP/BBQNNRKR w HFhf - 0 1"
+        );
+        assert_eq!(game.legal_moves().len(), 20);
+        Ok(())
+    }
+
+    #[test]
+    fn chess960_castling_notation() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Algebraic);
+        game.set_variant(Variant::Chess960(0));
+        game.set_fen("1k5r/3p4/8/8/8/8/8/RK5R w KQk - 0 1")?;
+
+        play(&mut game, &["O-O-O"])?;
+        assert_eq!(piece_on(&game, C1), Some(Piece::white_king(C1)?));
+        assert_eq!(piece_on(&game, D1), Some(Piece::white_rook(D1)?));
+        assert_eq!(game.get_fen_castle(), "k");
+
+        play(&mut game, &["O-O"])?;
+        assert_eq!(piece_on(&game, G8), Some(Piece::black_king(G8)?));
+        assert_eq!(piece_on(&game, F8), Some(Piece::black_rook(F8)?));
+        assert_eq!(game.get_fen_castle(), "-");
+
+        game.undo()?;
+        game.undo()?;
+        assert_eq!(Fen::get_fen(&game), "1k5r/3p4/8/8/8/8/8/RK5R w KQk - 0 1");
+        Ok(())
+    }
+
+    #[test]
+    fn chess960_undo_every_legal_move() -> ChuiResult<()> {
+        for fen in [
+            "4k3/8/8/8/8/8/8/R4KR1 w KQ - 0 1",
+            "4k3/8/8/8/8/8/8/R5KR w KQ - 0 1",
+            "bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w KQkq - 2 9",
+        ] {
+            let mut game = new_game(ParserEngine::Algebraic);
+            game.set_variant(Variant::Chess960(0));
+            game.set_fen(fen)?;
+            let board = game.board;
+
+            for chess_move in game.legal_moves() {
+                game.set_current_move(Some(chess_move));
+                game.apply_move()?;
+                game.undo()?;
+
+                assert_eq!(game.board, board);
+                assert_eq!(Fen::get_fen(&game), fen);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn chess960_fen_castling_by_file() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Algebraic);
+        game.set_variant(Variant::Chess960(0));
+        game.set_fen("4k3/8/8/8/8/8/8/R1R1K2R w KC - 0 1")?;
+
+        assert_eq!(game.get_fen_castle(), "KC");
+        assert_eq!(game.get_shredder_fen_castle(), "HC");
+        Ok(())
+    }
+
+    #[test]
+    fn san_move_list() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Algebraic);
+        play(
+            &mut game,
+            &["e4", "e5", "Nf3", "Nc6", "Nc3", "Nf6", "Nd5", "Ne7", "Nxe5"],
+        )?;
+        play(&mut game, &["Nexd5", "exd5", "Nxd5", "Qf3", "Nb4", "Qxf7#"])?;
+        assert_eq!(
+            game.get_san_move_list(),
+            vec![
+                "e4", "e5", "Nf3", "Nc6", "Nc3", "Nf6", "Nd5", "Ne7", "Nxe5", "Nexd5", "exd5",
+                "Nxd5", "Qf3", "Nb4", "Qxf7#"
+            ]
+        );
+
+        game.undo()?;
+        game.redo()?;
+        assert_eq!(game.get_san_move_list().last().unwrap(), "Qxf7#");
+        Ok(())
+    }
+
+    #[test]
+    fn parse_resolves_moves_for_board() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Algebraic);
+        game.set_fen("4k3/8/8/3pP3/8/5N2/8/1N2K3 w - d6 0 1")?;
+
+        let the_move = game.parse("Nbd2".to_string(), game.to_move)?;
+        assert_eq!(the_move.from_coord, Coord::try_from(B1).ok());
+        assert_eq!(the_move.from_piece, piece_on(&game, B1));
+        assert_eq!(the_move.to_piece, None);
+
+        let the_move = game.parse("exd6".to_string(), game.to_move)?;
+        assert_eq!(the_move.from_coord, Coord::try_from(E5).ok());
+        assert_eq!(the_move.to_coord, Coord::try_from(D6).ok());
+        assert_eq!(the_move.to_piece, piece_on(&game, D5));
+        assert_eq!(the_move.check, None);
+
+        let reason = |game: &mut Game, the_move: &str| -> String {
+            match game.parse(the_move.to_string(), game.to_move) {
+                Err(ChuiError::InvalidMove(reason)) => reason,
+                result => panic!("{:?} should be an invalid move: {:?}", the_move, result),
+            }
+        };
+
+        assert_eq!(
+            reason(&mut game, "Nd2"),
+            "Ambiguous move: the White Knights on `b1`, `f3` can all move to `d2`; name the \
+            file or rank of the one to move"
+        );
+        assert_eq!(reason(&mut game, "Ne1"), "No White Knight can move to `e1`");
+
+        game.set_parser(ParserEngine::Coordinate);
+        assert_eq!(
+            reason(&mut game, "f3-e1"),
+            "The White Knight on `f3` cannot move to `e1`, which is occupied by the White King"
+        );
+        assert_eq!(
+            reason(&mut game, "f3-f4"),
+            "The White Knight on `f3` cannot move to or capture on `f4`"
+        );
+        Ok(())
+    }
 }
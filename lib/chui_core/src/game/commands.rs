@@ -11,7 +11,7 @@ pub fn switch_parser(game: &mut Game, command: &Command) {
         println!("Current parser: {}", game.parser.name());
         command.display_help(context);
         println!();
-        println!("Select option. (1-8, b to go back, h for help)");
+        println!("Select option. (1-9, b to go back, h for help)");
 
         let input: String = Game::get_input();
 
@@ -23,26 +23,27 @@ pub fn switch_parser(game: &mut Game, command: &Command) {
                 break;
             }
 
-            // Some(CommandKind::SwitchToConciseReversibleParser) => {
-            //     let parser_engine = ParserEngine::ConciseReversible;
-            //     println!("Switching parser to {:?}.", parser_engine);
-            //     game.set_parser(parser_engine);
-            //     break;
-            // }
-
-            // Some(CommandKind::SwitchToCoordinateParser) => {
-            //     let parser_engine = ParserEngine::Coordinate;
-            //     println!("Switching parser to {:?}.", parser_engine);
-            //     game.set_parser(parser_engine);
-            //     break;
-            // }
-
-            // Some(CommandKind::SwitchToDescriptiveParser) => {
-            //     let parser_engine = ParserEngine::Descriptive;
-            //     println!("Switching parser to {:?}.", parser_engine);
-            //     game.set_parser(parser_engine);
-            //     break;
-            // }
+            Some(CommandKind::SwitchToConciseReversibleParser) => {
+                let parser_engine = ParserEngine::ConciseReversible;
+                println!("Switching parser to {:?}.", parser_engine);
+                game.set_parser(parser_engine);
+                break;
+            }
+
+            Some(CommandKind::SwitchToCoordinateParser) => {
+                let parser_engine = ParserEngine::Coordinate;
+                println!("Switching parser to {:?}.", parser_engine);
+                game.set_parser(parser_engine);
+                break;
+            }
+
+            Some(CommandKind::SwitchToDescriptiveParser) => {
+                let parser_engine = ParserEngine::Descriptive;
+                println!("Switching parser to {:?}.", parser_engine);
+                game.set_parser(parser_engine);
+                break;
+            }
+
             Some(CommandKind::SwitchToICCFParser) => {
                 let parser_engine = ParserEngine::ICCF;
                 println!("Switching parser to {:?}.", parser_engine);
@@ -50,26 +51,34 @@ pub fn switch_parser(game: &mut Game, command: &Command) {
                 break;
             }
 
-            // Some(CommandKind::SwitchToLongAlgebraicParser) => {
-            //     let parser_engine = ParserEngine::LongAlgebraic;
-            //     println!("Switching parser to {:?}.", parser_engine);
-            //     game.set_parser(parser_engine);
-            //     break;
-            // }
-
-            // Some(CommandKind::SwitchToReversibleAlgebraicParser) => {
-            //     let parser_engine = ParserEngine::ReversibleAlgebraic;
-            //     println!("Switching parser to {:?}.", parser_engine);
-            //     game.set_parser(parser_engine);
-            //     break;
-            // }
-
-            // Some(CommandKind::SwitchToSmithParser) => {
-            //     let parser_engine = ParserEngine::Smith;
-            //     println!("Switching parser to {:?}.", parser_engine);
-            //     game.set_parser(parser_engine);
-            //     break;
-            // }
+            Some(CommandKind::SwitchToLongAlgebraicParser) => {
+                let parser_engine = ParserEngine::LongAlgebraic;
+                println!("Switching parser to {:?}.", parser_engine);
+                game.set_parser(parser_engine);
+                break;
+            }
+
+            Some(CommandKind::SwitchToReversibleAlgebraicParser) => {
+                let parser_engine = ParserEngine::ReversibleAlgebraic;
+                println!("Switching parser to {:?}.", parser_engine);
+                game.set_parser(parser_engine);
+                break;
+            }
+
+            Some(CommandKind::SwitchToSmithParser) => {
+                let parser_engine = ParserEngine::Smith;
+                println!("Switching parser to {:?}.", parser_engine);
+                game.set_parser(parser_engine);
+                break;
+            }
+
+            Some(CommandKind::SwitchToUciParser) => {
+                let parser_engine = ParserEngine::Uci;
+                println!("Switching parser to {:?}.", parser_engine);
+                game.set_parser(parser_engine);
+                break;
+            }
+
             Some(CommandKind::Help) => {
                 continue;
             }
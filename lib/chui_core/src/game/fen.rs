@@ -1,6 +1,6 @@
 //! FEN module.
 
-use super::super::{Color, Fen, Game};
+use super::super::{Castling, Color, Coord, Fen, Game, Position};
 
 /// Get the FEN to move character.
 pub fn get_fen_to_move(game: &Game) -> String {
@@ -10,27 +10,79 @@ pub fn get_fen_to_move(game: &Game) -> String {
     }
 }
 
-/// Get the FEN for castle characters.
-pub fn get_fen_castle(game: &Game) -> String {
-    let mut castle = String::new();
+/// Get the castling rights of `game` as the letter for each right, given a function
+/// mapping the `Color`, side and castling Rook file of a right to its letter.
+fn get_castle<F>(game: &Game, letter: F) -> String
+where
+    F: Fn(Color, Castling, u8) -> char,
+{
+    let castle: String = [Color::White, Color::Black]
+        .into_iter()
+        .flat_map(|color| [(color, Castling::King), (color, Castling::Queen)])
+        .filter(|(color, castling)| game.board.can_castle(*color, *castling))
+        .filter_map(|(color, castling)| {
+            game.board
+                .get_castling_rook_coord(color, castling)
+                .ok()
+                .map(|coord| letter(color, castling, coord.get_file()))
+        })
+        .collect();
 
-    if game.board.white_can_castle_kingside {
-        castle = format!("{}{}", castle, "K");
+    if castle.is_empty() {
+        "-".to_string()
+    } else {
+        castle
     }
+}
 
-    if game.board.white_can_castle_queenside {
-        castle = format!("{}{}", castle, "Q");
-    }
+/// Get the letter of a castling Rook's file, in upper case for White.
+fn get_file_letter(color: Color, file: u8) -> char {
+    let letter = char::from(b'a' + file);
 
-    if game.board.black_can_castle_kingside {
-        castle = format!("{}{}", castle, "k");
+    match color {
+        Color::White => letter.to_ascii_uppercase(),
+        Color::Black => letter,
     }
+}
 
-    if game.board.black_can_castle_queenside {
-        castle = format!("{}{}", castle, "q");
-    }
+/// Get the FEN for castle characters. As with X-FEN, a castling Rook is named by its side of
+/// the board (`KQkq`), unless there is another Rook further out on that side of the King
+/// (as may happen in Chess960), in which case it is named by its file.
+pub fn get_fen_castle(game: &Game) -> String {
+    let position = game.board.get_position();
 
-    castle
+    get_castle(game, |color, castling, file| {
+        let rank: u8 = match color {
+            Color::White => 0,
+            Color::Black => 7,
+        };
+        let mut outer_files = match castling {
+            Castling::King => file + 1..8,
+            Castling::Queen => 0..file,
+        };
+        let is_outermost = !outer_files.any(|outer_file| {
+            position
+                .get_piece(Coord::new(outer_file, rank).ok())
+                .is_some_and(|piece| piece.is_rook() && piece.get_color() == color)
+        });
+
+        let letter = match (is_outermost, castling) {
+            (true, Castling::King) => 'K',
+            (true, Castling::Queen) => 'Q',
+            (false, _) => return get_file_letter(color, file),
+        };
+
+        match color {
+            Color::White => letter,
+            Color::Black => letter.to_ascii_lowercase(),
+        }
+    })
+}
+
+/// Get the Shredder-FEN for castle characters. The castling Rooks are
+/// named by their file instead of by their side of the board.
+pub fn get_shredder_fen_castle(game: &Game) -> String {
+    get_castle(game, |color, _, file| get_file_letter(color, file))
 }
 
 /// Get the FEN en passant square.
@@ -40,9 +92,12 @@ pub fn get_fen_en_passant(game: &Game) -> String {
         .map_or_else(|| "-".to_string(), |coord| coord.to_string())
 }
 
-/// Get the X-FEN en passant square.
+/// Get the X-FEN en passant square. Unlike FEN, X-FEN only records the
+/// en passant target square if an en passant capture is possible.
 pub fn get_x_fen_en_passant(game: &Game) -> String {
-    get_fen_en_passant(game)
+    game.board
+        .get_true_en_passant_coord()
+        .map_or_else(|| "-".to_string(), |coord| coord.to_string())
 }
 
 /// Get the FEN half-move clock.
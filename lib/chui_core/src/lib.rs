@@ -6,38 +6,54 @@ mod command;
 mod condition;
 mod constants;
 mod coordinate;
+mod epd;
 mod fen;
 mod game;
+mod locale;
 mod move_generator;
+mod move_record;
+mod move_tree;
 mod parser;
+mod perft;
+mod pgn;
 mod piece;
 mod player;
 mod position;
 mod result;
+#[cfg(feature = "serde")]
+mod serialization;
+mod testing;
 mod traits;
 mod util;
 mod variant;
+mod zobrist;
 
 /// Chui Core Prelude
 pub mod prelude {
     use super::*;
+    pub use ::rand;
     pub use board::Board;
     pub use chess_move::{Castling, Check, ChessMove, MoveType};
     pub use command::{Command, CommandContext, CommandKind};
     pub use condition::{DrawCondition, WinCondition};
     pub use constants::*;
     pub use coordinate::{Coord, NonMaxU8};
+    pub use epd::{Epd, EpdOperation};
     pub use fen::Fen;
     pub use game::Game;
+    pub use locale::Locale;
     pub use move_generator::MoveGenerator;
+    pub use move_record::MoveRecord;
+    pub use move_tree::{Arrow, Highlight, MarkColor, MoveNode, MoveTree, NodeId};
     pub use parser::ParserEngine;
-    pub use piece::{Color, Piece, PieceKind};
+    pub use perft::Perft;
+    pub use pgn::{PgnGame, PgnMove, PgnReader, PgnWriter};
+    pub use piece::{Color, Piece, PieceKind, PieceStyle};
     pub use player::Player;
     pub use position::{
         Array1D, Array2D, ArrayBitPosition, BitPosition, BitSetPosition, BitmaskArray,
         Easy1DPosition, Easy2DPosition, EnumArray, EnumPosition, PieceEnum,
     };
-    pub use rand;
     pub use result::{ChuiError, ChuiResult};
     pub use traits::{Coordinate, Parser, Position};
     pub use util::{
@@ -45,6 +61,7 @@ pub mod prelude {
         easy_2d_position_copy, enum_position_copy, gen_coords, num_sep, piece_operation,
     };
     pub use variant::Variant;
+    pub use zobrist::Zobrist;
 }
 
 use prelude::*;
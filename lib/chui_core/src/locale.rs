@@ -0,0 +1,264 @@
+//! Notation locales.
+
+use std::convert::TryFrom;
+
+use crate::prelude::*;
+
+/// The languages of the piece letters in algebraic notation. Only the piece letters
+/// change: squares, captures, castling and check marks are written the same in every
+/// language (e.g., `Nxf3+` in English is `Sxf3+` in German and `Cxf3+` in French).
+#[derive(Debug, Copy, Clone, Default, PartialOrd, Ord, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Locale {
+    /// English: `K`, `Q`, `R`, `B`, `N`.
+    #[default]
+    English,
+
+    /// German: `K` (König), `D` (Dame), `T` (Turm), `L` (Läufer), `S` (Springer).
+    German,
+
+    /// French: `R` (Roi), `D` (Dame), `T` (Tour), `F` (Fou), `C` (Cavalier).
+    French,
+
+    /// Spanish: `R` (Rey), `D` (Dama), `T` (Torre), `A` (Alfil), `C` (Caballo).
+    Spanish,
+
+    /// Dutch: `K` (Koning), `D` (Dame), `T` (Toren), `L` (Loper), `P` (Paard).
+    Dutch,
+
+    /// Italian: `R` (Re), `D` (Donna), `T` (Torre), `A` (Alfiere), `C` (Cavallo).
+    Italian,
+}
+
+impl Locale {
+    /// All the locales.
+    pub const ALL: [Locale; 6] = [
+        Locale::English,
+        Locale::German,
+        Locale::French,
+        Locale::Spanish,
+        Locale::Dutch,
+        Locale::Italian,
+    ];
+
+    /// Get the two letter language code of the locale (e.g., `de` for German).
+    pub const fn get_code(self) -> &'static str {
+        match self {
+            Locale::English => "en",
+            Locale::German => "de",
+            Locale::French => "fr",
+            Locale::Spanish => "es",
+            Locale::Dutch => "nl",
+            Locale::Italian => "it",
+        }
+    }
+
+    /// Get the letters of the King, Queen, Rook, Bishop and Knight, in that order.
+    const fn get_piece_letters(self) -> [char; 5] {
+        match self {
+            Locale::English => ['K', 'Q', 'R', 'B', 'N'],
+            Locale::German => ['K', 'D', 'T', 'L', 'S'],
+            Locale::French => ['R', 'D', 'T', 'F', 'C'],
+            Locale::Spanish | Locale::Italian => ['R', 'D', 'T', 'A', 'C'],
+            Locale::Dutch => ['K', 'D', 'T', 'L', 'P'],
+        }
+    }
+
+    /// Get the letter of the piece kind. Pawns have no letter in algebraic notation, so
+    /// returns `None` for a Pawn.
+    pub const fn get_piece_letter(self, kind: PieceKind) -> Option<char> {
+        let letters: [char; 5] = self.get_piece_letters();
+
+        match kind {
+            PieceKind::King => Some(letters[0]),
+            PieceKind::Queen => Some(letters[1]),
+            PieceKind::Rook => Some(letters[2]),
+            PieceKind::Bishop => Some(letters[3]),
+            PieceKind::Knight => Some(letters[4]),
+            PieceKind::Pawn => None,
+        }
+    }
+
+    /// Get the piece kind of the letter, if it is a piece letter in this locale.
+    pub fn get_piece_kind(self, letter: char) -> Option<PieceKind> {
+        [
+            PieceKind::King,
+            PieceKind::Queen,
+            PieceKind::Rook,
+            PieceKind::Bishop,
+            PieceKind::Knight,
+        ]
+        .into_iter()
+        .find(|kind| self.get_piece_letter(*kind) == Some(letter))
+    }
+
+    /// Translate a move in algebraic notation from this locale to `locale`, e.g.,
+    /// `Locale::German.translate("Sxe5+", Locale::French)` is `Cxe5+`. Every upper case
+    /// letter, other than the `O` of castling, is read as a piece letter.
+    ///
+    /// # Errors
+    ///
+    /// Errors when the move has an upper case letter that is not a piece letter in this
+    /// locale.
+    pub fn translate(self, the_move: &str, locale: Locale) -> ChuiResult<String> {
+        the_move
+            .chars()
+            .map(|c| {
+                if !c.is_uppercase() || c == 'O' {
+                    return Ok(c);
+                }
+
+                self.get_piece_kind(c)
+                    .and_then(|kind| locale.get_piece_letter(kind))
+                    .ok_or_else(|| {
+                        ChuiError::InvalidPiece(format!(
+                            "`{}` is not a piece letter in {:?} (use one of {})",
+                            c,
+                            self,
+                            self.get_piece_letters()
+                                .iter()
+                                .map(|letter| format!("`{}`", letter))
+                                .collect::<Vec<String>>()
+                                .join(", ")
+                        ))
+                    })
+            })
+            .collect()
+    }
+}
+
+impl TryFrom<&str> for Locale {
+    type Error = ChuiError;
+
+    /// Get the locale from its two letter language code (e.g., `de`) or its English name
+    /// (e.g., `German`), in any case.
+    fn try_from(locale: &str) -> ChuiResult<Locale> {
+        Locale::ALL
+            .into_iter()
+            .find(|candidate| {
+                candidate.get_code().eq_ignore_ascii_case(locale.trim())
+                    || format!("{:?}", candidate).eq_ignore_ascii_case(locale.trim())
+            })
+            .ok_or_else(|| {
+                ChuiError::InvalidInput(format!(
+                    "`{}` is not a supported locale (use one of {})",
+                    locale.trim(),
+                    Locale::ALL
+                        .iter()
+                        .map(|locale| format!("`{}`", locale.get_code()))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{self, play};
+
+    fn new_game(locale: Locale) -> Game {
+        let mut game = testing::new_game(ParserEngine::Algebraic);
+        game.set_locale(locale);
+        game
+    }
+
+    #[test]
+    fn piece_letters() {
+        for locale in Locale::ALL {
+            assert_eq!(locale.get_piece_letter(PieceKind::Pawn), None);
+
+            for kind in [
+                PieceKind::King,
+                PieceKind::Queen,
+                PieceKind::Rook,
+                PieceKind::Bishop,
+                PieceKind::Knight,
+            ] {
+                let letter = locale.get_piece_letter(kind).unwrap();
+                assert_eq!(locale.get_piece_kind(letter), Some(kind));
+            }
+        }
+
+        assert_eq!(
+            Locale::German.get_piece_letter(PieceKind::Knight),
+            Some('S')
+        );
+        assert_eq!(Locale::French.get_piece_letter(PieceKind::King), Some('R'));
+        assert_eq!(
+            Locale::Spanish.get_piece_letter(PieceKind::Bishop),
+            Some('A')
+        );
+        assert_eq!(Locale::Dutch.get_piece_letter(PieceKind::Knight), Some('P'));
+        assert_eq!(
+            Locale::Italian.get_piece_letter(PieceKind::Queen),
+            Some('D')
+        );
+        assert_eq!(Locale::German.get_piece_kind('B'), None);
+    }
+
+    #[test]
+    fn translate_moves() -> ChuiResult<()> {
+        assert_eq!(Locale::English.translate("Nxf3+", Locale::German)?, "Sxf3+");
+        assert_eq!(Locale::German.translate("Sxf3+", Locale::French)?, "Cxf3+");
+        assert_eq!(Locale::French.translate("Rxe2", Locale::English)?, "Kxe2");
+        assert_eq!(
+            Locale::English.translate("exd8=Q#", Locale::Dutch)?,
+            "exd8=D#"
+        );
+        assert_eq!(Locale::Italian.translate("Tae1", Locale::Spanish)?, "Tae1");
+        assert_eq!(Locale::English.translate("O-O-O", Locale::French)?, "O-O-O");
+        assert!(Locale::German.translate("Bxc6", Locale::English).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn locale_from_str() -> ChuiResult<()> {
+        assert_eq!(Locale::try_from("de")?, Locale::German);
+        assert_eq!(Locale::try_from("FR")?, Locale::French);
+        assert_eq!(Locale::try_from("italian")?, Locale::Italian);
+        assert!(Locale::try_from("xx").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn play_german_moves() -> ChuiResult<()> {
+        let mut game = new_game(Locale::German);
+        play(
+            &mut game,
+            &["e4", "e5", "Sf3", "Sc6", "Lb5", "a6", "Lxc6", "dxc6", "O-O"],
+        )?;
+        assert!(game.parse("Nf3".to_string(), game.to_move).is_err());
+        assert_eq!(
+            game.get_san_move_list(),
+            vec!["e4", "e5", "Nf3", "Nc6", "Bb5", "a6", "Bxc6", "dxc6", "O-O"]
+        );
+        assert_eq!(
+            game.get_san_move_list_for_locale(Locale::French),
+            vec!["e4", "e5", "Cf3", "Cc6", "Fb5", "a6", "Fxc6", "dxc6", "O-O"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn generate_french_moves() -> ChuiResult<()> {
+        let mut game = new_game(Locale::French);
+        game.set_fen("4k3/1P6/8/8/8/8/8/4K1N1 w - - 0 1")?;
+
+        let generate = |from: &str, to: &str| -> ChuiResult<String> {
+            game.parser.generate_move_from_board_coordinates(
+                &game,
+                Coord::try_from(from)?,
+                Coord::try_from(to)?,
+            )
+        };
+        assert_eq!(generate("g1", "f3")?, "Cf3");
+        assert_eq!(generate("e1", "d2")?, "Rd2");
+        assert_eq!(generate("b7", "b8")?, "b8=D+");
+
+        play(&mut game, &["b8T+"])?;
+        assert_eq!(game.get_san_move_list(), vec!["b8=R+"]);
+        Ok(())
+    }
+}
@@ -16,7 +16,7 @@ use std::fmt::Write as _; // import without risk of name clashing
 /// Example:
 ///
 /// ```
-/// use chui_core::MoveGenerator;
+/// use chui_core::prelude::*;
 ///
 /// let g = MoveGenerator::generate_move_list();
 /// let (answer, _reason) = g.validate_moves();
@@ -253,7 +253,7 @@ impl<'a> MoveGenerator<'a> {
     /// Example:
     ///
     /// ```
-    /// use chui_core::MoveGenerator;
+    /// use chui_core::prelude::*;
     ///
     /// let g = MoveGenerator::new();
     ///
@@ -308,7 +308,7 @@ impl<'a> MoveGenerator<'a> {
     /// Example:
     ///
     /// ```
-    /// use chui_core::MoveGenerator;
+    /// use chui_core::prelude::*;
     ///
     /// let mut g = MoveGenerator::generate_move_list();
     /// let (answer, _reason) = g.validate_moves();
@@ -326,7 +326,7 @@ impl<'a> MoveGenerator<'a> {
         (true, "Valid.".to_string())
     }
 
-    #[allow(clippy::similar_names)]
+    #[allow(clippy::similar_names, clippy::nonminimal_bool)]
     /// Checks to see if the move from the given
     /// `{file_a}{rank_b}{-,x}{file_b}{rank_b}` is valid.
     /// Return `true` on valid, `false` on invalid.
@@ -334,7 +334,7 @@ impl<'a> MoveGenerator<'a> {
     /// Example:
     ///
     /// ```
-    /// use chui_core::MoveGenerator;
+    /// use chui_core::prelude::*;
     ///
     /// let file_a: usize = 0; let rank_a: usize = 6; // a7
     /// let file_b: usize = 0; let rank_b: usize = 7; // a8
@@ -390,7 +390,7 @@ impl<'a> MoveGenerator<'a> {
         false
     }
 
-    #[allow(clippy::similar_names)]
+    #[allow(clippy::similar_names, clippy::nonminimal_bool)]
     /// Checks to see if the move from the given
     /// `{file_a}{rank_a}{-,x}{file_b}{rank_b}` is a valid
     /// pawn promotion move.
@@ -398,7 +398,7 @@ impl<'a> MoveGenerator<'a> {
     /// Example:
     ///
     /// ```
-    /// use chui_core::MoveGenerator;
+    /// use chui_core::prelude::*;
     ///
     /// let file_a: usize = 0; let rank_a: usize = 6; // a7
     /// let file_b: usize = 0; let rank_b: usize = 7; // a8
@@ -445,7 +445,7 @@ impl<'a> MoveGenerator<'a> {
     /// Example:
     ///
     /// ```
-    /// use chui_core::MoveGenerator;
+    /// use chui_core::prelude::*;
     ///
     /// let mut g = MoveGenerator::new();
     /// g.generate_pawn_moves();
@@ -505,7 +505,7 @@ impl<'a> MoveGenerator<'a> {
     /// Example:
     ///
     /// ```
-    /// use chui_core::MoveGenerator;
+    /// use chui_core::prelude::*;
     ///
     /// let mut g = MoveGenerator::new();
     /// g.generate_pawn_captures();
@@ -699,7 +699,7 @@ impl<'a> MoveGenerator<'a> {
     /// Example:
     ///
     /// ```
-    /// use chui_core::MoveGenerator;
+    /// use chui_core::prelude::*;
     ///
     /// let mut g = MoveGenerator::new();
     /// g.generate_king_moves();
@@ -740,7 +740,7 @@ impl<'a> MoveGenerator<'a> {
     /// Example:
     ///
     /// ```
-    /// use chui_core::MoveGenerator;
+    /// use chui_core::prelude::*;
     ///
     /// let mut g = MoveGenerator::new();
     /// g.generate_king_captures();
@@ -785,7 +785,7 @@ impl<'a> MoveGenerator<'a> {
     /// Example:
     ///
     /// ```
-    /// use chui_core::MoveGenerator;
+    /// use chui_core::prelude::*;
     ///
     /// let mut g = MoveGenerator::new();
     /// g.generate_queen_moves();
@@ -822,7 +822,7 @@ impl<'a> MoveGenerator<'a> {
     /// Example:
     ///
     /// ```
-    /// use chui_core::MoveGenerator;
+    /// use chui_core::prelude::*;
     ///
     /// let mut g = MoveGenerator::new();
     /// g.generate_queen_captures();
@@ -862,7 +862,7 @@ impl<'a> MoveGenerator<'a> {
     /// Example:
     ///
     /// ```
-    /// use chui_core::MoveGenerator;
+    /// use chui_core::prelude::*;
     ///
     /// let mut g = MoveGenerator::new();
     /// g.generate_rook_moves();
@@ -899,7 +899,7 @@ impl<'a> MoveGenerator<'a> {
     /// Example:
     ///
     /// ```
-    /// use chui_core::MoveGenerator;
+    /// use chui_core::prelude::*;
     ///
     /// let mut g = MoveGenerator::new();
     /// g.generate_rook_captures();
@@ -939,7 +939,7 @@ impl<'a> MoveGenerator<'a> {
     /// Example:
     ///
     /// ```
-    /// use chui_core::MoveGenerator;
+    /// use chui_core::prelude::*;
     ///
     /// let mut g = MoveGenerator::new();
     /// g.generate_bishop_moves();
@@ -976,7 +976,7 @@ impl<'a> MoveGenerator<'a> {
     /// Example:
     ///
     /// ```
-    /// use chui_core::MoveGenerator;
+    /// use chui_core::prelude::*;
     ///
     /// let mut g = MoveGenerator::new();
     /// g.generate_bishop_captures();
@@ -1016,7 +1016,7 @@ impl<'a> MoveGenerator<'a> {
     /// Example:
     ///
     /// ```
-    /// use chui_core::MoveGenerator;
+    /// use chui_core::prelude::*;
     ///
     /// let mut g = MoveGenerator::new();
     /// g.generate_knight_moves();
@@ -1053,7 +1053,7 @@ impl<'a> MoveGenerator<'a> {
     /// Example:
     ///
     /// ```
-    /// use chui_core::MoveGenerator;
+    /// use chui_core::prelude::*;
     ///
     /// let mut g = MoveGenerator::new();
     /// g.generate_knight_captures();
@@ -1092,7 +1092,7 @@ impl<'a> MoveGenerator<'a> {
     /// Example:
     ///
     /// ```
-    /// use chui_core::MoveGenerator;
+    /// use chui_core::prelude::*;
     ///
     /// let mut g = MoveGenerator::new();
     /// g.generate_castle_moves();
@@ -1124,7 +1124,7 @@ impl<'a> MoveGenerator<'a> {
     /// Example:
     ///
     /// ```
-    /// use chui_core::MoveGenerator;
+    /// use chui_core::prelude::*;
     ///
     /// let mut g = MoveGenerator::new();
     /// g.generate_square_to_square_moves();
@@ -1259,7 +1259,7 @@ impl<'a> MoveGenerator<'a> {
     /// Example:
     ///
     /// ```
-    /// use chui_core::MoveGenerator;
+    /// use chui_core::prelude::*;
     ///
     /// let mut g = MoveGenerator::new();
     /// g.generate_square_to_square_captures();
@@ -1389,7 +1389,7 @@ impl<'a> MoveGenerator<'a> {
     /// Example:
     ///
     /// ```
-    /// use chui_core::MoveGenerator;
+    /// use chui_core::prelude::*;
     ///
     /// let g = MoveGenerator::generate_move_list();
     /// let (answer, _reason) = g.validate_moves();
@@ -0,0 +1,83 @@
+//! Provides the `MoveRecord` struct. A move record holds an applied `ChessMove` along with
+//! the state of the game that the move cannot restore by itself, so that the move can be
+//! taken back.
+
+use crate::prelude::*;
+
+/// Represents a move that has been applied to a [`Game`], along with the state of the game
+/// from before the move was applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoveRecord {
+    /// The applied move.
+    pub chess_move: ChessMove,
+
+    /// The applied move in Standard Algebraic Notation.
+    pub san: String,
+
+    /// The piece captured by the move, if any.
+    pub captured_piece: Option<Piece>,
+
+    /// Could White castle on the king side before the move?
+    pub white_can_castle_kingside: bool,
+
+    /// Could White castle on the queen side before the move?
+    pub white_can_castle_queenside: bool,
+
+    /// Could Black castle on the king side before the move?
+    pub black_can_castle_kingside: bool,
+
+    /// Could Black castle on the queen side before the move?
+    pub black_can_castle_queenside: bool,
+
+    /// The en passant target square before the move.
+    pub en_passant_target_square: Option<Coord>,
+
+    /// The true (X-FEN) en passant target square before the move.
+    pub true_en_passant_target_square: Option<Coord>,
+
+    /// The en passant target piece before the move.
+    pub en_passant_target_piece: Option<Piece>,
+
+    /// The half-move clock before the move.
+    pub half_move_clock: usize,
+
+    /// Did White win before the move?
+    pub white_wins: bool,
+
+    /// Did Black win before the move?
+    pub black_wins: bool,
+
+    /// Was the game a draw before the move?
+    pub is_draw: bool,
+
+    /// The win condition before the move.
+    pub win_condition: Option<WinCondition>,
+
+    /// The draw condition before the move.
+    pub draw_condition: Option<DrawCondition>,
+}
+
+impl MoveRecord {
+    /// Return a new [`MoveRecord`] for `chess_move`, recording the state of `game` before the
+    /// move is applied. The captured piece is set once the move has been applied.
+    pub fn new(game: &Game, chess_move: ChessMove) -> MoveRecord {
+        MoveRecord {
+            chess_move,
+            san: String::new(),
+            captured_piece: None,
+            white_can_castle_kingside: game.board.white_can_castle_kingside,
+            white_can_castle_queenside: game.board.white_can_castle_queenside,
+            black_can_castle_kingside: game.board.black_can_castle_kingside,
+            black_can_castle_queenside: game.board.black_can_castle_queenside,
+            en_passant_target_square: game.board.get_en_passant_coord(),
+            true_en_passant_target_square: game.board.get_true_en_passant_coord(),
+            en_passant_target_piece: game.board.get_en_passant_piece(),
+            half_move_clock: game.half_move_clock,
+            white_wins: game.white_wins,
+            black_wins: game.black_wins,
+            is_draw: game.is_draw,
+            win_condition: game.win_condition,
+            draw_condition: game.draw_condition,
+        }
+    }
+}
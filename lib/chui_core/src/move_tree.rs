@@ -0,0 +1,488 @@
+//! Provides the `MoveTree` struct. A move tree holds the moves of a game along with the
+//! variations played instead of them, and a cursor on the move of the current position.
+
+use std::collections::HashMap;
+
+use crate::prelude::*;
+
+/// The identifier of a node of a [`MoveTree`]. Identifiers are not reused once a node
+/// is deleted.
+pub type NodeId = usize;
+
+/// The color of an arrow or a highlighted square.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MarkColor {
+    /// Green.
+    #[default]
+    Green,
+
+    /// Red.
+    Red,
+
+    /// Yellow.
+    Yellow,
+
+    /// Blue.
+    Blue,
+}
+
+/// An arrow drawn on the board, from one square to another.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Arrow {
+    /// The square the arrow starts from.
+    pub from: Coord,
+
+    /// The square the arrow points to.
+    pub to: Coord,
+
+    /// The color of the arrow.
+    pub color: MarkColor,
+}
+
+/// A highlighted square of the board.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Highlight {
+    /// The highlighted square.
+    pub coord: Coord,
+
+    /// The color of the highlight.
+    pub color: MarkColor,
+}
+
+/// A node of a [`MoveTree`]: a move, the moves played after it, and its annotations. The
+/// root node of a tree has no move, and stands for the start position.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MoveNode {
+    /// The record of the move, or `None` for the root node.
+    record: Option<MoveRecord>,
+
+    /// The node of the previous move, or `None` for the root node.
+    parent: Option<NodeId>,
+
+    /// The nodes of the moves played after this one. The first is the main line, and the
+    /// others are its variations.
+    children: Vec<NodeId>,
+
+    /// The win condition of the position after the move, kept when the game leaves it.
+    win_condition: Option<WinCondition>,
+
+    /// The draw condition of the position after the move, kept when the game leaves it.
+    draw_condition: Option<DrawCondition>,
+
+    /// The comments before the move. The comments of the root node are the comments of
+    /// the game.
+    pub pre_comments: Vec<String>,
+
+    /// The comments after the move.
+    pub comments: Vec<String>,
+
+    /// The Numeric Annotation Glyphs of the move (e.g., `1` for `!`).
+    pub nags: Vec<u8>,
+
+    /// The arrows drawn on the board after the move.
+    pub arrows: Vec<Arrow>,
+
+    /// The squares highlighted on the board after the move.
+    pub highlights: Vec<Highlight>,
+}
+
+impl MoveNode {
+    /// Get the record of the move, or `None` for the root node.
+    pub fn get_record(&self) -> Option<&MoveRecord> {
+        self.record.as_ref()
+    }
+
+    /// Get the move, or `None` for the root node.
+    pub fn get_move(&self) -> Option<&ChessMove> {
+        self.record.as_ref().map(|record| &record.chess_move)
+    }
+
+    /// Get the move in Standard Algebraic Notation, or `None` for the root node.
+    pub fn get_san(&self) -> Option<&str> {
+        self.record.as_ref().map(|record| record.san.as_str())
+    }
+
+    /// Get the node of the previous move, or `None` for the root node.
+    pub const fn get_parent(&self) -> Option<NodeId> {
+        self.parent
+    }
+
+    /// Get the nodes of the moves played after this one: the main line first, then its
+    /// variations.
+    pub fn get_children(&self) -> &[NodeId] {
+        &self.children
+    }
+
+    /// Get the win and draw conditions of the position after the move, as they were when
+    /// the game last left it. The conditions of the current position are those of the
+    /// game.
+    pub const fn get_result(&self) -> (Option<WinCondition>, Option<DrawCondition>) {
+        (self.win_condition, self.draw_condition)
+    }
+}
+
+/// The moves of a game as a tree: each node is a move, whose first child continues the
+/// main line, and whose other children are variations played instead of that first
+/// child. The cursor is the node of the current position of the game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoveTree {
+    /// The nodes, by identifier.
+    nodes: HashMap<NodeId, MoveNode>,
+
+    /// The identifier of the next node added.
+    next_id: NodeId,
+
+    /// The node of the current position.
+    cursor: NodeId,
+}
+
+impl Default for MoveTree {
+    fn default() -> Self {
+        MoveTree::new()
+    }
+}
+
+impl MoveTree {
+    /// The identifier of the root node, which stands for the start position.
+    pub const ROOT: NodeId = 0;
+
+    /// Return a new tree with only the root node, which is the cursor.
+    pub fn new() -> MoveTree {
+        MoveTree {
+            nodes: HashMap::from([(MoveTree::ROOT, MoveNode::default())]),
+            next_id: MoveTree::ROOT + 1,
+            cursor: MoveTree::ROOT,
+        }
+    }
+
+    /// Get the node of the current position.
+    pub const fn get_cursor(&self) -> NodeId {
+        self.cursor
+    }
+
+    /// Set the node of the current position.
+    pub(crate) fn set_cursor(&mut self, node_id: NodeId) {
+        self.cursor = node_id;
+    }
+
+    /// Keep the win and draw conditions of the position after the move of a node.
+    pub(crate) fn set_result(
+        &mut self,
+        node_id: NodeId,
+        win_condition: Option<WinCondition>,
+        draw_condition: Option<DrawCondition>,
+    ) {
+        if let Some(node) = self.nodes.get_mut(&node_id) {
+            node.win_condition = win_condition;
+            node.draw_condition = draw_condition;
+        }
+    }
+
+    /// Get a node.
+    pub fn get_node(&self, node_id: NodeId) -> Option<&MoveNode> {
+        self.nodes.get(&node_id)
+    }
+
+    /// Get a node, to annotate it.
+    pub fn get_node_mut(&mut self, node_id: NodeId) -> Option<&mut MoveNode> {
+        self.nodes.get_mut(&node_id)
+    }
+
+    /// Get a node, or an error if there is no such node.
+    ///
+    /// # Errors
+    ///
+    /// Errors when the tree has no node `node_id`.
+    fn get_existing_node(&self, node_id: NodeId) -> ChuiResult<&MoveNode> {
+        self.nodes.get(&node_id).ok_or_else(|| {
+            ChuiError::IndexOutOfRange(format!("there is no node {} in the move tree", node_id))
+        })
+    }
+
+    /// Get the child of a node with the given move in Standard Algebraic Notation.
+    pub fn find_child(&self, node_id: NodeId, san: &str) -> Option<NodeId> {
+        self.nodes.get(&node_id).and_then(|node| {
+            node.children
+                .iter()
+                .copied()
+                .find(|child| self.nodes[child].get_san() == Some(san))
+        })
+    }
+
+    /// Add a node for the move of `record` after `parent`: as its main line when `parent`
+    /// has no moves after it yet, otherwise as its last variation. Returns the new node.
+    pub(crate) fn add_child(&mut self, parent: NodeId, record: MoveRecord) -> NodeId {
+        let node_id: NodeId = self.next_id;
+        self.next_id += 1;
+        self.nodes.insert(
+            node_id,
+            MoveNode {
+                record: Some(record),
+                parent: Some(parent),
+                ..MoveNode::default()
+            },
+        );
+
+        if let Some(node) = self.nodes.get_mut(&parent) {
+            node.children.push(node_id);
+        }
+
+        node_id
+    }
+
+    /// Get the nodes of the moves from the start position to `node_id`. The root node is
+    /// not included.
+    ///
+    /// # Errors
+    ///
+    /// Errors when the tree has no node `node_id`.
+    pub fn get_path(&self, node_id: NodeId) -> ChuiResult<Vec<NodeId>> {
+        let mut path: Vec<NodeId> = Vec::new();
+        let mut node_id: NodeId = node_id;
+
+        while let Some(parent) = self.get_existing_node(node_id)?.parent {
+            path.push(node_id);
+            node_id = parent;
+        }
+        path.reverse();
+
+        Ok(path)
+    }
+
+    /// Get the nodes of the main line, from the first move to the last.
+    pub fn get_main_line(&self) -> Vec<NodeId> {
+        std::iter::successors(self.nodes[&MoveTree::ROOT].children.first(), |node_id| {
+            self.nodes[node_id].children.first()
+        })
+        .copied()
+        .collect()
+    }
+
+    /// Is `node_id` the node `ancestor`, or a node of the moves played after it?
+    pub fn is_in_line_of(&self, node_id: NodeId, ancestor: NodeId) -> bool {
+        std::iter::successors(Some(node_id), |node_id| {
+            self.nodes.get(node_id).and_then(|node| node.parent)
+        })
+        .any(|node_id| node_id == ancestor)
+    }
+
+    /// Make the variation of `node_id` the main line after its previous move. The other
+    /// moves keep their order.
+    ///
+    /// # Errors
+    ///
+    /// Errors when the tree has no node `node_id`, or when it is the root node.
+    pub(crate) fn promote_variation(&mut self, node_id: NodeId) -> ChuiResult<()> {
+        let parent: NodeId = self.get_parent_of_move(node_id)?;
+
+        if let Some(node) = self.nodes.get_mut(&parent) {
+            node.children.retain(|child| *child != node_id);
+            node.children.insert(0, node_id);
+        }
+
+        Ok(())
+    }
+
+    /// Delete the node `node_id` and the moves played after it.
+    ///
+    /// # Errors
+    ///
+    /// Errors when the tree has no node `node_id`, when it is the root node, or when the
+    /// cursor is on the node or a move played after it.
+    pub(crate) fn delete_variation(&mut self, node_id: NodeId) -> ChuiResult<()> {
+        let parent: NodeId = self.get_parent_of_move(node_id)?;
+
+        if self.is_in_line_of(self.cursor, node_id) {
+            return Err(ChuiError::InvalidInput(
+                "the move of the current position cannot be deleted".to_string(),
+            ));
+        }
+
+        if let Some(node) = self.nodes.get_mut(&parent) {
+            node.children.retain(|child| *child != node_id);
+        }
+
+        let mut deleted: Vec<NodeId> = vec![node_id];
+        while let Some(node_id) = deleted.pop() {
+            if let Some(node) = self.nodes.remove(&node_id) {
+                deleted.extend(node.children);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Get the parent of a node with a move.
+    ///
+    /// # Errors
+    ///
+    /// Errors when the tree has no node `node_id`, or when it is the root node.
+    fn get_parent_of_move(&self, node_id: NodeId) -> ChuiResult<NodeId> {
+        self.get_existing_node(node_id)?
+            .parent
+            .ok_or_else(|| ChuiError::InvalidInput("the start position has no move".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::coord::*;
+    use crate::testing::{new_game, play};
+
+    #[test]
+    fn add_and_goto_variations() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Algebraic);
+        let nodes = play(&mut game, &["e4", "e5", "Nf3"])?;
+        let fen = Fen::get_fen(&game);
+
+        let c5 = game.add_variation(nodes[1], "c5")?;
+        assert_eq!(game.get_move_tree().get_cursor(), c5);
+        assert_eq!(game.get_san_move_list(), vec!["e4", "c5"]);
+        assert_eq!(game.get_main_line_san(), vec!["e4", "e5", "Nf3"]);
+        assert_eq!(
+            game.get_move_tree()
+                .get_node(nodes[0])
+                .map(MoveNode::get_children),
+            Some(&[nodes[1], c5][..])
+        );
+
+        let nc3 = play(&mut game, &["Nc3"])?[0];
+        assert_eq!(game.get_move_tree().get_path(nc3)?, vec![nodes[0], c5, nc3]);
+
+        game.goto(nodes[2])?;
+        assert_eq!(Fen::get_fen(&game), fen);
+        assert_eq!(game.get_san_move_list(), vec!["e4", "e5", "Nf3"]);
+        assert_eq!(game.move_list.len(), 3);
+
+        game.goto(MoveTree::ROOT)?;
+        assert_eq!(Fen::get_fen(&game), Fen::STARTING_POSITION);
+        assert!(!game.can_undo());
+
+        // A move already played goes to its node.
+        assert_eq!(game.add_variation(nodes[0], "e4")?, nodes[0]);
+
+        // A move that cannot be played leaves the game where it was.
+        game.goto(nc3)?;
+        assert!(game.add_variation(nodes[2], "Ke3").is_err());
+        assert!(game.add_variation(MoveTree::ROOT, "d4").is_err());
+        assert!(game.goto(100).is_err());
+        assert_eq!(game.get_move_tree().get_cursor(), nc3);
+        assert_eq!(game.get_san_move_list(), vec!["e4", "c5", "Nc3"]);
+        Ok(())
+    }
+
+    #[test]
+    fn promote_and_delete_variations() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Algebraic);
+        let nodes = play(&mut game, &["e4", "e5", "Nf3"])?;
+        let d5 = game.add_variation(nodes[1], "d5")?;
+        let c5 = game.add_variation(nodes[1], "c5")?;
+        let nc3 = play(&mut game, &["Nc3"])?[0];
+
+        game.promote_variation(c5)?;
+        assert_eq!(game.get_main_line_san(), vec!["e4", "c5", "Nc3"]);
+        assert_eq!(
+            game.get_move_tree()
+                .get_node(nodes[0])
+                .map(MoveNode::get_children),
+            Some(&[c5, nodes[1], d5][..])
+        );
+        assert!(game.promote_variation(MoveTree::ROOT).is_err());
+
+        // Deleting the line of the current position goes back to the previous move.
+        game.delete_variation(c5)?;
+        assert_eq!(game.get_move_tree().get_cursor(), nodes[0]);
+        assert_eq!(game.get_san_move_list(), vec!["e4"]);
+        assert!(game.get_move_tree().get_node(c5).is_none());
+        assert!(game.get_move_tree().get_node(nc3).is_none());
+        assert_eq!(game.get_main_line_san(), vec!["e4", "e5", "Nf3"]);
+
+        game.delete_variation(d5)?;
+        assert_eq!(
+            game.get_move_tree()
+                .get_node(nodes[0])
+                .map(MoveNode::get_children),
+            Some(&[nodes[1]][..])
+        );
+        assert!(game.delete_variation(d5).is_err());
+        assert!(game.delete_variation(MoveTree::ROOT).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn new_moves_add_variations() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Algebraic);
+        let nodes = play(&mut game, &["e4", "e5", "Nf3"])?;
+
+        game.undo()?;
+        assert_eq!(game.get_main_line_san(), vec!["e4", "e5", "Nf3"]);
+        let nc3 = play(&mut game, &["Nc3"])?[0];
+        assert_eq!(game.get_main_line_san(), vec!["e4", "e5", "Nf3"]);
+        assert_eq!(game.get_san_move_list(), vec!["e4", "e5", "Nc3"]);
+        assert_eq!(
+            game.get_move_tree()
+                .get_node(nodes[1])
+                .map(MoveNode::get_children),
+            Some(&[nodes[2], nc3][..])
+        );
+
+        // Playing a move already in the tree goes to its node, leaving the order as it is.
+        game.undo()?;
+        assert_eq!(play(&mut game, &["Nc3"])?, vec![nc3]);
+        game.undo()?;
+        game.redo()?;
+        assert_eq!(game.get_move_tree().get_cursor(), nc3);
+        assert_eq!(game.get_main_line_san(), vec!["e4", "e5", "Nf3"]);
+
+        let d4 = game.add_variation(nodes[0], "d4")?;
+        game.goto(MoveTree::ROOT)?;
+        assert_eq!(play(&mut game, &["d4"])?, vec![d4]);
+        assert_eq!(game.get_main_line_san(), vec!["e4", "e5", "Nf3"]);
+        game.goto(MoveTree::ROOT)?;
+        play(&mut game, &["e4"])?;
+        assert_eq!(game.get_move_tree().get_cursor(), nodes[0]);
+        assert_eq!(game.get_main_line().len(), 3);
+
+        // Only promoting a variation makes it the main line.
+        game.promote_variation(nc3)?;
+        assert_eq!(game.get_main_line_san(), vec!["e4", "e5", "Nc3"]);
+
+        game.set_fen(Fen::STARTING_POSITION)?;
+        assert!(game.get_main_line().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn annotate_moves() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Algebraic);
+        let nodes = play(&mut game, &["e4", "e5"])?;
+        game.add_variation(nodes[1], "c5")?;
+
+        if let Some(node) = game.get_node_mut(nodes[0]) {
+            node.comments.push("Best by test.".to_string());
+            node.nags.push(1);
+            node.arrows.push(Arrow {
+                from: Coord::try_from(E2)?,
+                to: Coord::try_from(E4)?,
+                color: MarkColor::Green,
+            });
+            node.highlights.push(Highlight {
+                coord: Coord::try_from(D5)?,
+                color: MarkColor::Red,
+            });
+        }
+        if let Some(root) = game.get_node_mut(MoveTree::ROOT) {
+            root.pre_comments.push("A quiet game.".to_string());
+        }
+
+        let node = game.get_move_tree().get_node(nodes[0]).unwrap();
+        assert_eq!(node.get_san(), Some("e4"));
+        assert_eq!(node.arrows[0].to, Coord::try_from(E4)?);
+        assert_eq!(node.highlights[0].color, MarkColor::Red);
+        assert!(game
+            .to_pgn()
+            .ends_with("\n\n{A quiet game.} 1. e4 $1 {Best by test.} 1... e5 (1... c5) *\n"));
+        Ok(())
+    }
+}
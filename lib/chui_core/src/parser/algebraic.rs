@@ -11,6 +11,9 @@ use crate::prelude::*;
 
 /// A parser that will parse algebraic chess notation.
 /// Example moves: `e4`, `Bxc6+`, `Kd6`, `e8Q#`, `a1=N`, etc.
+///
+/// The piece letters are those of the parser's `Locale` (e.g., `Sxf3` in German). Figurines
+/// of either color are read as piece letters (e.g., `♘f3` or `♕xd7#`).
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AlgebraicParser<'a> {
     /// The move generator. Generates chess moves in Algebraic Notation.
@@ -18,6 +21,9 @@ pub struct AlgebraicParser<'a> {
 
     /// The chess move to move.
     pub move_obj: ChessMove,
+
+    /// The language of the piece letters.
+    pub locale: Locale,
 }
 
 impl<'a> Parser for AlgebraicParser<'a> {
@@ -128,10 +134,25 @@ impl<'a> Parser for AlgebraicParser<'a> {
     /// Token 8: + (
     ///     check (mate)
     /// )
-    fn parse(&mut self, move_string: String, to_move: Color) -> ChuiResult<ChessMove> {
+    fn parse_notation(&mut self, move_string: String, to_move: Color) -> ChuiResult<ChessMove> {
         // Check the move to see it's valid. No whitespace allowed. At
         // the same time, trim any surrounding whitespace.
-        let the_move: String = self.trim_and_check_whitespace(&move_string)?;
+        let input_move: String = self.trim_and_check_whitespace(&move_string)?;
+
+        // Read the piece letters of the locale, and figurines, as English piece letters.
+        let the_move: String = ChessMove::fan_to_san(&if self.locale == Locale::English {
+            input_move.clone()
+        } else {
+            self.locale.translate(&input_move, Locale::English)?
+        });
+
+        // A promotion may be written with `=` (e.g., `e8=Q`), which is read as `e8Q`.
+        let the_move: String = match the_move.split_once('=') {
+            Some((head, tail)) if tail.starts_with(['Q', 'R', 'B', 'N']) => {
+                format!("{}{}", head, tail)
+            }
+            _ => the_move,
+        };
 
         if the_move.len() < 2 {
             self.invalid_input("Input move is too small in length (<2)")?;
@@ -142,7 +163,7 @@ impl<'a> Parser for AlgebraicParser<'a> {
         }
 
         // Record the input move.
-        self.move_obj.set_input_move(the_move.clone());
+        self.move_obj.set_input_move(input_move);
 
         // Parse each character in the move.
         for (move_idx, token) in the_move.chars().enumerate() {
@@ -163,12 +184,18 @@ impl<'a> Parser for AlgebraicParser<'a> {
         // `self.move_obj` and reset `self.move_obj` to a new
         // instance of `ChessMove`.
         let mut move_obj = self.move_obj.to_owned();
+        move_obj.to_move = to_move;
         move_obj.set_color(to_move);
         self.move_obj = ChessMove::new(to_move);
 
         Ok(move_obj)
     }
 
+    /// Set the language of the piece letters.
+    fn set_locale(&mut self, locale: Locale) {
+        self.locale = locale;
+    }
+
     /// The name of the parser.
     fn name(&self) -> String {
         "Algebraic Parser".to_string()
@@ -176,7 +203,13 @@ impl<'a> Parser for AlgebraicParser<'a> {
 
     /// Some examples of the moves parsed by this parser.
     fn eg(&self) -> String {
-        format!("e4, Bxc6, Qb4, exf8=Q++ ({})", self.name())
+        format!(
+            "{} ({})",
+            Locale::English
+                .translate("e4, Bxc6, Qb4, exf8=Q++", self.locale)
+                .expect("the examples have English piece letters"),
+            self.name()
+        )
     }
 
     /// Return a String representing the move from board Coordinates to this
@@ -187,44 +220,8 @@ impl<'a> Parser for AlgebraicParser<'a> {
         from_coord: Coord,
         to_coord: Coord,
     ) -> ChuiResult<String> {
-        let mut _move_string = String::new();
-        let mut the_move = ChessMove::new(game.to_move);
-        let piece: Piece = match game.board.get_position().get_piece(Some(from_coord)) {
-            Some(piece) => piece,
-            None => {
-                return Err(ChuiError::InvalidMove(format!(
-                    "No piece at ({}, {})",
-                    from_coord.get_rank(),
-                    from_coord.get_file()
-                )))
-            }
-        };
-        match piece.get_kind() {
-            PieceKind::Pawn => {
-                the_move.set_pawn_move();
-                let file = self.match_index_to_file(from_coord.get_file()).unwrap();
-                _move_string = format!("{}", file);
-            }
-            _ => {
-                the_move.set_piece_move(piece);
-                _move_string = format!("{}", piece);
-            }
-        }
-        // TODO: fix _to_square
-        let _to_square = game.board.get_position().get_piece(Some(to_coord));
-        let piece_move_coords = piece.get_move_coords(&(game.board), None);
-        for coord in piece_move_coords.iter() {
-            if &to_coord == coord {
-                println!("Valid move.");
-                break;
-            } else {
-                _move_string = "Invalid move.".to_string();
-                // println!("{}", _move_string);
-            }
-        }
-
-        // Ok(_move_string)
-        Ok("move_string not implemented".to_string())
+        self.get_move_from_board_coordinates(game, from_coord, to_coord)?
+            .to_san_for_locale(&game.board, self.locale)
     }
 }
 
@@ -234,6 +231,7 @@ impl<'a> AlgebraicParser<'a> {
         Box::new(AlgebraicParser {
             move_generator: MoveGenerator::new(),
             move_obj: ChessMove::new(to_move),
+            locale: Locale::default(),
         })
     }
 
@@ -276,6 +274,17 @@ impl<'a> AlgebraicParser<'a> {
     ///
     /// Returns a [`ChuiError`] result if the `token` is not a correct file.
     fn try_file(&mut self, token: char) -> ChuiResult<()> {
+        if !CHAR_FILES.contains(&token) {
+            return AlgebraicParser::token_not_satisfied(token);
+        }
+
+        // A second file means the coordinate recorded so far disambiguates the piece to
+        // move (e.g., the `a` in `Rae1`, the `a1` in `Ra1e1`, or the `e` in `exf4`).
+        if let Some(file) = self.move_obj.to_coord_file.take() {
+            self.move_obj.from_coord_file = Some(file);
+            self.move_obj.from_coord_rank = self.move_obj.to_coord_rank.take();
+        }
+
         self.move_obj.set_to_coord_file(token)?;
         Ok(())
         // AlgebraicParser::token_not_satisfied(token)
@@ -304,6 +313,16 @@ impl<'a> AlgebraicParser<'a> {
     ///
     /// Returns a [`ChuiError`] result if the matched token has an out of range index.
     fn try_rank(&mut self, token: char) -> ChuiResult<()> {
+        if !CHAR_RANKS.contains(&token) {
+            return AlgebraicParser::token_not_satisfied(token);
+        }
+
+        // A second rank means the rank recorded so far disambiguates the piece to move
+        // (e.g., the first `1` in `R1e1`).
+        if let Some(rank) = self.move_obj.to_coord_rank.take() {
+            self.move_obj.from_coord_rank = Some(rank);
+        }
+
         self.move_obj.set_to_coord_rank(token)?;
         Ok(())
         // AlgebraicParser::token_not_satisfied(token)
@@ -1172,7 +1191,7 @@ impl<'a> AlgebraicParser<'a> {
 //     fn parse_the_move(the_move: String) -> ChuiResult<ChessMove> {
 //         let mut parser = parser::new(ParserEngine::Algebraic);
 //         // If parsing won't work for White, it won't work for Black.
-//         parser.parse(the_move, Color::White)
+//         parser.parse_notation(the_move, Color::White)
 //     }
 
 //     #[test]
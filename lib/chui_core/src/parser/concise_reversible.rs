@@ -6,16 +6,52 @@ use crate::prelude::*;
 
 /// A parser that will parse concise reversible chess notation.
 /// Example moves: `e24`, `e75`, `Ng1f3`, `Nb8c6`, `Bb5:Nc6`, etc.
-#[derive(Debug, Copy, Clone)]
-pub struct ConciseReversibleParser;
+///
+/// A Pawn moving straight ahead is written with its file and the two ranks (e.g., `e24`).
+/// Other moves are written with the "from" square and the "to" square, and captures with
+/// a `:` and the captured piece (e.g., `Bb5:Nc6`; a captured Pawn has no letter, e.g.,
+/// `e4:d5`). En passant captures are marked with `ep` (e.g., `e5:d6ep`), so the move can be
+/// taken back from the notation alone. Because the captured piece is part of the move, a
+/// move parsed with `parse()` is checked against the board.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ConciseReversibleParser {
+    /// The color to move.
+    pub to_move: Color,
+}
+
+/// A move in concise reversible notation, along with what it claims about the board.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ConciseReversibleMove {
+    /// The parsed move.
+    chess_move: ChessMove,
+
+    /// The kind of the captured piece.
+    captured: Option<PieceKind>,
+
+    /// Is the move an en passant capture?
+    en_passant: bool,
+}
 
 impl Parser for ConciseReversibleParser {
     /// Parse the chess move, return `Ok(ChessMove)` on success,
     /// `ChuiError::InvalidMove(reason)` on failure.
-    fn parse(&mut self, _the_move: String, _to_move: Color) -> ChuiResult<ChessMove> {
-        Err(ChuiError::InvalidMove(
-            "ConciseReversibleParser not implemented".to_string(),
-        ))
+    fn parse_notation(&mut self, move_string: String, to_move: Color) -> ChuiResult<ChessMove> {
+        self.to_move = to_move;
+
+        Ok(self.parse_concise(move_string, to_move)?.chess_move)
+    }
+
+    /// Parse the chess move and check that the captured piece and en passant given by the
+    /// move match the `board`.
+    fn parse(&mut self, move_string: String, board: &Board) -> ChuiResult<ChessMove> {
+        self.to_move = board.get_to_move();
+        let concise: ConciseReversibleMove = self.parse_concise(move_string, self.to_move)?;
+        let mut chess_move: ChessMove = concise.chess_move;
+        chess_move.validate_move_for_board(board)?;
+        chess_move.validate_captured_piece(concise.captured, concise.en_passant)?;
+        chess_move.check = chess_move.get_check(board)?;
+
+        Ok(chess_move)
     }
 
     fn name(&self) -> String {
@@ -23,36 +59,422 @@ impl Parser for ConciseReversibleParser {
     }
 
     fn eg(&self) -> String {
-        format!("Examples for {}", self.name())
+        format!(
+            "Examples for {}: `e24`, `e75`, `Ng1f3`, `Bb5:Nc6`, `e4:d5`, `e5:d6ep`, \
+            `b7:Ra8Q`, `O-O`, etc.",
+            self.name()
+        )
     }
 
     /// Return a String representing the move from board Coordinates to this
     /// parser's notation.
-    fn generate_move_from_board_Coordinates(
+    fn generate_move_from_board_coordinates(
         &self,
-        engine: &Engine,
-        from_index: (Coord),
-        _to_index: (Coord),
+        game: &Game,
+        from_coord: Coord,
+        to_coord: Coord,
     ) -> ChuiResult<String> {
-        let board = &(engine.board.get_board());
-
-        let piece = match board[from_index.0][from_index.1] {
-            Some(piece) => piece,
-            None => {
-                return Err(ChuiError::InvalidMove(format!(
-                    "Invalid move. No piece at ({}, {})",
-                    from_index.0, from_index.1
-                )))
-            }
-        };
+        let the_move: ChessMove =
+            self.get_move_from_board_coordinates(game, from_coord, to_coord)?;
 
-        Ok(format!("Piece: {}", piece))
+        ConciseReversibleParser::get_move_text(&the_move, &game.board)
     }
 }
 
 impl ConciseReversibleParser {
     /// Return a new dynamic parser that implements the `Parser` trait.
-    pub fn new() -> Box<dyn Parser + Send + Sync> {
-        Box::new(ConciseReversibleParser {})
+    pub fn new(to_move: Color) -> Box<ConciseReversibleParser> {
+        Box::new(ConciseReversibleParser { to_move })
+    }
+
+    /// Return the move in concise reversible notation (e.g., `e24`, `Ng1f3`, `Bb5:Nc6+`,
+    /// `e5:d6ep` or `b7:Ra8Q`), given the `board` the move is played on. The move must be
+    /// validated for the board first (see `ChessMove::validate_move_for_board()`).
+    ///
+    /// # Errors
+    ///
+    /// Errors when the move is missing its coordinates or moving piece, or cannot be
+    /// applied to the board.
+    pub fn get_move_text(the_move: &ChessMove, board: &Board) -> ChuiResult<String> {
+        let (from_coord, to_coord, from_piece) =
+            match (the_move.from_coord, the_move.to_coord, the_move.from_piece) {
+                (Some(from_coord), Some(to_coord), Some(from_piece)) => {
+                    (from_coord, to_coord, from_piece)
+                }
+                _ => {
+                    return Err(ChuiError::InvalidMove(
+                        "The move must be validated for the board before it can be written"
+                            .to_string(),
+                    ))
+                }
+            };
+
+        let mut move_text: String = match (the_move.castling, the_move.to_piece) {
+            (Some(Castling::King), _) => "O-O".to_string(),
+            (Some(Castling::Queen), _) => "O-O-O".to_string(),
+            (None, None) if from_piece.is_pawn() => format!(
+                "{}{}{}",
+                from_coord,
+                to_coord.get_rank() + 1,
+                the_move
+                    .promotion
+                    .map_or_else(String::new, |piece| piece.repr().0.to_uppercase())
+            ),
+            (None, to_piece) => format!(
+                "{}{}{}{}{}{}",
+                if from_piece.is_pawn() {
+                    String::new()
+                } else {
+                    from_piece.repr().0.to_uppercase()
+                },
+                from_coord,
+                to_piece.map_or_else(String::new, |piece| if piece.is_pawn() {
+                    ":".to_string()
+                } else {
+                    format!(":{}", piece.repr().0.to_uppercase())
+                }),
+                to_coord,
+                the_move
+                    .promotion
+                    .map_or_else(String::new, |piece| piece.repr().0.to_uppercase()),
+                if to_piece.is_some_and(|piece| piece.get_coord() != to_coord) {
+                    "ep"
+                } else {
+                    ""
+                }
+            ),
+        };
+
+        match the_move.get_check(board)? {
+            Some(Check::Check) => move_text.push('+'),
+            Some(Check::Mate) => move_text.push('#'),
+            None => (),
+        }
+
+        Ok(move_text)
+    }
+
+    /// Parse a move in concise reversible notation.
+    ///
+    /// # Errors
+    ///
+    /// Errors when the move is not in concise reversible notation.
+    fn parse_concise(
+        &self,
+        move_string: String,
+        to_move: Color,
+    ) -> ChuiResult<ConciseReversibleMove> {
+        let the_move: String = self.trim_and_check_whitespace(&move_string)?;
+        let invalid = |reason: &str| -> ChuiError {
+            ChuiError::InvalidInput(format!("{} is an invalid move: {}", the_move, reason))
+        };
+        let mut concise = ConciseReversibleMove {
+            chess_move: ChessMove::new(to_move),
+            captured: None,
+            en_passant: false,
+        };
+        let chess_move: &mut ChessMove = &mut concise.chess_move;
+
+        // Check or check mate.
+        let body: &str = if let Some(body) = the_move
+            .strip_suffix("++")
+            .or_else(|| the_move.strip_suffix('#'))
+        {
+            chess_move.set_check_mate();
+            body
+        } else if let Some(body) = the_move.strip_suffix('+') {
+            chess_move.set_check();
+            body
+        } else {
+            &the_move
+        };
+
+        // En passant.
+        let body: &str = match body
+            .strip_suffix("e.p.")
+            .or_else(|| body.strip_suffix("ep"))
+        {
+            Some(body) => {
+                concise.en_passant = true;
+                body
+            }
+            None => body,
+        };
+
+        // Castling.
+        match body {
+            "O-O" | "0-0" => chess_move.set_castling_king(),
+            "O-O-O" | "0-0-0" => chess_move.set_castling_queen(),
+            _ => {
+                let mut rest: &str = body;
+
+                // The piece to move. Pawns have no letter.
+                match rest.chars().next() {
+                    Some(token) if "KQRBN".contains(token) => {
+                        chess_move.set_piece_move(Piece::try_from(token)?);
+                        rest = &rest[1..];
+                    }
+                    _ => chess_move.set_pawn_move(),
+                }
+
+                let from_coord: Coord = rest
+                    .get(..2)
+                    .and_then(|from| Coord::try_from(from).ok())
+                    .ok_or_else(|| invalid("the \"from\" square is invalid"))?;
+                rest = &rest[2..];
+
+                let to_coord: Coord = match rest.strip_prefix(':') {
+                    // Captures the named piece on the "to" square. Pawns have no letter.
+                    Some(capture) => {
+                        chess_move.set_capture()?;
+                        rest = capture;
+
+                        concise.captured = match rest.chars().next() {
+                            Some(token) if "QRBN".contains(token) => {
+                                rest = &rest[1..];
+                                Some(Piece::try_from(token)?.get_kind())
+                            }
+                            _ => Some(PieceKind::Pawn),
+                        };
+
+                        let to_coord: Option<Coord> =
+                            rest.get(..2).and_then(|to| Coord::try_from(to).ok());
+                        rest = rest.get(2..).unwrap_or_default();
+                        to_coord
+                    }
+                    // A Pawn moving straight ahead only gives the rank it moves to.
+                    None if chess_move.is_pawn_move() => {
+                        let file: String = from_coord.to_string()[..1].to_string();
+                        let to_coord: Option<Coord> = rest
+                            .get(..1)
+                            .and_then(|rank| Coord::try_from((file.as_str(), rank)).ok());
+                        rest = rest.get(1..).unwrap_or_default();
+                        to_coord
+                    }
+                    None => {
+                        let to_coord: Option<Coord> =
+                            rest.get(..2).and_then(|to| Coord::try_from(to).ok());
+                        rest = rest.get(2..).unwrap_or_default();
+                        to_coord
+                    }
+                }
+                .ok_or_else(|| invalid("the \"to\" square is invalid"))?;
+
+                // The promotion piece.
+                match rest {
+                    "" => (),
+                    piece
+                        if piece.len() == 1
+                            && piece.chars().all(|c| CHAR_PROMOTION_PIECES.contains(&c)) =>
+                    {
+                        chess_move.set_promotion(Piece::try_from(piece.to_uppercase().as_str())?);
+                    }
+                    _ => return Err(invalid("the promotion piece is invalid")),
+                }
+
+                from_coord.validate_possible_move(to_coord)?;
+                chess_move.set_from_coord(Some(from_coord));
+                chess_move.set_to_coord(Some(to_coord));
+            }
+        }
+
+        if concise.en_passant
+            && !(chess_move.is_pawn_capture() && concise.captured == Some(PieceKind::Pawn))
+        {
+            return Err(invalid(
+                "only a Pawn capturing a Pawn can capture en passant",
+            ));
+        }
+
+        chess_move.set_input_move(move_string);
+        chess_move.is_parsed = true;
+
+        Ok(concise)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::coord::*;
+    use crate::testing::{new_game, play};
+
+    fn parse(input: &str, to_move: Color) -> ChuiResult<ChessMove> {
+        ConciseReversibleParser::new(to_move).parse_notation(input.to_string(), to_move)
+    }
+
+    fn reason(input: &str) -> String {
+        match parse(input, Color::White) {
+            Err(ChuiError::InvalidInput(reason)) => reason,
+            result => panic!("{:?} should be invalid input: {:?}", input, result),
+        }
+    }
+
+    fn generate(game: &Game, from: (char, u8), to: (char, u8)) -> ChuiResult<String> {
+        ConciseReversibleParser::new(game.to_move).generate_move_from_board_coordinates(
+            game,
+            Coord::try_from(from)?,
+            Coord::try_from(to)?,
+        )
+    }
+
+    #[test]
+    fn a_pawn_moving_ahead_gives_its_file_and_ranks() -> ChuiResult<()> {
+        let the_move = parse("e24", Color::White)?;
+        assert_eq!(the_move.get_coordinate_text(), "e2e4");
+        assert!(the_move.is_pawn_move());
+
+        let the_move = parse("e21Q#", Color::Black)?;
+        assert_eq!(the_move.get_coordinate_text(), "e2e1q");
+        assert!(the_move.is_check_mate());
+
+        assert!(parse("e2e4", Color::White).is_err());
+        assert!(parse("e22", Color::White).is_err());
+        assert_eq!(
+            reason("e29"),
+            "e29 is an invalid move: the \"to\" square is invalid"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn other_moves_give_both_squares() -> ChuiResult<()> {
+        let the_move = parse("Ng1f3", Color::White)?;
+        assert_eq!(the_move.get_coordinate_text(), "g1f3");
+        assert!(the_move.is_piece_move());
+
+        assert!(parse("Ng1-f3", Color::White).is_err());
+        assert!(parse("Ng1f", Color::White).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn a_capture_names_the_captured_piece() -> ChuiResult<()> {
+        let the_move = parse("Bb5:Nc6", Color::White)?;
+        assert!(the_move.is_piece_capture());
+        assert!(the_move.from_piece.is_some_and(|piece| piece.is_bishop()));
+
+        // A captured Pawn has no letter.
+        let the_move = parse("e4:d5", Color::White)?;
+        assert!(the_move.is_pawn_capture());
+
+        let the_move = parse("b7:Ra8N", Color::White)?;
+        assert!(the_move.is_pawn_capture());
+        assert_eq!(
+            the_move.promotion.map(|piece| piece.get_kind()),
+            Some(PieceKind::Knight)
+        );
+
+        // A King is never captured, and `x` is not the capture mark.
+        assert!(parse("Bb5:Kc6", Color::White).is_err());
+        assert!(parse("Bb5:Pc6", Color::White).is_err());
+        assert!(parse("Bb5xNc6", Color::White).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn en_passant_is_marked() -> ChuiResult<()> {
+        for input in ["e5:d6ep+", "e5:d6e.p.+"] {
+            let the_move = parse(input, Color::White)?;
+            assert!(the_move.is_pawn_capture());
+            assert!(the_move.is_check());
+        }
+
+        for input in ["Ng1f3ep", "e5:Nd6ep", "e56ep"] {
+            assert_eq!(
+                reason(input),
+                format!(
+                    "{} is an invalid move: only a Pawn capturing a Pawn can capture en passant",
+                    input
+                )
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn castling_with_letters_or_zeros() -> ChuiResult<()> {
+        assert!(parse("O-O", Color::White)?.is_castling_king());
+        assert!(parse("0-0-0", Color::Black)?.is_castling_queen());
+        assert!(parse("O-O-O-O", Color::White).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn the_captured_piece_must_match_the_board() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::ConciseReversible);
+        play(&mut game, &["e24", "d75", "e4:d5", "Qd8:d5", "Nb1c3"])?;
+
+        // A Pawn is on d2: not a Knight or a Queen, and the move must capture it.
+        assert!(game.parse("Qd5:Nd2".to_string(), game.to_move).is_err());
+        assert!(game.parse("Qd5:Qd2".to_string(), game.to_move).is_err());
+        assert!(game.parse("Qd5d2".to_string(), game.to_move).is_err());
+        // Nothing to capture on c4.
+        assert!(game.parse("Qd5:c4".to_string(), game.to_move).is_err());
+
+        play(&mut game, &["Qd5:d2+", "Bc1:Qd2"])?;
+        assert_eq!(
+            game.get_san_move_list(),
+            vec!["e4", "d5", "exd5", "Qxd5", "Nc3", "Qxd2+", "Bxd2"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn en_passant_must_be_marked_to_be_played() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::ConciseReversible);
+        play(&mut game, &["e24", "a76", "e45", "d75"])?;
+
+        assert!(game.parse("e5:d6".to_string(), game.to_move).is_err());
+        play(&mut game, &["e5:d6ep"])?;
+        assert_eq!(game.get_san_move_list()[4..], ["exd6"]);
+        Ok(())
+    }
+
+    #[test]
+    fn moves_are_written_with_the_captured_piece() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::ConciseReversible);
+        assert_eq!(generate(&game, E2, E4)?, "e24");
+        assert_eq!(generate(&game, G1, F3)?, "Ng1f3");
+        assert!(generate(&game, G1, G3).is_err());
+
+        play(&mut game, &["e24", "e75", "Ng1f3", "Nb8c6", "Bf1b5", "d76"])?;
+        assert_eq!(generate(&game, B5, C6)?, "Bb5:Nc6+");
+        assert_eq!(generate(&game, F3, E5)?, "Nf3:e5");
+        assert_eq!(generate(&game, E1, G1)?, "O-O");
+
+        game.set_fen("r3k3/1P6/8/3pP3/8/8/8/4K3 w q d6 0 1")?;
+        assert_eq!(generate(&game, E5, D6)?, "e5:d6ep");
+        assert_eq!(generate(&game, B7, A8)?, "b7:Ra8Q+");
+        assert_eq!(generate(&game, B7, B8)?, "b78Q+");
+        Ok(())
+    }
+
+    #[test]
+    fn written_moves_play_back() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::ConciseReversible);
+        let moves: Vec<((char, u8), (char, u8))> = vec![
+            (E2, E4),
+            (D7, D5),
+            (E4, E5),
+            (F7, F5),
+            (E5, F6),
+            (G8, F6),
+            (D1, H5),
+            (F6, H5),
+        ];
+        let mut move_texts: Vec<String> = Vec::new();
+
+        for (from, to) in moves {
+            let move_text = generate(&game, from, to)?;
+            play(&mut game, &[move_text.as_str()])?;
+            move_texts.push(move_text);
+        }
+
+        assert_eq!(
+            move_texts,
+            vec!["e24", "d75", "e45", "f75", "e5:f6ep", "Ng8:f6", "Qd1h5+", "Nf6:Qh5"]
+        );
+        Ok(())
     }
 }
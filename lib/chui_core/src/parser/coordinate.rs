@@ -4,20 +4,72 @@
 
 use crate::prelude::*;
 
-use super::Parser;
-
 /// A parser that will parse Coordinate chess notation.
 /// Example moves: `E2-E4`, `e7-e5`, `G1-F3`, `B8-c6`, `f1-b5`, etc.
-#[derive(Debug, Copy, Clone)]
-pub struct CoordinateParser;
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CoordinateParser {
+    /// The color to move.
+    pub to_move: Color,
+}
 
 impl Parser for CoordinateParser {
     /// Parse the chess move, return `Ok(ChessMove)` on success,
     /// `ChuiError::InvalidMove(reason)` on failure.
-    fn parse(&mut self, _the_move: String, _to_move: Color) -> ChuiResult<ChessMove> {
-        Err(ChuiError::InvalidMove(
-            "CoordinateParser not implemented".to_string(),
-        ))
+    ///
+    /// The move is the "from" square and the "to" square joined by a `-`, in upper or
+    /// lower case, followed by an optional promotion piece (e.g., `e7-e8Q` or `e7-e8=q`).
+    fn parse_notation(&mut self, move_string: String, to_move: Color) -> ChuiResult<ChessMove> {
+        self.to_move = to_move;
+        let the_move: String = self.trim_and_check_whitespace(&move_string)?.to_lowercase();
+
+        let (from, to) = the_move.split_once('-').ok_or_else(|| {
+            ChuiError::InvalidInput(format!(
+                "{} is an invalid move: the squares must be separated by `-`",
+                move_string.trim()
+            ))
+        })?;
+
+        let (to, promotion) = match (to.get(..2), to.get(2..)) {
+            (Some(to), Some(promotion)) => (to, promotion),
+            _ => {
+                return Err(ChuiError::InvalidInput(format!(
+                    "{} is an invalid move: the \"to\" square is missing",
+                    move_string.trim()
+                )))
+            }
+        };
+        let from_coord: Coord = Coord::try_from(from)?;
+        let to_coord: Coord = Coord::try_from(to)?;
+
+        let promotion: Option<Piece> = match promotion.strip_prefix('=').unwrap_or(promotion) {
+            "" => None,
+            piece => Some(
+                piece
+                    .chars()
+                    .next()
+                    .filter(|c| piece.len() == 1 && CHAR_PROMOTION_PIECES.contains(c))
+                    .and_then(|c| Piece::try_from(c.to_ascii_uppercase()).ok())
+                    .ok_or_else(|| {
+                        ChuiError::InvalidInput(format!(
+                            "{} is an invalid move: `{}` is not a promotion piece",
+                            move_string.trim(),
+                            piece
+                        ))
+                    })?,
+            ),
+        };
+
+        from_coord.validate_possible_move(to_coord)?;
+
+        Ok(ChessMove {
+            to_move,
+            promotion,
+            from_coord: Some(from_coord),
+            to_coord: Some(to_coord),
+            input_move: move_string,
+            is_parsed: true,
+            ..ChessMove::default()
+        })
     }
 
     fn name(&self) -> String {
@@ -25,36 +77,170 @@ impl Parser for CoordinateParser {
     }
 
     fn eg(&self) -> String {
-        format!("Examples for {}", self.name())
+        format!(
+            "Examples for {}: `E2-E4`, `e7-e5`, `G1-F3`, `B8-c6`, `e7-e8Q`, etc.",
+            self.name()
+        )
     }
 
     /// Return a String representing the move from board Coordinates to this
     /// parser's notation.
-    fn generate_move_from_board_Coordinates(
+    fn generate_move_from_board_coordinates(
         &self,
-        engine: &Engine,
-        coord: Coord,
+        game: &Game,
+        from_coord: Coord,
+        to_coord: Coord,
     ) -> ChuiResult<String> {
-        let board = &(engine.board.get_board());
-
-        let piece = match board[coord.get_rank()][coord.get_file()] {
-            Some(piece) => piece,
-            None => {
-                return Err(ChuiError::InvalidMove(format!(
-                    "Invalid move. No piece at ({}, {})",
-                    coord.get_rank(),
-                    coord.get_file()
-                )))
-            }
-        };
+        let the_move: ChessMove =
+            self.get_move_from_board_coordinates(game, from_coord, to_coord)?;
 
-        Ok(format!("Piece: {}", piece))
+        Ok(CoordinateParser::get_move_text(&the_move))
     }
 }
 
 impl CoordinateParser {
     /// Return a new dynamic parser that implements the `Parser` trait.
-    pub fn new() -> Box<dyn Parser + Send + Sync> {
-        Box::new(CoordinateParser {})
+    pub fn new(to_move: Color) -> Box<CoordinateParser> {
+        Box::new(CoordinateParser { to_move })
+    }
+
+    /// Return the move in Coordinate notation (e.g., `e2-e4` or `e7-e8Q`). Returns an empty
+    /// `String` if either square is not set.
+    pub fn get_move_text(the_move: &ChessMove) -> String {
+        match (the_move.from_coord, the_move.to_coord) {
+            (Some(from_coord), Some(to_coord)) => format!(
+                "{}-{}{}",
+                from_coord,
+                to_coord,
+                the_move
+                    .promotion
+                    .map_or_else(String::new, |piece| piece.repr().0.to_uppercase())
+            ),
+            _ => String::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::coord::*;
+    use crate::testing::{new_game, play};
+
+    fn parse(input: &str, to_move: Color) -> ChuiResult<ChessMove> {
+        CoordinateParser::new(to_move).parse_notation(input.to_string(), to_move)
+    }
+
+    fn reason(input: &str) -> String {
+        match parse(input, Color::White) {
+            Err(ChuiError::InvalidInput(reason)) => reason,
+            result => panic!("{:?} should be invalid input: {:?}", input, result),
+        }
+    }
+
+    #[test]
+    fn squares_are_joined_by_a_hyphen_in_either_case() -> ChuiResult<()> {
+        for input in ["e2-e4", "E2-E4", "e2-E4", " e2-e4 "] {
+            let the_move = parse(input, Color::White)?;
+            assert_eq!(the_move.from_coord, Coord::try_from(E2).ok());
+            assert_eq!(the_move.to_coord, Coord::try_from(E4).ok());
+            assert_eq!(the_move.promotion, None);
+        }
+
+        assert_eq!(
+            reason("e2e4"),
+            "e2e4 is an invalid move: the squares must be separated by `-`"
+        );
+        assert_eq!(
+            reason("e2-"),
+            "e2- is an invalid move: the \"to\" square is missing"
+        );
+        assert!(parse("e2 - e4", Color::White).is_err());
+        assert!(parse("i2-e4", Color::White).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn squares_no_piece_could_move_between_are_rejected() {
+        // Neither a line nor a Knight's jump, whatever is on the board.
+        assert!(parse("e2-d5", Color::White).is_err());
+        assert!(parse("e2-e2", Color::White).is_err());
+    }
+
+    #[test]
+    fn promotion_follows_the_to_square() -> ChuiResult<()> {
+        for input in ["e7-e8q", "E7-E8Q", "e7-e8=Q"] {
+            let the_move = parse(input, Color::White)?;
+            assert_eq!(
+                the_move.promotion.map(|piece| piece.get_kind()),
+                Some(PieceKind::Queen)
+            );
+        }
+
+        let the_move = parse("b2-b1=b", Color::Black)?;
+        assert_eq!(
+            the_move.promotion.map(|piece| piece.get_kind()),
+            Some(PieceKind::Bishop)
+        );
+
+        assert_eq!(
+            reason("e7-e8k"),
+            "e7-e8k is an invalid move: `k` is not a promotion piece"
+        );
+        assert_eq!(
+            reason("e7-e8qq"),
+            "e7-e8qq is an invalid move: `qq` is not a promotion piece"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn castling_is_the_kings_move() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Coordinate);
+        play(
+            &mut game,
+            &[
+                "E2-E4", "e7-e5", "G1-F3", "B8-c6", "f1-b5", "g8-f6", "e1-g1",
+            ],
+        )?;
+
+        assert_eq!(
+            game.get_san_move_list(),
+            vec!["e4", "e5", "Nf3", "Nc6", "Bb5", "Nf6", "O-O"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn moves_are_written_in_lower_case_with_an_upper_case_promotion() -> ChuiResult<()> {
+        let parser = CoordinateParser::new(Color::White);
+        let mut game = new_game(ParserEngine::Coordinate);
+
+        assert_eq!(
+            parser.generate_move_from_board_coordinates(
+                &game,
+                Coord::try_from(G1)?,
+                Coord::try_from(F3)?
+            )?,
+            "g1-f3"
+        );
+        assert!(parser
+            .generate_move_from_board_coordinates(&game, Coord::try_from(G1)?, Coord::try_from(G3)?)
+            .is_err());
+
+        game.set_fen("8/4P1k1/8/8/8/8/8/4K3 w - - 0 1")?;
+        assert_eq!(
+            parser.generate_move_from_board_coordinates(
+                &game,
+                Coord::try_from(E7)?,
+                Coord::try_from(E8)?
+            )?,
+            "e7-e8Q"
+        );
+        assert_eq!(
+            CoordinateParser::get_move_text(&parse("E7-E8=n", Color::White)?),
+            "e7-e8N"
+        );
+        Ok(())
     }
 }
@@ -4,18 +4,81 @@
 
 use crate::prelude::*;
 
+/// The names of the files in descriptive notation, from the a-file to the h-file. Each file
+/// is named after the piece that starts on it.
+const FILE_NAMES: [&str; 8] = ["QR", "QN", "QB", "Q", "K", "KB", "KN", "KR"];
+
 /// A parser that will parse English descriptive chess notation.
 /// Example moves: `P-K4`, `NxN`, `QxRch`, `Q-KR4 mate`, `O-O`, etc.
-#[derive(Debug, Copy, Clone)]
-pub struct DescriptiveParser;
+///
+/// Ranks are counted from the side of the player to move, so White's `P-K4` and Black's
+/// `P-K4` are `e4` and `e5`. A file may be named by its side of the board (e.g., `KB3`) or
+/// left for the board to decide (e.g., `B3`), and captures name the piece that is captured
+/// (e.g., `NxN` or `PxKBP`). The move is resolved against the legal moves of the board, so
+/// moves in this notation must be parsed with `parse()`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DescriptiveParser {
+    /// The color to move.
+    pub to_move: Color,
+}
+
+/// A move in descriptive notation, before it is resolved against the board.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DescriptiveMove {
+    /// The side to castle to, if castling.
+    castling: Option<Castling>,
+
+    /// The kind of piece to move.
+    piece: PieceKind,
+
+    /// The files the piece to move may stand on.
+    from_files: Vec<u8>,
+
+    /// The kind of piece to capture, and the files it may stand on.
+    captured: Option<(PieceKind, Vec<u8>)>,
+
+    /// The squares the piece may move to.
+    to_coords: Vec<Coord>,
+
+    /// The kind of piece to promote to.
+    promotion: Option<PieceKind>,
+
+    /// Check or check mate.
+    check: Option<Check>,
+}
 
 impl Parser for DescriptiveParser {
-    /// Parse the chess move, return `Ok(ChessMove)` on success,
-    /// `ChuiError::InvalidMove(reason)` on failure.
-    fn parse(&mut self, _the_move: String, _to_move: Color) -> ChuiResult<ChessMove> {
-        Err(ChuiError::InvalidMove(
-            "DescriptiveParser not implemented".to_string(),
-        ))
+    /// Descriptive notation cannot be understood without the board. Always errors; use
+    /// `parse()`.
+    fn parse_notation(&mut self, move_string: String, to_move: Color) -> ChuiResult<ChessMove> {
+        self.to_move = to_move;
+
+        Err(ChuiError::InvalidMove(format!(
+            "`{}` cannot be resolved without the board in descriptive notation",
+            move_string.trim()
+        )))
+    }
+
+    /// Parse the chess move and resolve it against the legal moves of `board`.
+    fn parse(&mut self, move_string: String, board: &Board) -> ChuiResult<ChessMove> {
+        self.to_move = board.get_to_move();
+        let descriptive: DescriptiveMove =
+            DescriptiveParser::parse_descriptive(&move_string, self.to_move)?;
+        let mut chess_move: ChessMove =
+            DescriptiveParser::resolve(&descriptive, board, self.to_move).map_err(|error| {
+                match error {
+                    ChuiError::InvalidMove(reason) => {
+                        ChuiError::InvalidMove(format!("`{}`: {}", move_string.trim(), reason))
+                    }
+                    error => error,
+                }
+            })?;
+
+        chess_move.check = descriptive.check;
+        chess_move.set_input_move(move_string);
+        chess_move.is_parsed = true;
+
+        Ok(chess_move)
     }
 
     fn name(&self) -> String {
@@ -23,36 +86,597 @@ impl Parser for DescriptiveParser {
     }
 
     fn eg(&self) -> String {
-        format!("Examples for {}", self.name())
+        format!(
+            "Examples for {}: `P-K4`, `N-KB3`, `NxN`, `QxRch`, `Q-KR4mate`, `P-QB8=Q`, `O-O`, etc.",
+            self.name()
+        )
     }
 
     /// Return a String representing the move from board Coordinates to this
     /// parser's notation.
-    fn generate_move_from_board_Coordinates(
+    fn generate_move_from_board_coordinates(
         &self,
-        engine: &Engine,
-        from_index: (Coord),
-        _to_index: (Coord),
+        game: &Game,
+        from_coord: Coord,
+        to_coord: Coord,
     ) -> ChuiResult<String> {
-        let board = &(engine.board.get_board());
+        let the_move: ChessMove =
+            self.get_move_from_board_coordinates(game, from_coord, to_coord)?;
+
+        DescriptiveParser::get_move_text(&the_move, &game.board)
+    }
+}
+
+impl DescriptiveParser {
+    /// Return a new dynamic parser that implements the `Parser` trait.
+    pub fn new(to_move: Color) -> Box<DescriptiveParser> {
+        Box::new(DescriptiveParser { to_move })
+    }
+
+    /// Return the move in descriptive notation (e.g., `P-K4`, `NxN`, `QxRch` or
+    /// `P-QB8=Q mate`), given the `board` the move is played on. The shortest text that
+    /// names only this move on the board is used. The move must be validated for the board
+    /// first (see `ChessMove::validate_move_for_board()`).
+    ///
+    /// # Errors
+    ///
+    /// Errors when the move is missing its coordinates or moving piece, or cannot be
+    /// applied to the board.
+    pub fn get_move_text(the_move: &ChessMove, board: &Board) -> ChuiResult<String> {
+        let (from_coord, to_coord, from_piece) =
+            match (the_move.from_coord, the_move.to_coord, the_move.from_piece) {
+                (Some(from_coord), Some(to_coord), Some(from_piece)) => {
+                    (from_coord, to_coord, from_piece)
+                }
+                _ => {
+                    return Err(ChuiError::InvalidMove(
+                        "The move must be validated for the board before it can be written"
+                            .to_string(),
+                    ))
+                }
+            };
+        let color: Color = from_piece.get_color();
 
-        let piece = match board[from_index.0][from_index.1] {
-            Some(piece) => piece,
+        let mut move_text: String = match the_move.castling {
+            Some(Castling::King) => "O-O".to_string(),
+            Some(Castling::Queen) => "O-O-O".to_string(),
             None => {
-                return Err(ChuiError::InvalidMove(format!(
-                    "Invalid move. No piece at ({}, {})",
-                    from_index.0, from_index.1
-                )))
+                let promotion: String = the_move.promotion.map_or_else(String::new, |piece| {
+                    format!("={}", piece.repr().0.to_uppercase())
+                });
+                let destinations: Vec<String> = match the_move.to_piece {
+                    Some(to_piece) => {
+                        let captured = DescriptiveParser::get_piece_names(to_piece);
+                        let square: String = DescriptiveParser::get_square_names(to_coord, color)
+                            .pop()
+                            .unwrap_or_default();
+                        captured
+                            .iter()
+                            .map(|captured| format!("x{}", captured))
+                            .chain(
+                                captured
+                                    .iter()
+                                    .map(|captured| format!("x{}/{}", captured, square)),
+                            )
+                            .collect()
+                    }
+                    None => DescriptiveParser::get_square_names(to_coord, color)
+                        .iter()
+                        .map(|square| format!("-{}", square))
+                        .collect(),
+                };
+
+                // Use the shortest text that names only this move.
+                let mut candidates: Vec<String> = DescriptiveParser::get_piece_names(from_piece)
+                    .iter()
+                    .flat_map(|piece| {
+                        let promotion: &str = &promotion;
+                        destinations.iter().map(move |destination| {
+                            format!("{}{}{}", piece, destination, promotion)
+                        })
+                    })
+                    .collect();
+                candidates.sort_by_key(String::len);
+
+                candidates
+                    .into_iter()
+                    .find(|candidate| {
+                        DescriptiveParser::parse_descriptive(candidate, color)
+                            .and_then(|descriptive| {
+                                DescriptiveParser::resolve(&descriptive, board, color)
+                            })
+                            .is_ok_and(|resolved| {
+                                resolved.from_coord == Some(from_coord)
+                                    && resolved.to_coord == Some(to_coord)
+                            })
+                    })
+                    .ok_or_else(|| {
+                        ChuiError::InvalidMove(format!(
+                            "The move from `{}` to `{}` cannot be written in descriptive \
+                            notation",
+                            from_coord, to_coord
+                        ))
+                    })?
+            }
+        };
+
+        match the_move.get_check(board)? {
+            Some(Check::Check) => move_text.push_str("ch"),
+            Some(Check::Mate) => move_text.push_str(" mate"),
+            None => (),
+        }
+
+        Ok(move_text)
+    }
+
+    /// Return the names of `piece` in descriptive notation, from the shortest to the
+    /// longest (e.g., `P`, `BP` and `KBP` for a Pawn on the f-file, or `N` and `KN` for a
+    /// Knight on the king side).
+    fn get_piece_names(piece: Piece) -> Vec<String> {
+        let letter: String = piece.repr().0.to_uppercase();
+        let file: usize = usize::from(piece.get_file());
+        let side: &str = if file < 4 { "Q" } else { "K" };
+
+        match piece.get_kind() {
+            PieceKind::Pawn => {
+                let mut names: Vec<String> = vec![letter.clone()];
+                if let Some(short) = DescriptiveParser::get_short_file_name(piece.get_file()) {
+                    names.push(format!("{}{}", short, letter));
+                }
+                names.push(format!("{}{}", FILE_NAMES[file], letter));
+                names
+            }
+            PieceKind::Knight | PieceKind::Bishop | PieceKind::Rook => {
+                vec![letter.clone(), format!("{}{}", side, letter)]
+            }
+            PieceKind::Queen | PieceKind::King => vec![letter],
+        }
+    }
+
+    /// Return the names of the square `coord` in descriptive notation for `color`, from
+    /// the shortest to the longest (e.g., `B3` and `KB3` for White's `f3`).
+    fn get_square_names(coord: Coord, color: Color) -> Vec<String> {
+        let rank: u8 = DescriptiveParser::get_rank_for_color(coord.get_rank(), color) + 1;
+        let mut names: Vec<String> = Vec::new();
+
+        if let Some(short) = DescriptiveParser::get_short_file_name(coord.get_file()) {
+            names.push(format!("{}{}", short, rank));
+        }
+        names.push(format!(
+            "{}{}",
+            FILE_NAMES[usize::from(coord.get_file())],
+            rank
+        ));
+
+        names
+    }
+
+    /// Return the name of the file without its side of the board (e.g., `B` for the
+    /// c-file and the f-file). The King and Queen files have no such name.
+    fn get_short_file_name(file: u8) -> Option<&'static str> {
+        match file {
+            0 | 7 => Some("R"),
+            1 | 6 => Some("N"),
+            2 | 5 => Some("B"),
+            _ => None,
+        }
+    }
+
+    /// Ranks are counted from each player's own side of the board. Convert the rank index
+    /// of the board to the rank index for `color`, or the other way around.
+    const fn get_rank_for_color(rank: u8, color: Color) -> u8 {
+        match color {
+            Color::White => rank,
+            Color::Black => 7 - rank,
+        }
+    }
+
+    /// Return the files named by `name` (e.g., `KB` is the f-file, and `B` is the c-file
+    /// or the f-file).
+    fn get_files(name: &str) -> Option<Vec<u8>> {
+        match name {
+            "R" => Some(vec![0, 7]),
+            "N" => Some(vec![1, 6]),
+            "B" => Some(vec![2, 5]),
+            _ => FILE_NAMES
+                .iter()
+                .position(|file_name| *file_name == name)
+                .and_then(|file| u8::try_from(file).ok())
+                .map(|file| vec![file]),
+        }
+    }
+
+    /// Return the kind of piece named by `letter`.
+    fn get_piece_kind(letter: char) -> Option<PieceKind> {
+        match letter {
+            'P' => Some(PieceKind::Pawn),
+            'N' => Some(PieceKind::Knight),
+            'B' => Some(PieceKind::Bishop),
+            'R' => Some(PieceKind::Rook),
+            'Q' => Some(PieceKind::Queen),
+            'K' => Some(PieceKind::King),
+            _ => None,
+        }
+    }
+
+    /// Parse the name of a piece (e.g., `P`, `KBP`, `N`, `QR` or `K`) into its kind and the
+    /// files it may stand on. A Pawn is named by its file, and a Knight, Bishop or Rook by
+    /// its side of the board.
+    fn parse_piece(name: &str) -> Option<(PieceKind, Vec<u8>)> {
+        let kind: PieceKind = DescriptiveParser::get_piece_kind(name.chars().last()?)?;
+        let prefix: &str = &name[..name.len() - 1];
+
+        let files: Vec<u8> = match (kind, prefix) {
+            (_, "") => (0..8).collect(),
+            (PieceKind::Pawn, prefix) => DescriptiveParser::get_files(prefix)?,
+            (PieceKind::Knight | PieceKind::Bishop | PieceKind::Rook, "Q") => (0..4).collect(),
+            (PieceKind::Knight | PieceKind::Bishop | PieceKind::Rook, "K") => (4..8).collect(),
+            _ => return None,
+        };
+
+        Some((kind, files))
+    }
+
+    /// Parse the name of a square (e.g., `K4`, `QB3` or `N5`) into the squares it may be
+    /// for `color`.
+    fn parse_square(name: &str, color: Color) -> Option<Vec<Coord>> {
+        let rank: u8 = name
+            .chars()
+            .last()?
+            .to_digit(10)
+            .and_then(|rank| u8::try_from(rank).ok())?;
+
+        if !(1..=8).contains(&rank) {
+            return None;
+        }
+
+        let rank: u8 = DescriptiveParser::get_rank_for_color(rank - 1, color);
+
+        DescriptiveParser::get_files(&name[..name.len() - 1])?
+            .into_iter()
+            .map(|file| Coord::new(file, rank).ok())
+            .collect()
+    }
+
+    /// Parse a move in descriptive notation for `color`. Case, whitespace, and `Kt` for
+    /// the Knight are accepted.
+    ///
+    /// # Errors
+    ///
+    /// Errors when the move is not in descriptive notation.
+    fn parse_descriptive(move_string: &str, color: Color) -> ChuiResult<DescriptiveMove> {
+        let invalid = |reason: &str| -> ChuiError {
+            ChuiError::InvalidInput(format!(
+                "{} is an invalid move: {}",
+                move_string.trim(),
+                reason
+            ))
+        };
+        let mut the_move: String = move_string
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_uppercase()
+            .replace("KT", "N");
+
+        if the_move.is_empty() {
+            return Err(invalid("the move is empty"));
+        }
+
+        let mut descriptive = DescriptiveMove {
+            castling: None,
+            piece: PieceKind::King,
+            from_files: (0..8).collect(),
+            captured: None,
+            to_coords: Vec::new(),
+            promotion: None,
+            check: None,
+        };
+
+        // Check, check mate and en passant.
+        loop {
+            if let Some(body) = ["MATE", "#", "++"]
+                .iter()
+                .find_map(|suffix| the_move.strip_suffix(suffix))
+            {
+                descriptive.check = Some(Check::Mate);
+                the_move = body.to_string();
+            } else if let Some(body) = ["CH", "+"]
+                .iter()
+                .find_map(|suffix| the_move.strip_suffix(suffix))
+            {
+                descriptive.check = descriptive.check.or(Some(Check::Check));
+                the_move = body.to_string();
+            } else if let Some(body) = ["E.P.", "EP"]
+                .iter()
+                .find_map(|suffix| the_move.strip_suffix(suffix))
+            {
+                the_move = body.to_string();
+            } else {
+                break;
+            }
+        }
+
+        // Castling.
+        descriptive.castling = match the_move.as_str() {
+            "O-O" | "0-0" | "CASTLES" | "CASTLESK" | "CASTLESKR" => Some(Castling::King),
+            "O-O-O" | "0-0-0" | "CASTLESQ" | "CASTLESQR" => Some(Castling::Queen),
+            _ => None,
+        };
+
+        if descriptive.castling.is_some() {
+            return Ok(descriptive);
+        }
+
+        // Promotion (e.g., `=Q`, `(Q)`, `/Q` or `Q`).
+        let promotion = the_move
+            .strip_suffix(')')
+            .and_then(|body| body.strip_suffix(|c: char| "NBRQ".contains(c)))
+            .and_then(|body| body.strip_suffix('('))
+            .map(|body| (body.len(), &the_move[body.len() + 1..body.len() + 2]))
+            .or_else(|| {
+                let body = the_move.strip_suffix(|c: char| "NBRQ".contains(c))?;
+                let body = body
+                    .strip_suffix(['=', '/'])
+                    .or_else(|| body.ends_with('8').then_some(body))?;
+                Some((body.len(), &the_move[the_move.len() - 1..]))
+            });
+
+        if let Some((len, piece)) = promotion {
+            descriptive.promotion = piece
+                .chars()
+                .next()
+                .and_then(DescriptiveParser::get_piece_kind);
+            the_move.truncate(len);
+        }
+
+        // The piece to move, and the square it moves to or the piece it captures.
+        let separator: usize = the_move
+            .find(['-', 'X'])
+            .ok_or_else(|| invalid("the move must contain `-` or `x`"))?;
+        let (piece, destination) = the_move.split_at(separator);
+
+        (descriptive.piece, descriptive.from_files) = DescriptiveParser::parse_piece(piece)
+            .ok_or_else(|| invalid("the piece to move is invalid"))?;
+
+        match destination.split_at(1) {
+            ("-", square) => {
+                descriptive.to_coords = DescriptiveParser::parse_square(square, color)
+                    .ok_or_else(|| invalid("the square to move to is invalid"))?;
+            }
+            (_, captured) => {
+                let (captured, square) = match captured.split_once('/') {
+                    Some((captured, square)) => (
+                        captured,
+                        DescriptiveParser::parse_square(square, color)
+                            .ok_or_else(|| invalid("the square to capture on is invalid"))?,
+                    ),
+                    None => (
+                        captured,
+                        (0..8)
+                            .flat_map(|file| {
+                                (0..8).filter_map(move |rank| Coord::new(file, rank).ok())
+                            })
+                            .collect(),
+                    ),
+                };
+
+                descriptive.captured = Some(
+                    DescriptiveParser::parse_piece(captured)
+                        .ok_or_else(|| invalid("the piece to capture is invalid"))?,
+                );
+                descriptive.to_coords = square;
+            }
+        }
+
+        Ok(descriptive)
+    }
+
+    /// Resolve the descriptive move to the only legal move on `board` for `color` that it
+    /// names.
+    ///
+    /// # Errors
+    ///
+    /// Errors when no legal move, or more than one legal move, is named by the move.
+    fn resolve(
+        descriptive: &DescriptiveMove,
+        board: &Board,
+        color: Color,
+    ) -> ChuiResult<ChessMove> {
+        let mut moves: Vec<ChessMove> = board
+            .legal_moves(color)
+            .into_iter()
+            .filter(|chess_move| match descriptive.castling {
+                Some(castling) => chess_move.castling == Some(castling),
+                None => DescriptiveParser::is_match(descriptive, chess_move),
+            })
+            .collect();
+
+        match moves.len() {
+            1 => Ok(moves.remove(0)),
+            0 => Err(ChuiError::InvalidMove(
+                "No legal move matches the move".to_string(),
+            )),
+            _ => Err(ChuiError::InvalidMove(format!(
+                "Ambiguous move: {} legal moves match the move ({})",
+                moves.len(),
+                moves
+                    .iter()
+                    .map(ChessMove::get_coordinate_text)
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ))),
+        }
+    }
+
+    /// Does the legal move `chess_move` match the descriptive move (that is not castling)?
+    fn is_match(descriptive: &DescriptiveMove, chess_move: &ChessMove) -> bool {
+        let (from_piece, to_coord) = match (chess_move.from_piece, chess_move.to_coord) {
+            (Some(from_piece), Some(to_coord)) => (from_piece, to_coord),
+            _ => return false,
+        };
+        let is_captured_match: bool = match (&descriptive.captured, chess_move.to_piece) {
+            (None, None) => true,
+            (Some((kind, files)), Some(to_piece)) => {
+                to_piece.get_kind() == *kind && files.contains(&to_piece.get_file())
             }
+            _ => false,
         };
 
-        Ok(format!("Piece: {}", piece))
+        chess_move.castling.is_none()
+            && from_piece.get_kind() == descriptive.piece
+            && descriptive.from_files.contains(&from_piece.get_file())
+            && descriptive.to_coords.contains(&to_coord)
+            && chess_move.promotion.map(|piece| piece.get_kind()) == descriptive.promotion
+            && is_captured_match
     }
 }
 
-impl DescriptiveParser {
-    /// Return a new dynamic parser that implements the `Parser` trait.
-    pub fn new() -> Box<dyn Parser + Send + Sync> {
-        Box::new(DescriptiveParser {})
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::coord::*;
+    use crate::testing::{new_game, play};
+
+    fn generate(game: &Game, from: (char, u8), to: (char, u8)) -> ChuiResult<String> {
+        DescriptiveParser::new(game.to_move).generate_move_from_board_coordinates(
+            game,
+            Coord::try_from(from)?,
+            Coord::try_from(to)?,
+        )
+    }
+
+    #[test]
+    fn play_moves() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Descriptive);
+        play(
+            &mut game,
+            &["P-K4", "p-k4", "Kt-KB3", "N-QB3", "B-N5", "P-QR3", "BxN"],
+        )?;
+
+        // Either Pawn can capture the Bishop.
+        assert!(play(&mut game, &["PxB"]).is_err());
+        play(&mut game, &["QPxB", "O-O"])?;
+
+        assert_eq!(
+            game.get_san_move_list(),
+            vec!["e4", "e5", "Nf3", "Nc6", "Bb5", "a6", "Bxc6", "dxc6", "O-O"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parse_for_each_color() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Descriptive);
+
+        // Both Knights can move to the third rank on the Bishop files.
+        assert!(play(&mut game, &["N-B3"]).is_err());
+        play(&mut game, &["N-KB3", "N-KB3", "P-Q4", "P-Q4"])?;
+        assert_eq!(game.get_san_move_list(), vec!["Nf3", "Nf6", "d4", "d5"]);
+
+        // Descriptive notation needs the board.
+        assert!(DescriptiveParser::new(Color::White)
+            .parse_notation("P-K4".to_string(), Color::White)
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn parse_checks_and_mates() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Descriptive);
+        play(&mut game, &["P-KB3", "P-K4", "P-KN4"])?;
+        play(&mut game, &["Q-R5 mate"])?;
+        assert_eq!(game.win_condition, Some(WinCondition::Checkmate));
+        assert!(game.move_list.last().unwrap().is_check_mate());
+
+        game.set_fen("r3k3/8/8/8/8/8/8/Q3K3 w - - 0 1")?;
+        for input in ["QxRch", "QxR ch", "QxR+", "q x r CH"] {
+            let the_move = game.parse(input.to_string(), game.to_move)?;
+            assert_eq!(the_move.get_coordinate_text(), "a1a8");
+            assert!(the_move.is_check());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_promotions_and_en_passant() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Descriptive);
+        game.set_fen("1n2k3/2P5/8/8/8/8/p7/4K3 w - - 0 1")?;
+
+        for input in ["P-QB8=Q", "P-B8(Q)", "P-B8/Q", "P-B8Q"] {
+            let the_move = game.parse(input.to_string(), game.to_move)?;
+            assert_eq!(the_move.get_coordinate_text(), "c7c8q");
+        }
+        assert_eq!(
+            game.parse("PxN=N".to_string(), game.to_move)?
+                .get_coordinate_text(),
+            "c7b8n"
+        );
+        // The Pawn must promote.
+        assert!(game.parse("P-B8".to_string(), game.to_move).is_err());
+
+        play(&mut game, &["P-B8=N"])?;
+        // Black's eighth rank is White's first rank.
+        assert_eq!(
+            game.parse("P-QR8=Q".to_string(), game.to_move)?
+                .get_coordinate_text(),
+            "a2a1q"
+        );
+
+        game.set_fen("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1")?;
+        for input in ["PxP", "PxP e.p.", "PxPep", "KPxQP"] {
+            let the_move = game.parse(input.to_string(), game.to_move)?;
+            assert_eq!(the_move.get_coordinate_text(), "e5d6");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_invalid_moves() {
+        let mut game = new_game(ParserEngine::Descriptive);
+
+        for input in [
+            "", "P-K9", "P-K", "PK4", "X-K4", "QP-K4", "P-K5", "QN-KB3", "N-Q2", "PxP", "O-O",
+            "KBP-KN4",
+        ] {
+            assert!(
+                game.parse(input.to_string(), game.to_move).is_err(),
+                "{:?} should not parse",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn generate_moves() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Descriptive);
+        assert_eq!(generate(&game, E2, E4)?, "P-K4");
+        assert_eq!(generate(&game, G1, F3)?, "N-KB3");
+        assert_eq!(generate(&game, B1, A3)?, "N-QR3");
+
+        play(&mut game, &["P-K4"])?;
+        assert_eq!(generate(&game, E7, E5)?, "P-K4");
+
+        play(&mut game, &["P-Q4", "P-QB4"])?;
+        assert_eq!(generate(&game, D5, C4)?, "PxBP");
+        assert_eq!(generate(&game, D5, E4)?, "PxKP");
+        assert_eq!(generate(&game, D5, D4)?, "P-Q5");
+
+        game.set_fen("r3k3/8/8/8/8/8/8/Q3K2R w K - 0 1")?;
+        assert_eq!(generate(&game, A1, A8)?, "QxRch");
+        assert_eq!(generate(&game, E1, G1)?, "O-O");
+
+        game.set_fen("rnbqkbnr/pppp1ppp/8/4p3/6P1/5P2/PPPPP2P/RNBQKBNR b KQkq - 0 2")?;
+        assert_eq!(generate(&game, D8, H4)?, "Q-R5 mate");
+
+        // Two Rooks can capture the Knight.
+        game.set_fen("4k3/8/8/8/R2n3R/8/8/4K3 w - - 0 1")?;
+        assert_eq!(generate(&game, A4, D4)?, "QRxN");
+        assert_eq!(generate(&game, H4, D4)?, "KRxN");
+
+        game.set_fen("1n2k3/2P5/8/8/8/8/8/4K3 w - - 0 1")?;
+        assert_eq!(generate(&game, C7, C8)?, "P-B8=Qch");
+        assert_eq!(generate(&game, C7, B8)?, "PxN=Qch");
+        Ok(())
     }
 }
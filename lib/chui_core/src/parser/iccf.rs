@@ -15,7 +15,7 @@ pub struct ICCFParser {
 impl Parser for ICCFParser {
     /// Parse the chess move, return `Ok(ChessMove)` on success,
     /// `ChuiError::InvalidMove(reason)` on failure.
-    fn parse(&mut self, move_string: String, to_move: Color) -> ChuiResult<ChessMove> {
+    fn parse_notation(&mut self, move_string: String, to_move: Color) -> ChuiResult<ChessMove> {
         self.to_move = to_move;
         let mut the_move: String = self.trim_and_check_whitespace(&move_string)?;
         the_move.retain(|c: char| CHAR_RANKS.contains(&c) || CHAR_PROMOTION_PIECES.contains(&c));
@@ -30,14 +30,22 @@ impl Parser for ICCFParser {
                 .as_str(),
             )?;
         }
-        let from_coord: Coord = Coord::try_from((
-            the_move.remove(0).to_digit(10).unwrap() - 1,
-            the_move.remove(0).to_digit(10).unwrap() - 1,
-        ))?;
-        let to_coord: Coord = Coord::try_from((
-            the_move.remove(0).to_digit(10).unwrap() - 1,
-            the_move.remove(0).to_digit(10).unwrap() - 1,
-        ))?;
+        // The letters of a promotion piece are kept too, so they can be anywhere.
+        let squares: Vec<u32> = match the_move
+            .drain(..4)
+            .map(|c: char| c.to_digit(10).map(|digit| digit - 1))
+            .collect::<Option<Vec<u32>>>()
+        {
+            Some(squares) => squares,
+            None => {
+                return Err(ChuiError::InvalidMove(format!(
+                    "{} is an invalid move: the squares must be four digits from 1 to 8",
+                    move_string
+                )))
+            }
+        };
+        let from_coord: Coord = Coord::try_from((squares[0], squares[1]))?;
+        let to_coord: Coord = Coord::try_from((squares[2], squares[3]))?;
         let promotion: Option<Piece> = if !the_move.is_empty() {
             Piece::try_from(the_move.remove(0)).ok()
         } else {
@@ -88,3 +96,41 @@ impl ICCFParser {
         Box::new(ICCFParser { to_move })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{new_game, play};
+
+    #[test]
+    fn squares_are_file_and_rank_digits() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::ICCF);
+        play(&mut game, &["5254", "5755", "7163"])?;
+        assert_eq!(game.get_san_move_list(), vec!["e4", "e5", "Nf3"]);
+        Ok(())
+    }
+
+    #[test]
+    fn moves_in_other_notations_are_invalid() {
+        let mut game = new_game(ParserEngine::ICCF);
+
+        for input in ["Bb5:Nc6", "Bb5xNc6", "b7a8rQ"] {
+            match game.parse(input.to_string(), game.to_move) {
+                Err(ChuiError::InvalidMove(reason)) => assert_eq!(
+                    reason,
+                    format!(
+                        "{} is an invalid move: the squares must be four digits from 1 to 8",
+                        input
+                    )
+                ),
+                result => panic!("{:?} should be an invalid move: {:?}", input, result),
+            }
+        }
+
+        // The lenient parser tries the moves as well, and cannot play them either.
+        game.lenient = true;
+        for input in ["Bb5:Nc6", "Bb5xNc6", "b7a8rQ"] {
+            assert!(game.parse(input.to_string(), game.to_move).is_err());
+        }
+    }
+}
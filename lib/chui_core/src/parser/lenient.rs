@@ -0,0 +1,470 @@
+//! Lenient notation module.
+
+#![allow(clippy::new_ret_no_self)]
+
+use crate::prelude::*;
+
+/// The annotations, check marks and en passant marks that are dropped from the end of a
+/// move, in lower case.
+const SUFFIXES: [&str; 8] = ["!", "?", "+", "#", "ch", "mate", "e.p.", "ep"];
+
+/// The separators that are dropped from a move.
+const SEPARATORS: [char; 8] = ['x', ':', '-', '=', '(', ')', '/', ' '];
+
+/// The most edits a suggested move may be away from the input move.
+const MAX_SUGGESTION_DISTANCE: usize = 1;
+
+/// A parser that accepts moves as people tend to type them, and resolves them to the
+/// legal move they clearly mean. Example moves: `nf3`, `0-0`, `e8Q`, `Bxc6ch`, `exd6ep`,
+/// `Ng1-f3`, `e2e4`, etc.
+///
+/// The move is compared with the ways each legal move may be written: algebraic (`Nf3`),
+/// with or without disambiguation (`Ngf3`), long algebraic (`Ng1f3`) and coordinate (`g1f3`),
+/// ignoring captures, separators, check marks, annotations and en passant marks, and `0`
+/// for `O` in castling. The case of the move is matched as typed first (so `bc4` is a Pawn
+/// capture and `Bc4` a Bishop move), then in any case. When the move is ambiguous or not
+/// legal, the error suggests the legal moves that were likely meant (see `suggest()`).
+/// The piece letters are those of the parser's `Locale`. The move is resolved against the
+/// legal moves of the board, so moves must be parsed with `parse()`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LenientParser {
+    /// The color to move.
+    pub to_move: Color,
+
+    /// The language of the piece letters.
+    pub locale: Locale,
+}
+
+/// A legal move and the ways it may be written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Candidate {
+    /// The legal move.
+    chess_move: ChessMove,
+
+    /// The move in algebraic notation, without check marks.
+    san: String,
+
+    /// The ways the move may be written, normalized (see `LenientParser::normalize()`).
+    keys: Vec<String>,
+}
+
+impl Parser for LenientParser {
+    /// Lenient input cannot be understood without the board. Always errors; use `parse()`.
+    fn parse_notation(&mut self, move_string: String, to_move: Color) -> ChuiResult<ChessMove> {
+        self.to_move = to_move;
+
+        Err(ChuiError::InvalidMove(format!(
+            "`{}` cannot be resolved without the board",
+            move_string.trim()
+        )))
+    }
+
+    /// Parse the chess move and resolve it against the legal moves of `board`.
+    fn parse(&mut self, move_string: String, board: &Board) -> ChuiResult<ChessMove> {
+        self.to_move = board.get_to_move();
+        let the_move: &str = move_string.trim();
+
+        if the_move.is_empty() {
+            self.invalid_input("Input move cannot be empty")?;
+        }
+
+        let candidates: Vec<Candidate> = LenientParser::get_candidates(board, self.locale)?;
+        let matches: Vec<&Candidate> = LenientParser::get_matches(the_move, &candidates);
+
+        match matches.len() {
+            1 => {
+                let mut chess_move: ChessMove = matches[0].chess_move.clone();
+                chess_move.check = chess_move.get_check(board)?;
+                chess_move.set_input_move(move_string);
+                chess_move.is_parsed = true;
+
+                Ok(chess_move)
+            }
+            0 => Err(ChuiError::InvalidMove(format!(
+                "`{}` is not a legal move{}",
+                the_move,
+                LenientParser::did_you_mean(&LenientParser::get_suggestions(the_move, &candidates))
+            ))),
+            _ => Err(ChuiError::InvalidMove(format!(
+                "`{}` is ambiguous{}",
+                the_move,
+                LenientParser::did_you_mean(
+                    &matches
+                        .iter()
+                        .map(|candidate| candidate.san.clone())
+                        .collect::<Vec<String>>()
+                )
+            ))),
+        }
+    }
+
+    /// Set the language of the piece letters.
+    fn set_locale(&mut self, locale: Locale) {
+        self.locale = locale;
+    }
+
+    fn name(&self) -> String {
+        "Lenient Parser".to_string()
+    }
+
+    fn eg(&self) -> String {
+        format!(
+            "Examples for {}: `nf3`, `0-0`, `e8Q`, `Bxc6ch`, `exd6ep`, `Ng1-f3`, `e2e4`, etc.",
+            self.name()
+        )
+    }
+
+    /// Return a String representing the move from board Coordinates in algebraic
+    /// notation.
+    fn generate_move_from_board_coordinates(
+        &self,
+        game: &Game,
+        from_coord: Coord,
+        to_coord: Coord,
+    ) -> ChuiResult<String> {
+        self.get_move_from_board_coordinates(game, from_coord, to_coord)?
+            .to_san_for_locale(&game.board, self.locale)
+    }
+}
+
+impl LenientParser {
+    /// Return a new dynamic parser that implements the `Parser` trait.
+    pub fn new(to_move: Color) -> Box<LenientParser> {
+        Box::new(LenientParser {
+            to_move,
+            locale: Locale::default(),
+        })
+    }
+
+    /// Return the legal moves (in algebraic notation, with the piece letters of `locale`)
+    /// that `move_string` likely means on `board`: the moves it matches, if it is ambiguous, or else the moves that are the
+    /// fewest edits away from it, if any are close.
+    ///
+    /// # Errors
+    ///
+    /// Errors when a legal move cannot be written in algebraic notation, which should not
+    /// happen.
+    pub fn suggest(move_string: &str, board: &Board, locale: Locale) -> ChuiResult<Vec<String>> {
+        let candidates: Vec<Candidate> = LenientParser::get_candidates(board, locale)?;
+        let matches: Vec<&Candidate> = LenientParser::get_matches(move_string, &candidates);
+
+        if matches.is_empty() {
+            return Ok(LenientParser::get_suggestions(move_string, &candidates));
+        }
+
+        Ok(matches
+            .iter()
+            .map(|candidate| candidate.san.clone())
+            .collect())
+    }
+
+    /// Normalize a move for comparison: drop annotations, check marks, en passant marks
+    /// and separators, and read `0` as `O`.
+    fn normalize(move_string: &str) -> String {
+        let mut the_move: &str = move_string.trim();
+
+        while let Some(suffix) = SUFFIXES
+            .iter()
+            .find(|suffix| the_move.to_lowercase().ends_with(*suffix))
+        {
+            the_move = &the_move[..the_move.len() - suffix.len()];
+        }
+
+        the_move
+            .chars()
+            .filter(|c| !SEPARATORS.contains(c))
+            .map(|c| if c == '0' { 'O' } else { c })
+            .collect()
+    }
+
+    /// Return the legal moves of `board`, with the ways each may be written with the piece
+    /// letters of `locale`.
+    ///
+    /// # Errors
+    ///
+    /// Errors when a legal move cannot be written in algebraic notation.
+    fn get_candidates(board: &Board, locale: Locale) -> ChuiResult<Vec<Candidate>> {
+        board
+            .legal_moves(board.get_to_move())
+            .into_iter()
+            .map(|chess_move| {
+                let san: String = chess_move
+                    .to_san_for_locale(board, locale)?
+                    .trim_end_matches(['+', '#'])
+                    .to_string();
+                let keys: Vec<String> = LenientParser::get_keys(&chess_move, &san, locale)
+                    .iter()
+                    .map(|key| LenientParser::normalize(key))
+                    .collect();
+
+                Ok(Candidate {
+                    chess_move,
+                    san,
+                    keys,
+                })
+            })
+            .collect()
+    }
+
+    /// Return the ways `chess_move` may be written, given its algebraic notation `san`,
+    /// with the piece letters of `locale`.
+    fn get_keys(chess_move: &ChessMove, san: &str, locale: Locale) -> Vec<String> {
+        let (from_coord, to_coord, from_piece) = match (
+            chess_move.from_coord,
+            chess_move.to_coord,
+            chess_move.from_piece,
+        ) {
+            (Some(from_coord), Some(to_coord), Some(from_piece)) => {
+                (from_coord, to_coord, from_piece)
+            }
+            _ => return vec![san.to_string()],
+        };
+        let from: String = from_coord.to_string();
+        let to: String = to_coord.to_string();
+        let promotion: String = chess_move
+            .promotion
+            .and_then(|piece| locale.get_piece_letter(piece.get_kind()))
+            .map_or_else(String::new, String::from);
+        let letter: String = locale
+            .get_piece_letter(from_piece.get_kind())
+            .map_or_else(String::new, String::from);
+
+        let mut keys: Vec<String> = vec![
+            san.to_string(),
+            format!("{}{}{}", from, to, promotion),
+            format!("{}{}{}{}", letter, from, to, promotion),
+            format!("{}{}{}{}", letter, &from[..1], to, promotion),
+            format!("{}{}{}{}", letter, &from[1..], to, promotion),
+        ];
+
+        // A piece move without its disambiguation (e.g., `Nd2` for `Nbd2`) is ambiguous.
+        // A Pawn move may name the Pawn, unless `P` is a piece letter (a Dutch Knight).
+        if from_piece.is_pawn() {
+            if locale.get_piece_kind('P').is_none() {
+                keys.push(format!("P{}", san));
+            }
+        } else {
+            keys.push(format!("{}{}", letter, to));
+        }
+
+        keys
+    }
+
+    /// Return the candidates that `move_string` matches, in the case as typed, or else in
+    /// any case.
+    fn get_matches<'a>(move_string: &str, candidates: &'a [Candidate]) -> Vec<&'a Candidate> {
+        let the_move: String = LenientParser::normalize(move_string);
+
+        let matches: Vec<&Candidate> = candidates
+            .iter()
+            .filter(|candidate| candidate.keys.contains(&the_move))
+            .collect();
+
+        if !matches.is_empty() {
+            return matches;
+        }
+
+        candidates
+            .iter()
+            .filter(|candidate| {
+                candidate
+                    .keys
+                    .iter()
+                    .any(|key| key.eq_ignore_ascii_case(&the_move))
+            })
+            .collect()
+    }
+
+    /// Return the algebraic notation of the candidates that are the fewest edits away
+    /// from `move_string`, ignoring case, if they are close enough.
+    fn get_suggestions(move_string: &str, candidates: &[Candidate]) -> Vec<String> {
+        let the_move: String = LenientParser::normalize(move_string).to_lowercase();
+        let distances: Vec<(usize, &Candidate)> = candidates
+            .iter()
+            .map(|candidate| {
+                let distance: usize = candidate
+                    .keys
+                    .iter()
+                    .map(|key| LenientParser::get_distance(&the_move, &key.to_lowercase()))
+                    .min()
+                    .unwrap_or(usize::MAX);
+
+                (distance, candidate)
+            })
+            .collect();
+
+        let closest: usize = distances
+            .iter()
+            .map(|(distance, _)| *distance)
+            .min()
+            .unwrap_or(usize::MAX);
+
+        if closest > MAX_SUGGESTION_DISTANCE {
+            return Vec::new();
+        }
+
+        let mut suggestions: Vec<String> = distances
+            .into_iter()
+            .filter(|(distance, _)| *distance == closest)
+            .map(|(_, candidate)| candidate.san.clone())
+            .collect();
+        suggestions.sort();
+        suggestions.dedup();
+
+        suggestions
+    }
+
+    /// Return the edit (Levenshtein) distance between two moves.
+    fn get_distance(a: &str, b: &str) -> usize {
+        let b: Vec<char> = b.chars().collect();
+        let mut distances: Vec<usize> = (0..=b.len()).collect();
+
+        for (i, a_char) in a.chars().enumerate() {
+            let mut previous: usize = distances[0];
+            distances[0] = i + 1;
+
+            for (j, b_char) in b.iter().enumerate() {
+                let substitution: usize = previous + usize::from(a_char != *b_char);
+                previous = distances[j + 1];
+                distances[j + 1] = substitution.min(distances[j] + 1).min(previous + 1);
+            }
+        }
+
+        distances[b.len()]
+    }
+
+    /// Return the "did you mean …" part of an error message for the `suggestions`.
+    fn did_you_mean(suggestions: &[String]) -> String {
+        let suggestions: Vec<String> = suggestions
+            .iter()
+            .map(|suggestion| format!("`{}`", suggestion))
+            .collect();
+
+        match suggestions.split_last() {
+            None => String::new(),
+            Some((last, [])) => format!("; did you mean {}?", last),
+            Some((last, rest)) => format!("; did you mean {} or {}?", rest.join(", "), last),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{self, play};
+
+    fn new_game() -> Game {
+        let mut game = testing::new_game(ParserEngine::Algebraic);
+        game.lenient = true;
+        game
+    }
+
+    fn reason(game: &mut Game, the_move: &str) -> String {
+        match game.parse(the_move.to_string(), game.to_move) {
+            Err(ChuiError::InvalidMove(reason)) => reason,
+            result => panic!("{:?} should be an invalid move: {:?}", the_move, result),
+        }
+    }
+
+    #[test]
+    fn play_human_input() -> ChuiResult<()> {
+        let mut game = new_game();
+        play(
+            &mut game,
+            &[
+                "e2e4", "Ng8-f6", "e5", "d7d5", "exd6ep", "nc6", "ng1f3", "b6", "Bb5", "Bb7",
+                "Bxc6ch", "bxc6", "0-0",
+            ],
+        )?;
+        assert_eq!(
+            game.get_san_move_list(),
+            vec![
+                "e4", "Nf6", "e5", "d5", "exd6", "Nc6", "Nf3", "b6", "Bb5", "Bb7", "Bxc6+", "Bxc6",
+                "O-O"
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parse_promotions() -> ChuiResult<()> {
+        let mut game = new_game();
+        game.set_fen("8/4P1k1/8/8/8/8/8/4K3 w - - 0 1")?;
+
+        for input in ["e8Q", "e8=q", "e7e8q", "e7-e8=Q", "e8(Q)", "Pe8Q"] {
+            let the_move = game.parse(input.to_string(), game.to_move)?;
+            assert_eq!(
+                the_move.promotion.map(|piece| piece.get_kind()),
+                Some(PieceKind::Queen),
+                "{:?} should promote to a Queen",
+                input
+            );
+        }
+
+        let the_move = game.parse("e8n".to_string(), game.to_move)?;
+        assert_eq!(
+            the_move.promotion.map(|piece| piece.get_kind()),
+            Some(PieceKind::Knight)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn match_case_as_typed_first() -> ChuiResult<()> {
+        let mut game = new_game();
+        game.set_fen("4k3/8/8/8/8/1P6/8/4KB2 w - - 0 1")?;
+
+        assert_eq!(
+            game.parse("bc4".to_string(), game.to_move)?
+                .to_san(&game.board)?,
+            "Bc4"
+        );
+
+        game.set_fen("4k3/8/8/8/2p5/1P6/8/4KB2 w - - 0 1")?;
+        assert_eq!(
+            game.parse("bc4".to_string(), game.to_move)?
+                .to_san(&game.board)?,
+            "bxc4"
+        );
+        assert_eq!(
+            game.parse("Bc4".to_string(), game.to_move)?
+                .to_san(&game.board)?,
+            "Bxc4"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn suggest_moves() -> ChuiResult<()> {
+        let mut game = new_game();
+
+        assert_eq!(
+            reason(&mut game, "Nc4"),
+            "`Nc4` is not a legal move; did you mean `Nc3` or `c4`?"
+        );
+        assert_eq!(game.suggest_moves("Nc4")?, vec!["Nc3", "c4"]);
+        assert_eq!(reason(&mut game, "Qxf7#"), "`Qxf7#` is not a legal move");
+
+        game.set_fen("4k3/8/8/8/8/5N2/8/1N2K3 w - - 0 1")?;
+        assert_eq!(
+            reason(&mut game, "nd2"),
+            "`nd2` is ambiguous; did you mean `Nbd2` or `Nfd2`?"
+        );
+        assert_eq!(game.suggest_moves("Nd2")?, vec!["Nbd2", "Nfd2"]);
+        Ok(())
+    }
+
+    #[test]
+    fn lenient_is_opt_in() {
+        let mut game = new_game();
+        game.lenient = false;
+
+        for input in ["nf3", "Ng1-f3", "0-0", "e2e4"] {
+            assert!(
+                game.parse(input.to_string(), game.to_move).is_err(),
+                "{:?} should not parse",
+                input
+            );
+        }
+    }
+}
@@ -6,16 +6,49 @@ use crate::prelude::*;
 
 /// A parser that will parse long algebraic chess notation.
 /// Example moves: `e2e4`, `e7e5`, `d2d3`, `Bf8b4+`, `Bb5xc6`, etc.
-#[derive(Debug, Copy, Clone)]
-pub struct LongAlgebraicParser;
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LongAlgebraicParser {
+    /// The color to move.
+    pub to_move: Color,
+}
 
 impl Parser for LongAlgebraicParser {
     /// Parse the chess move, return `Ok(ChessMove)` on success,
     /// `ChuiError::InvalidMove(reason)` on failure.
-    fn parse(&mut self, _the_move: String, _to_move: Color) -> ChuiResult<ChessMove> {
-        Err(ChuiError::InvalidMove(
-            "LongAlgebraicParser not implemented".to_string(),
-        ))
+    ///
+    /// The move is an optional piece letter, the "from" square, an optional `-` or `x`
+    /// (capture), the "to" square, an optional promotion piece (e.g., `=Q`) and an optional
+    /// check (`+`) or check mate (`#` or `++`). Castling is `O-O` or `O-O-O`.
+    fn parse_notation(&mut self, move_string: String, to_move: Color) -> ChuiResult<ChessMove> {
+        self.to_move = to_move;
+        let the_move: String = self.trim_and_check_whitespace(&move_string)?;
+        let mut chess_move = ChessMove::new(to_move);
+
+        // Check or check mate.
+        let body: &str = if let Some(body) = the_move
+            .strip_suffix("++")
+            .or_else(|| the_move.strip_suffix('#'))
+        {
+            chess_move.set_check_mate();
+            body
+        } else if let Some(body) = the_move.strip_suffix('+') {
+            chess_move.set_check();
+            body
+        } else {
+            &the_move
+        };
+
+        // Castling.
+        match body {
+            "O-O" | "0-0" => chess_move.set_castling_king(),
+            "O-O-O" | "0-0-0" => chess_move.set_castling_queen(),
+            _ => LongAlgebraicParser::parse_squares(body, &mut chess_move)?,
+        }
+
+        chess_move.set_input_move(move_string);
+        chess_move.is_parsed = true;
+
+        Ok(chess_move)
     }
 
     fn name(&self) -> String {
@@ -23,36 +56,280 @@ impl Parser for LongAlgebraicParser {
     }
 
     fn eg(&self) -> String {
-        format!("Examples for {}", self.name())
+        format!(
+            "Examples for {}: `e2e4`, `e7e5`, `Ng1f3`, `Bf8b4+`, `Bb5xc6`, `e7e8=Q#`, `O-O`, etc.",
+            self.name()
+        )
     }
 
     /// Return a String representing the move from board Coordinates to this
     /// parser's notation.
-    fn generate_move_from_board_Coordinates(
+    fn generate_move_from_board_coordinates(
         &self,
-        engine: &Engine,
-        from_index: (Coord),
-        _to_index: (Coord),
+        game: &Game,
+        from_coord: Coord,
+        to_coord: Coord,
     ) -> ChuiResult<String> {
-        let board = &(engine.board.get_board());
-
-        let piece = match board[from_index.0][from_index.1] {
-            Some(piece) => piece,
-            None => {
-                return Err(ChuiError::InvalidMove(format!(
-                    "Invalid move. No piece at ({}, {})",
-                    from_index.0, from_index.1
-                )))
-            }
-        };
+        let the_move: ChessMove =
+            self.get_move_from_board_coordinates(game, from_coord, to_coord)?;
 
-        Ok(format!("Piece: {}", piece))
+        LongAlgebraicParser::get_move_text(&the_move, &game.board)
     }
 }
 
 impl LongAlgebraicParser {
     /// Return a new dynamic parser that implements the `Parser` trait.
-    pub fn new() -> Box<dyn Parser + Send + Sync> {
-        Box::new(LongAlgebraicParser {})
+    pub fn new(to_move: Color) -> Box<LongAlgebraicParser> {
+        Box::new(LongAlgebraicParser { to_move })
+    }
+
+    /// Return the move in long algebraic notation (e.g., `Ng1f3`, `Bb5xc6` or `e7e8=Q#`),
+    /// given the `board` the move is played on. The move must be validated for the board
+    /// first (see `ChessMove::validate_move_for_board()`).
+    ///
+    /// # Errors
+    ///
+    /// Errors when the move is missing its coordinates or moving piece, or cannot be
+    /// applied to the board.
+    pub fn get_move_text(the_move: &ChessMove, board: &Board) -> ChuiResult<String> {
+        let (from_coord, to_coord, from_piece) =
+            match (the_move.from_coord, the_move.to_coord, the_move.from_piece) {
+                (Some(from_coord), Some(to_coord), Some(from_piece)) => {
+                    (from_coord, to_coord, from_piece)
+                }
+                _ => {
+                    return Err(ChuiError::InvalidMove(
+                        "The move must be validated for the board before it can be written"
+                            .to_string(),
+                    ))
+                }
+            };
+
+        let mut move_text: String = match the_move.castling {
+            Some(Castling::King) => "O-O".to_string(),
+            Some(Castling::Queen) => "O-O-O".to_string(),
+            None => format!(
+                "{}{}{}{}{}",
+                if from_piece.is_pawn() {
+                    String::new()
+                } else {
+                    from_piece.repr().0.to_uppercase()
+                },
+                from_coord,
+                if the_move.to_piece.is_some() { "x" } else { "" },
+                to_coord,
+                the_move.promotion.map_or_else(String::new, |piece| format!(
+                    "={}",
+                    piece.repr().0.to_uppercase()
+                ))
+            ),
+        };
+
+        match the_move.get_check(board)? {
+            Some(Check::Check) => move_text.push('+'),
+            Some(Check::Mate) => move_text.push('#'),
+            None => (),
+        }
+
+        Ok(move_text)
+    }
+
+    /// Parse the piece, squares, capture and promotion of a move that is not castling
+    /// (e.g., `Bb5xc6` or `e7e8=Q`) into `chess_move`.
+    ///
+    /// # Errors
+    ///
+    /// Errors when the move is not in long algebraic notation.
+    fn parse_squares(body: &str, chess_move: &mut ChessMove) -> ChuiResult<()> {
+        let invalid = |reason: &str| -> ChuiError {
+            ChuiError::InvalidInput(format!("{} is an invalid move: {}", body, reason))
+        };
+        let mut tokens = body.chars().peekable();
+
+        // The piece to move. Pawns have no letter.
+        match tokens.peek() {
+            Some(&token) if "KQRBN".contains(token) => {
+                chess_move.set_piece_move(Piece::try_from(token)?);
+                tokens.next();
+            }
+            _ => chess_move.set_pawn_move(),
+        }
+
+        // The "from" square.
+        let from: String = tokens.by_ref().take(2).collect();
+        let from_coord: Coord = Coord::try_from(from.as_str())
+            .map_err(|_| invalid("the \"from\" square is invalid"))?;
+
+        // Moves to or captures on the "to" square.
+        match tokens.peek() {
+            Some('x') => {
+                chess_move.set_capture()?;
+                tokens.next();
+            }
+            Some('-') => {
+                tokens.next();
+            }
+            _ => (),
+        }
+
+        let to: String = tokens.by_ref().take(2).collect();
+        let to_coord: Coord =
+            Coord::try_from(to.as_str()).map_err(|_| invalid("the \"to\" square is invalid"))?;
+
+        // The promotion piece.
+        let promotion: String = tokens.collect();
+
+        match promotion.strip_prefix('=').unwrap_or(&promotion) {
+            "" => (),
+            piece
+                if piece.len() == 1
+                    && piece.chars().all(|c| CHAR_PROMOTION_PIECES.contains(&c)) =>
+            {
+                chess_move.set_promotion(Piece::try_from(piece.to_uppercase().as_str())?);
+            }
+            _ => return Err(invalid("the promotion piece is invalid")),
+        }
+
+        from_coord.validate_possible_move(to_coord)?;
+        chess_move.set_from_coord(Some(from_coord));
+        chess_move.set_to_coord(Some(to_coord));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::coord::*;
+    use crate::testing::{new_game, play};
+
+    fn parse(input: &str, to_move: Color) -> ChuiResult<ChessMove> {
+        LongAlgebraicParser::new(to_move).parse_notation(input.to_string(), to_move)
+    }
+
+    fn generate(game: &Game, from: (char, u8), to: (char, u8)) -> ChuiResult<String> {
+        LongAlgebraicParser::new(game.to_move).generate_move_from_board_coordinates(
+            game,
+            Coord::try_from(from)?,
+            Coord::try_from(to)?,
+        )
+    }
+
+    #[test]
+    fn pieces_are_named_and_pawns_are_not() -> ChuiResult<()> {
+        let the_move = parse("e2e4", Color::White)?;
+        assert_eq!(the_move.from_coord, Coord::try_from(E2).ok());
+        assert_eq!(the_move.to_coord, Coord::try_from(E4).ok());
+        assert!(the_move.is_pawn_move());
+
+        let the_move = parse("Ng1f3", Color::White)?;
+        assert!(the_move.is_piece_move());
+        assert!(the_move.from_piece.is_some_and(|piece| piece.is_knight()));
+
+        // Piece letters are upper case, and a Pawn has none.
+        assert!(parse("nb1c3", Color::White).is_err());
+        assert!(parse("Pe2e4", Color::White).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn the_from_square_is_always_given() {
+        assert!(parse("e4", Color::White).is_err());
+        assert!(parse("Ne4", Color::White).is_err());
+        assert!(parse("Nxe4", Color::White).is_err());
+    }
+
+    #[test]
+    fn captures_are_marked_and_moves_may_be_hyphenated() -> ChuiResult<()> {
+        let the_move = parse("Bb5xc6", Color::White)?;
+        assert!(the_move.is_piece_capture());
+        assert!(the_move.from_piece.is_some_and(|piece| piece.is_bishop()));
+
+        let the_move = parse("Bf8-b4+", Color::Black)?;
+        assert!(the_move.is_piece_move());
+        assert!(the_move.is_check());
+
+        let the_move = parse("d7xc6", Color::Black)?;
+        assert!(the_move.is_pawn_capture());
+
+        assert!(parse("Ng1xf3x", Color::White).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn promotion_and_mate_suffixes() -> ChuiResult<()> {
+        let the_move = parse("e7e8=Q#", Color::White)?;
+        assert!(the_move.is_check_mate());
+        assert_eq!(
+            the_move.promotion.map(|piece| piece.get_kind()),
+            Some(PieceKind::Queen)
+        );
+
+        // `++` is an older way to write mate.
+        let the_move = parse("d2xc1n++", Color::Black)?;
+        assert!(the_move.is_pawn_capture());
+        assert!(the_move.is_check_mate());
+        assert_eq!(
+            the_move.promotion.map(|piece| piece.get_kind()),
+            Some(PieceKind::Knight)
+        );
+
+        assert!(parse("e7e8=K", Color::White).is_err());
+        assert!(parse("e7e8QQ", Color::White).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn castling_with_letters_or_zeros() -> ChuiResult<()> {
+        assert!(parse("O-O", Color::White)?.is_castling_king());
+        assert!(parse("0-0", Color::Black)?.is_castling_king());
+        assert!(parse("O-O-O", Color::Black)?.is_castling_queen());
+        assert!(parse("0-0-0+", Color::White)?.is_castling_queen());
+        assert!(parse("O-O-O-O", Color::White).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn the_piece_and_squares_must_match_the_board() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::LongAlgebraic);
+        play(
+            &mut game,
+            &[
+                "e2e4", "e7e5", "Ng1f3", "Nb8c6", "Bf1b5", "a7a6", "Bb5xc6", "d7xc6", "O-O",
+            ],
+        )?;
+        assert_eq!(
+            game.get_san_move_list(),
+            vec!["e4", "e5", "Nf3", "Nc6", "Bb5", "a6", "Bxc6", "dxc6", "O-O"]
+        );
+
+        // No Knight left on c6, the Bishop on f8 is blocked and the Queen's path is not clear.
+        assert!(play(&mut game, &["Nc6e5"]).is_err());
+        assert!(play(&mut game, &["Bf8xb4"]).is_err());
+        assert!(play(&mut game, &["Qd8d1"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn moves_are_written_with_the_piece_capture_and_check() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::LongAlgebraic);
+        assert_eq!(generate(&game, E2, E4)?, "e2e4");
+        assert_eq!(generate(&game, G1, F3)?, "Ng1f3");
+        assert!(generate(&game, G1, G3).is_err());
+
+        play(
+            &mut game,
+            &["e2e4", "e7e5", "Ng1f3", "Nb8c6", "Bf1b5", "d7d6"],
+        )?;
+        assert_eq!(generate(&game, B5, C6)?, "Bb5xc6+");
+        assert_eq!(generate(&game, E1, G1)?, "O-O");
+
+        game.set_fen("7k/4P3/8/8/8/8/8/4K3 w - - 0 1")?;
+        assert_eq!(generate(&game, E7, E8)?, "e7e8=Q+");
+
+        game.set_fen("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1")?;
+        assert_eq!(generate(&game, A1, A8)?, "Ra1a8#");
+        Ok(())
     }
 }
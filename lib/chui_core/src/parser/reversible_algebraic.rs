@@ -6,16 +6,51 @@ use crate::prelude::*;
 
 /// A parser that will parse reversible algebraic chess notation.
 /// Example moves: `e2-e4`, `e7-e5`, `Bb5xNc6`, `Bf8-b4#`, etc.
-#[derive(Debug, Copy, Clone)]
-pub struct ReversibleAlgebraicParser;
+///
+/// A move is written in long algebraic notation with the captured piece named after the
+/// `x` (e.g., `Bb5xNc6`; a captured Pawn has no letter, e.g., `e4xd5`), and en passant
+/// captures are marked with `ep` (e.g., `e5xd6ep`), so the move can be taken back from the
+/// notation alone. Because the captured piece is part of the move, a move parsed with
+/// `parse()` is checked against the board.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ReversibleAlgebraicParser {
+    /// The color to move.
+    pub to_move: Color,
+}
+
+/// A move in reversible algebraic notation, along with what it claims about the board.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ReversibleMove {
+    /// The parsed move.
+    chess_move: ChessMove,
+
+    /// The kind of the captured piece.
+    captured: Option<PieceKind>,
+
+    /// Is the move an en passant capture?
+    en_passant: bool,
+}
 
 impl Parser for ReversibleAlgebraicParser {
     /// Parse the chess move, return `Ok(ChessMove)` on success,
     /// `ChuiError::InvalidMove(reason)` on failure.
-    fn parse(&mut self, _the_move: String, _to_move: Color) -> ChuiResult<ChessMove> {
-        Err(ChuiError::InvalidMove(
-            "ReversibleAlgebraicParser not implemented".to_string(),
-        ))
+    fn parse_notation(&mut self, move_string: String, to_move: Color) -> ChuiResult<ChessMove> {
+        self.to_move = to_move;
+
+        Ok(self.parse_reversible(move_string, to_move)?.chess_move)
+    }
+
+    /// Parse the chess move and check that the captured piece and en passant given by the
+    /// move match the `board`.
+    fn parse(&mut self, move_string: String, board: &Board) -> ChuiResult<ChessMove> {
+        self.to_move = board.get_to_move();
+        let reversible: ReversibleMove = self.parse_reversible(move_string, self.to_move)?;
+        let mut chess_move: ChessMove = reversible.chess_move;
+        chess_move.validate_move_for_board(board)?;
+        chess_move.validate_captured_piece(reversible.captured, reversible.en_passant)?;
+        chess_move.check = chess_move.get_check(board)?;
+
+        Ok(chess_move)
     }
 
     fn name(&self) -> String {
@@ -23,36 +58,405 @@ impl Parser for ReversibleAlgebraicParser {
     }
 
     fn eg(&self) -> String {
-        format!("Examples for {}", self.name())
+        format!(
+            "Examples for {}: `e2-e4`, `Ng1-f3`, `Bb5xNc6`, `e4xd5`, `e5xd6ep`, `b7xRa8=Q`, \
+            `Bf8-b4#`, `O-O`, etc.",
+            self.name()
+        )
     }
 
     /// Return a String representing the move from board Coordinates to this
     /// parser's notation.
-    fn generate_move_from_board_Coordinates(
+    fn generate_move_from_board_coordinates(
         &self,
-        engine: &Engine,
-        from_index: Coord,
-        _to_index: Coord,
+        game: &Game,
+        from_coord: Coord,
+        to_coord: Coord,
     ) -> ChuiResult<String> {
-        let board = &(engine.board.get_board());
-
-        let piece = match board[from_index.0][from_index.1] {
-            Some(piece) => piece,
-            None => {
-                return Err(ChuiError::InvalidMove(format!(
-                    "Invalid move. No piece at ({}, {})",
-                    from_index.0, from_index.1
-                )))
-            }
-        };
+        let the_move: ChessMove =
+            self.get_move_from_board_coordinates(game, from_coord, to_coord)?;
 
-        Ok(format!("Piece: {}", piece))
+        ReversibleAlgebraicParser::get_move_text(&the_move, &game.board)
     }
 }
 
 impl ReversibleAlgebraicParser {
     /// Return a new dynamic parser that implements the `Parser` trait.
-    pub fn new() -> Box<dyn Parser + Send + Sync> {
-        Box::new(ReversibleAlgebraicParser {})
+    pub fn new(to_move: Color) -> Box<ReversibleAlgebraicParser> {
+        Box::new(ReversibleAlgebraicParser { to_move })
+    }
+
+    /// Return the move in reversible algebraic notation (e.g., `Ng1-f3`, `Bb5xNc6+`,
+    /// `e5xd6ep` or `b7xRa8=Q`), given the `board` the move is played on. The move must be
+    /// validated for the board first (see `ChessMove::validate_move_for_board()`).
+    ///
+    /// # Errors
+    ///
+    /// Errors when the move is missing its coordinates or moving piece, or cannot be
+    /// applied to the board.
+    pub fn get_move_text(the_move: &ChessMove, board: &Board) -> ChuiResult<String> {
+        let (from_coord, to_coord, from_piece) =
+            match (the_move.from_coord, the_move.to_coord, the_move.from_piece) {
+                (Some(from_coord), Some(to_coord), Some(from_piece)) => {
+                    (from_coord, to_coord, from_piece)
+                }
+                _ => {
+                    return Err(ChuiError::InvalidMove(
+                        "The move must be validated for the board before it can be written"
+                            .to_string(),
+                    ))
+                }
+            };
+
+        let mut move_text: String = match the_move.castling {
+            Some(Castling::King) => "O-O".to_string(),
+            Some(Castling::Queen) => "O-O-O".to_string(),
+            None => format!(
+                "{}{}{}{}{}{}",
+                if from_piece.is_pawn() {
+                    String::new()
+                } else {
+                    from_piece.repr().0.to_uppercase()
+                },
+                from_coord,
+                the_move.to_piece.map_or_else(
+                    || "-".to_string(),
+                    |piece| if piece.is_pawn() {
+                        "x".to_string()
+                    } else {
+                        format!("x{}", piece.repr().0.to_uppercase())
+                    }
+                ),
+                to_coord,
+                the_move.promotion.map_or_else(String::new, |piece| format!(
+                    "={}",
+                    piece.repr().0.to_uppercase()
+                )),
+                if the_move
+                    .to_piece
+                    .is_some_and(|piece| piece.get_coord() != to_coord)
+                {
+                    "ep"
+                } else {
+                    ""
+                }
+            ),
+        };
+
+        match the_move.get_check(board)? {
+            Some(Check::Check) => move_text.push('+'),
+            Some(Check::Mate) => move_text.push('#'),
+            None => (),
+        }
+
+        Ok(move_text)
+    }
+
+    /// Parse a move in reversible algebraic notation.
+    ///
+    /// # Errors
+    ///
+    /// Errors when the move is not in reversible algebraic notation.
+    fn parse_reversible(&self, move_string: String, to_move: Color) -> ChuiResult<ReversibleMove> {
+        let the_move: String = self.trim_and_check_whitespace(&move_string)?;
+        let invalid = |reason: &str| -> ChuiError {
+            ChuiError::InvalidInput(format!("{} is an invalid move: {}", the_move, reason))
+        };
+        let mut reversible = ReversibleMove {
+            chess_move: ChessMove::new(to_move),
+            captured: None,
+            en_passant: false,
+        };
+        let chess_move: &mut ChessMove = &mut reversible.chess_move;
+
+        // Check or check mate.
+        let body: &str = if let Some(body) = the_move
+            .strip_suffix("++")
+            .or_else(|| the_move.strip_suffix('#'))
+        {
+            chess_move.set_check_mate();
+            body
+        } else if let Some(body) = the_move.strip_suffix('+') {
+            chess_move.set_check();
+            body
+        } else {
+            &the_move
+        };
+
+        // En passant.
+        let body: &str = match body
+            .strip_suffix("e.p.")
+            .or_else(|| body.strip_suffix("ep"))
+        {
+            Some(body) => {
+                reversible.en_passant = true;
+                body
+            }
+            None => body,
+        };
+
+        // Castling.
+        match body {
+            "O-O" | "0-0" => chess_move.set_castling_king(),
+            "O-O-O" | "0-0-0" => chess_move.set_castling_queen(),
+            _ => {
+                let mut rest: &str = body;
+
+                // The piece to move. Pawns have no letter.
+                match rest.chars().next() {
+                    Some(token) if "KQRBN".contains(token) => {
+                        chess_move.set_piece_move(Piece::try_from(token)?);
+                        rest = &rest[1..];
+                    }
+                    _ => chess_move.set_pawn_move(),
+                }
+
+                let from_coord: Coord = rest
+                    .get(..2)
+                    .and_then(|from| Coord::try_from(from).ok())
+                    .ok_or_else(|| invalid("the \"from\" square is invalid"))?;
+                rest = &rest[2..];
+
+                // Moves to, or captures the named piece on, the "to" square. Pawns have no
+                // letter.
+                if let Some(capture) = rest.strip_prefix('x') {
+                    chess_move.set_capture()?;
+                    rest = capture;
+
+                    reversible.captured = match rest.chars().next() {
+                        Some(token) if "QRBN".contains(token) => {
+                            rest = &rest[1..];
+                            Some(Piece::try_from(token)?.get_kind())
+                        }
+                        _ => Some(PieceKind::Pawn),
+                    };
+                } else if let Some(to) = rest.strip_prefix('-') {
+                    rest = to;
+                } else {
+                    return Err(invalid("the squares must be separated by `-` or `x`"));
+                }
+
+                let to_coord: Coord = rest
+                    .get(..2)
+                    .and_then(|to| Coord::try_from(to).ok())
+                    .ok_or_else(|| invalid("the \"to\" square is invalid"))?;
+                rest = &rest[2..];
+
+                // The promotion piece.
+                match rest.strip_prefix('=').unwrap_or(rest) {
+                    "" => (),
+                    piece
+                        if piece.len() == 1
+                            && piece.chars().all(|c| CHAR_PROMOTION_PIECES.contains(&c)) =>
+                    {
+                        chess_move.set_promotion(Piece::try_from(piece.to_uppercase().as_str())?);
+                    }
+                    _ => return Err(invalid("the promotion piece is invalid")),
+                }
+
+                from_coord.validate_possible_move(to_coord)?;
+                chess_move.set_from_coord(Some(from_coord));
+                chess_move.set_to_coord(Some(to_coord));
+            }
+        }
+
+        if reversible.en_passant
+            && !(chess_move.is_pawn_capture() && reversible.captured == Some(PieceKind::Pawn))
+        {
+            return Err(invalid(
+                "only a Pawn capturing a Pawn can capture en passant",
+            ));
+        }
+
+        chess_move.set_input_move(move_string);
+        chess_move.is_parsed = true;
+
+        Ok(reversible)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::coord::*;
+    use crate::testing::{new_game, play};
+
+    fn parse(input: &str, to_move: Color) -> ChuiResult<ChessMove> {
+        ReversibleAlgebraicParser::new(to_move).parse_notation(input.to_string(), to_move)
+    }
+
+    fn reason(input: &str) -> String {
+        match parse(input, Color::White) {
+            Err(ChuiError::InvalidInput(reason)) => reason,
+            result => panic!("{:?} should be invalid input: {:?}", input, result),
+        }
+    }
+
+    fn generate(game: &Game, from: (char, u8), to: (char, u8)) -> ChuiResult<String> {
+        ReversibleAlgebraicParser::new(game.to_move).generate_move_from_board_coordinates(
+            game,
+            Coord::try_from(from)?,
+            Coord::try_from(to)?,
+        )
+    }
+
+    #[test]
+    fn the_squares_are_joined_by_a_hyphen_or_a_capture() -> ChuiResult<()> {
+        let the_move = parse("e2-e4", Color::White)?;
+        assert_eq!(the_move.get_coordinate_text(), "e2e4");
+        assert!(the_move.is_pawn_move());
+
+        let the_move = parse("Bf8-b4#", Color::Black)?;
+        assert!(the_move.is_piece_move());
+        assert!(the_move.is_check_mate());
+
+        assert_eq!(
+            reason("e2e4"),
+            "e2e4 is an invalid move: the squares must be separated by `-` or `x`"
+        );
+        assert!(parse("e4", Color::White).is_err());
+        assert!(parse("e2-e9", Color::White).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn a_capture_names_the_captured_piece() -> ChuiResult<()> {
+        let the_move = parse("Bb5xNc6", Color::White)?;
+        assert!(the_move.is_piece_capture());
+        assert!(the_move.from_piece.is_some_and(|piece| piece.is_bishop()));
+
+        // A captured Pawn has no letter.
+        let the_move = parse("e4xd5", Color::White)?;
+        assert!(the_move.is_pawn_capture());
+
+        let the_move = parse("b7xRa8=Q", Color::White)?;
+        assert!(the_move.is_pawn_capture());
+        assert_eq!(
+            the_move.promotion.map(|piece| piece.get_kind()),
+            Some(PieceKind::Queen)
+        );
+
+        // A King is never captured, and a piece is only named after the `x`.
+        assert!(parse("Bb5xKc6", Color::White).is_err());
+        assert!(parse("Bb5xPc6", Color::White).is_err());
+        assert!(parse("Bb5-Nc6", Color::White).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn en_passant_is_marked() -> ChuiResult<()> {
+        for input in ["e5xd6ep+", "e5xd6e.p.+"] {
+            let the_move = parse(input, Color::White)?;
+            assert!(the_move.is_pawn_capture());
+            assert!(the_move.is_check());
+        }
+
+        for input in ["Ng1-f3ep", "e5xNd6ep", "e5-e6ep"] {
+            assert_eq!(
+                reason(input),
+                format!(
+                    "{} is an invalid move: only a Pawn capturing a Pawn can capture en passant",
+                    input
+                )
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn promotion_follows_an_optional_equals_sign() -> ChuiResult<()> {
+        let the_move = parse("e2-e1n", Color::Black)?;
+        assert_eq!(
+            the_move.promotion.map(|piece| piece.get_kind()),
+            Some(PieceKind::Knight)
+        );
+
+        assert_eq!(
+            reason("e7-e8=K"),
+            "e7-e8=K is an invalid move: the promotion piece is invalid"
+        );
+        assert!(parse("e7-e8QQ", Color::White).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn the_captured_piece_must_match_the_board() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::ReversibleAlgebraic);
+        play(&mut game, &["e2-e4", "d7-d5", "e4xd5", "Qd8xd5", "Nb1-c3"])?;
+
+        // A Pawn is on d2: not a Knight or a Queen, and the move must capture it.
+        assert!(game.parse("Qd5xNd2".to_string(), game.to_move).is_err());
+        assert!(game.parse("Qd5xQd2".to_string(), game.to_move).is_err());
+        assert!(game.parse("Qd5-d2".to_string(), game.to_move).is_err());
+        // Nothing to capture on c4.
+        assert!(game.parse("Qd5xc4".to_string(), game.to_move).is_err());
+
+        play(&mut game, &["Qd5xd2+", "Bc1xQd2"])?;
+        assert_eq!(
+            game.get_san_move_list(),
+            vec!["e4", "d5", "exd5", "Qxd5", "Nc3", "Qxd2+", "Bxd2"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn en_passant_must_be_marked_to_be_played() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::ReversibleAlgebraic);
+        play(&mut game, &["e2-e4", "a7-a6", "e4-e5", "d7-d5"])?;
+
+        assert!(game.parse("e5xd6".to_string(), game.to_move).is_err());
+        play(&mut game, &["e5xd6ep"])?;
+        assert_eq!(game.get_san_move_list()[4..], ["exd6"]);
+        Ok(())
+    }
+
+    #[test]
+    fn moves_are_written_with_the_captured_piece() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::ReversibleAlgebraic);
+        assert_eq!(generate(&game, E2, E4)?, "e2-e4");
+        assert_eq!(generate(&game, G1, F3)?, "Ng1-f3");
+        assert!(generate(&game, G1, G3).is_err());
+
+        play(
+            &mut game,
+            &["e2-e4", "e7-e5", "Ng1-f3", "Nb8-c6", "Bf1-b5", "d7-d6"],
+        )?;
+        assert_eq!(generate(&game, B5, C6)?, "Bb5xNc6+");
+        assert_eq!(generate(&game, F3, E5)?, "Nf3xe5");
+        assert_eq!(generate(&game, E1, G1)?, "O-O");
+
+        game.set_fen("r3k3/1P6/8/3pP3/8/8/8/4K3 w q d6 0 1")?;
+        assert_eq!(generate(&game, E5, D6)?, "e5xd6ep");
+        assert_eq!(generate(&game, B7, A8)?, "b7xRa8=Q+");
+        assert_eq!(generate(&game, B7, B8)?, "b7-b8=Q+");
+        Ok(())
+    }
+
+    #[test]
+    fn written_moves_play_back() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::ReversibleAlgebraic);
+        let moves: Vec<((char, u8), (char, u8))> = vec![
+            (E2, E4),
+            (D7, D5),
+            (E4, E5),
+            (F7, F5),
+            (E5, F6),
+            (G8, F6),
+            (D1, H5),
+            (F6, H5),
+        ];
+        let mut move_texts: Vec<String> = Vec::new();
+
+        for (from, to) in moves {
+            let move_text = generate(&game, from, to)?;
+            play(&mut game, &[move_text.as_str()])?;
+            move_texts.push(move_text);
+        }
+
+        assert_eq!(
+            move_texts,
+            vec!["e2-e4", "d7-d5", "e4-e5", "f7-f5", "e5xf6ep", "Ng8xf6", "Qd1-h5+", "Nf6xQh5"]
+        );
+        Ok(())
     }
 }
@@ -6,16 +6,68 @@ use crate::prelude::*;
 
 /// A parser that will parse Smith chess notation.
 /// Example moves: `e1g1c`, `b4c3n`, `b5c6n`, `d7c6b`, `e2e4`, etc.
-#[derive(Debug, Copy, Clone)]
-pub struct SmithParser;
+///
+/// A move is the "from" square and the "to" square, followed by the lowercase letter of
+/// the captured piece, if any, then `c` (castling King side), `C` (castling Queen side) or
+/// `E` (en passant), if any, then the uppercase letter of the promotion piece, if any
+/// (e.g., `b7a8rQ`). Because the captured piece is part of the move, a move parsed with
+/// `parse()` is checked against the board.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SmithParser {
+    /// The color to move.
+    pub to_move: Color,
+}
+
+/// A move in Smith notation, along with what it claims about the board.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SmithMove {
+    /// The parsed move.
+    chess_move: ChessMove,
+
+    /// The kind of the captured piece.
+    captured: Option<PieceKind>,
+
+    /// Is the move an en passant capture?
+    en_passant: bool,
+}
 
 impl Parser for SmithParser {
     /// Parse the chess move, return `Ok(ChessMove)` on success,
     /// `ChuiError::InvalidMove(reason)` on failure.
-    fn parse(&mut self, _the_move: String, _to_move: Color) -> ChuiResult<ChessMove> {
-        Err(ChuiError::InvalidMove(
-            "SmithParser not implemented".to_string(),
-        ))
+    fn parse_notation(&mut self, move_string: String, to_move: Color) -> ChuiResult<ChessMove> {
+        self.to_move = to_move;
+
+        Ok(self.parse_smith(move_string, to_move)?.chess_move)
+    }
+
+    /// Parse the chess move and check that the captured piece, castling and en passant
+    /// given by the move match the `board`.
+    fn parse(&mut self, move_string: String, board: &Board) -> ChuiResult<ChessMove> {
+        self.to_move = board.get_to_move();
+        let smith: SmithMove = self.parse_smith(move_string, self.to_move)?;
+        let mut chess_move: ChessMove = smith.chess_move.clone();
+        chess_move.validate_move_for_board(board)?;
+        chess_move.validate_captured_piece(smith.captured, smith.en_passant)?;
+
+        if chess_move.castling != smith.chess_move.castling {
+            return Err(ChuiError::InvalidMove(format!(
+                "`{}` {} castling, but the move is {}",
+                chess_move.input_move.trim(),
+                if smith.chess_move.castling.is_some() {
+                    "is"
+                } else {
+                    "is not"
+                },
+                chess_move.castling.map_or_else(
+                    || "not castling".to_string(),
+                    |castling| format!("castling {:?} side", castling)
+                )
+            )));
+        }
+
+        chess_move.check = chess_move.get_check(board)?;
+
+        Ok(chess_move)
     }
 
     fn name(&self) -> String {
@@ -23,36 +75,264 @@ impl Parser for SmithParser {
     }
 
     fn eg(&self) -> String {
-        format!("Examples for {}", self.name())
+        format!(
+            "Examples for {}: `e2e4`, `e1g1c`, `b4c3n`, `d7c6b`, `e5d6E`, `b7a8rQ`, etc.",
+            self.name()
+        )
     }
 
     /// Return a String representing the move from board Coordinates to this
     /// parser's notation.
-    fn generate_move_from_board_Coordinates(
+    fn generate_move_from_board_coordinates(
         &self,
-        engine: &Engine,
-        from_index: (Coord),
-        _to_index: (Coord),
+        game: &Game,
+        from_coord: Coord,
+        to_coord: Coord,
     ) -> ChuiResult<String> {
-        let board = &(engine.board.get_board());
-
-        let piece = match board[from_index.0][from_index.1] {
-            Some(piece) => piece,
-            None => {
-                return Err(ChuiError::InvalidMove(format!(
-                    "Invalid move. No piece at ({}, {})",
-                    from_index.0, from_index.1
-                )))
-            }
-        };
+        let the_move: ChessMove =
+            self.get_move_from_board_coordinates(game, from_coord, to_coord)?;
 
-        Ok(format!("Piece: {}", piece))
+        Ok(SmithParser::get_move_text(&the_move))
     }
 }
 
 impl SmithParser {
     /// Return a new dynamic parser that implements the `Parser` trait.
-    pub fn new() -> Box<dyn Parser + Send + Sync> {
-        Box::new(SmithParser {})
+    pub fn new(to_move: Color) -> Box<SmithParser> {
+        Box::new(SmithParser { to_move })
+    }
+
+    /// Return the move in Smith notation (e.g., `e2e4`, `b4c3n`, `e1g1c`, `e5d6E` or
+    /// `b7a8rQ`). The move must be validated for the board first (see
+    /// `ChessMove::validate_move_for_board()`). Returns an empty `String` if either square
+    /// is not set.
+    pub fn get_move_text(the_move: &ChessMove) -> String {
+        let (from_coord, to_coord) = match (the_move.from_coord, the_move.to_coord) {
+            (Some(from_coord), Some(to_coord)) => (from_coord, to_coord),
+            _ => return String::new(),
+        };
+        let mut move_text: String = format!("{}{}", from_coord, to_coord);
+
+        if let Some(to_piece) = the_move.to_piece {
+            if to_piece.get_coord() == to_coord {
+                move_text.push_str(&to_piece.repr().0.to_lowercase());
+            } else {
+                move_text.push('E');
+            }
+        }
+
+        match the_move.castling {
+            Some(Castling::King) => move_text.push('c'),
+            Some(Castling::Queen) => move_text.push('C'),
+            None => (),
+        }
+
+        if let Some(promotion) = the_move.promotion {
+            move_text.push_str(&promotion.repr().0.to_uppercase());
+        }
+
+        move_text
+    }
+
+    /// Parse a move in Smith notation.
+    ///
+    /// # Errors
+    ///
+    /// Errors when the move is not in Smith notation.
+    fn parse_smith(&self, move_string: String, to_move: Color) -> ChuiResult<SmithMove> {
+        let the_move: String = self.trim_and_check_whitespace(&move_string)?;
+        let invalid = |reason: &str| -> ChuiError {
+            ChuiError::InvalidInput(format!("{} is an invalid move: {}", the_move, reason))
+        };
+
+        let (from, to, mut rest) = match (the_move.get(..2), the_move.get(2..4), the_move.get(4..))
+        {
+            (Some(from), Some(to), Some(rest)) => (from, to, rest),
+            _ => return Err(invalid("the move must begin with two squares")),
+        };
+        let from_coord: Coord = Coord::try_from(from)?;
+        let to_coord: Coord = Coord::try_from(to)?;
+        from_coord.validate_possible_move(to_coord)?;
+
+        let mut smith = SmithMove {
+            chess_move: ChessMove {
+                to_move,
+                from_coord: Some(from_coord),
+                to_coord: Some(to_coord),
+                ..ChessMove::default()
+            },
+            captured: None,
+            en_passant: false,
+        };
+
+        // The captured piece.
+        if let Some(letter) = rest.strip_prefix(['p', 'n', 'b', 'r', 'q']) {
+            smith.captured = Piece::try_from(&rest[..1])
+                .ok()
+                .map(|piece| piece.get_kind());
+            smith.chess_move.set_move_type(MoveType::PieceCapture);
+            rest = letter;
+        }
+
+        // Castling or en passant.
+        if let Some(indicator) = rest.chars().next().filter(|c| "cCE".contains(*c)) {
+            match indicator {
+                'c' | 'C' => {
+                    smith.chess_move.castling = Some(if indicator == 'c' {
+                        Castling::King
+                    } else {
+                        Castling::Queen
+                    });
+                    smith.chess_move.set_move_type(MoveType::Castle);
+                }
+                _ => {
+                    smith.en_passant = true;
+                    smith.captured = Some(PieceKind::Pawn);
+                    smith.chess_move.set_move_type(MoveType::PawnCapture);
+                }
+            }
+            rest = &rest[1..];
+        }
+
+        // The promotion piece.
+        if let Some(letter) = rest.strip_prefix(['N', 'B', 'R', 'Q']) {
+            smith.chess_move.promotion = Some(Piece::try_from(&rest[..1])?);
+            rest = letter;
+        }
+
+        if !rest.is_empty() {
+            return Err(invalid(format!("`{}` was not expected", rest).as_str()));
+        }
+
+        if smith.chess_move.castling.is_some() && smith.captured.is_some() {
+            return Err(invalid("castling cannot capture"));
+        }
+
+        smith.chess_move.set_input_move(move_string);
+        smith.chess_move.is_parsed = true;
+
+        Ok(smith)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::coord::*;
+    use crate::testing::{new_game, play};
+
+    fn generate(game: &Game, from: (char, u8), to: (char, u8)) -> ChuiResult<String> {
+        SmithParser::new(game.to_move).generate_move_from_board_coordinates(
+            game,
+            Coord::try_from(from)?,
+            Coord::try_from(to)?,
+        )
+    }
+
+    #[test]
+    fn parse_moves() -> ChuiResult<()> {
+        let mut parser = SmithParser::new(Color::White);
+
+        let the_move = parser.parse_notation("e2e4".to_string(), Color::White)?;
+        assert_eq!(the_move.get_coordinate_text(), "e2e4");
+
+        let the_move = parser.parse_notation("b4c3n".to_string(), Color::White)?;
+        assert!(the_move.is_piece_capture());
+
+        assert!(parser
+            .parse_notation("e1g1c".to_string(), Color::White)?
+            .is_castling_king());
+        assert!(parser
+            .parse_notation("e8c8C".to_string(), Color::Black)?
+            .is_castling_queen());
+
+        let the_move = parser.parse_notation("b7a8rQ".to_string(), Color::White)?;
+        assert!(the_move.is_piece_capture());
+        assert_eq!(the_move.get_coordinate_text(), "b7a8q");
+
+        for input in [
+            "", "e2", "e2e", "e2e9", "e2e4x", "e2e4Qq", "e7e8K", "e1g1k", "e2e4cc", "e2e4Qn",
+            "e1g1nc", "E2E4", "e2e2",
+        ] {
+            assert!(
+                parser
+                    .parse_notation(input.to_string(), Color::White)
+                    .is_err(),
+                "{:?} should not parse",
+                input
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn play_moves() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Smith);
+        play(
+            &mut game,
+            &[
+                "e2e4", "e7e5", "g1f3", "b8c6", "f1b5", "a7a6", "b5c6n", "d7c6b", "e1g1c",
+            ],
+        )?;
+        assert_eq!(
+            game.get_san_move_list(),
+            vec!["e4", "e5", "Nf3", "Nc6", "Bb5", "a6", "Bxc6", "dxc6", "O-O"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn check_moves_against_board() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Smith);
+        play(&mut game, &["e2e4", "d7d5"])?;
+
+        // The captured piece must match the board.
+        for input in ["e4d5", "e4d5n", "e4d5E", "e4e5p"] {
+            assert!(
+                game.parse(input.to_string(), game.to_move).is_err(),
+                "{:?} should not parse",
+                input
+            );
+        }
+        play(&mut game, &["e4d5p", "e7e5"])?;
+
+        // En passant must be marked.
+        assert!(game.parse("d5e6".to_string(), game.to_move).is_err());
+        assert!(game.parse("d5e6p".to_string(), game.to_move).is_err());
+        play(&mut game, &["d5e6E"])?;
+        assert_eq!(
+            game.get_san_move_list(),
+            vec!["e4", "d5", "exd5", "e5", "dxe6"]
+        );
+
+        // Castling must be marked.
+        game.set_fen("r3k3/8/8/8/8/8/8/4K2R w Kq - 0 1")?;
+        assert!(game.parse("e1g1".to_string(), game.to_move).is_err());
+        assert!(game.parse("e1g1C".to_string(), game.to_move).is_err());
+        assert!(game
+            .parse("e1g1c".to_string(), game.to_move)?
+            .is_castling_king());
+        Ok(())
+    }
+
+    #[test]
+    fn generate_moves() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Smith);
+        assert_eq!(generate(&game, E2, E4)?, "e2e4");
+
+        play(&mut game, &["e2e4", "d7d5"])?;
+        assert_eq!(generate(&game, E4, D5)?, "e4d5p");
+
+        play(&mut game, &["e4e5", "f7f5"])?;
+        assert_eq!(generate(&game, E5, F6)?, "e5f6E");
+
+        game.set_fen("r3k3/1P6/8/8/8/8/8/4K2R w Kq - 0 1")?;
+        assert_eq!(generate(&game, E1, G1)?, "e1g1c");
+        assert_eq!(generate(&game, B7, A8)?, "b7a8rQ");
+        assert_eq!(generate(&game, B7, B8)?, "b7b8Q");
+
+        game.set_fen("r3k3/8/8/8/8/8/8/4K2R b Kq - 0 1")?;
+        assert_eq!(generate(&game, E8, C8)?, "e8c8C");
+        Ok(())
     }
 }
@@ -0,0 +1,253 @@
+//! UCI notation module.
+
+#![allow(clippy::new_ret_no_self)]
+
+use crate::prelude::*;
+
+/// A parser that will parse the long algebraic notation used by the Universal Chess
+/// Interface (UCI) to talk to chess engines.
+/// Example moves: `e2e4`, `e7e5`, `g1f3`, `e7e8q`, `e1g1`, etc.
+///
+/// A move is the "from" square and the "to" square in lower case, followed by the lower
+/// case letter of the promotion piece, if any. There are no piece letters, captures or
+/// checks. Castling is written as the King's move: to the square it lands on (e.g.,
+/// `e1g1`), or onto its castling Rook on a Chess960 board (e.g., `e1h1`).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UciParser {
+    /// The color to move.
+    pub to_move: Color,
+}
+
+impl Parser for UciParser {
+    /// Parse the chess move, return `Ok(ChessMove)` on success,
+    /// `ChuiError::InvalidMove(reason)` on failure.
+    fn parse_notation(&mut self, move_string: String, to_move: Color) -> ChuiResult<ChessMove> {
+        self.to_move = to_move;
+        let the_move: String = self.trim_and_check_whitespace(&move_string)?;
+        let invalid = |reason: &str| -> ChuiError {
+            ChuiError::InvalidInput(format!("{} is an invalid move: {}", the_move, reason))
+        };
+
+        if the_move == "0000" {
+            return Err(invalid("null moves are not supported"));
+        }
+
+        let (from, to, promotion) = match (the_move.get(..2), the_move.get(2..4), the_move.get(4..))
+        {
+            (Some(from), Some(to), Some(promotion)) => (from, to, promotion),
+            _ => return Err(invalid("the move must be two squares")),
+        };
+        let from_coord: Coord = Coord::try_from(from)?;
+        let to_coord: Coord = Coord::try_from(to)?;
+
+        let promotion: Option<Piece> = match promotion {
+            "" => None,
+            "q" | "r" | "b" | "n" => Some(Piece::try_from(promotion.to_uppercase().as_str())?),
+            _ => {
+                return Err(invalid(
+                    "the promotion piece must be one of `q`, `r`, `b` or `n`",
+                ))
+            }
+        };
+
+        from_coord.validate_possible_move(to_coord)?;
+
+        Ok(ChessMove {
+            to_move,
+            promotion,
+            from_coord: Some(from_coord),
+            to_coord: Some(to_coord),
+            input_move: move_string,
+            is_parsed: true,
+            ..ChessMove::default()
+        })
+    }
+
+    fn name(&self) -> String {
+        "UCI Parser".to_string()
+    }
+
+    fn eg(&self) -> String {
+        format!(
+            "Examples for {}: `e2e4`, `e7e5`, `g1f3`, `e7e8q`, `e1g1`, etc.",
+            self.name()
+        )
+    }
+
+    /// Return a String representing the move from board Coordinates to this
+    /// parser's notation.
+    fn generate_move_from_board_coordinates(
+        &self,
+        game: &Game,
+        from_coord: Coord,
+        to_coord: Coord,
+    ) -> ChuiResult<String> {
+        let the_move: ChessMove =
+            self.get_move_from_board_coordinates(game, from_coord, to_coord)?;
+
+        Ok(UciParser::get_move_text(&the_move))
+    }
+}
+
+impl UciParser {
+    /// Return a new dynamic parser that implements the `Parser` trait.
+    pub fn new(to_move: Color) -> Box<UciParser> {
+        Box::new(UciParser { to_move })
+    }
+
+    /// Return the move in UCI notation (e.g., `e2e4`, `e7e8q` or `e1g1`). The move must be
+    /// validated for the board first (see `ChessMove::validate_move_for_board()`), so that
+    /// a castling move is written as the King moving onto its Rook on a Chess960 board.
+    /// Returns an empty `String` if either square is not set.
+    pub fn get_move_text(the_move: &ChessMove) -> String {
+        match (the_move.from_coord, the_move.to_coord) {
+            (Some(from_coord), Some(to_coord)) => format!(
+                "{}{}{}",
+                from_coord,
+                to_coord,
+                the_move
+                    .promotion
+                    .map_or_else(String::new, |piece| piece.repr().0.to_lowercase())
+            ),
+            _ => String::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::coord::*;
+    use crate::testing::{new_game, play};
+
+    fn parse(input: &str, to_move: Color) -> ChuiResult<ChessMove> {
+        UciParser::new(to_move).parse_notation(input.to_string(), to_move)
+    }
+
+    fn reason(input: &str) -> String {
+        match parse(input, Color::White) {
+            Err(ChuiError::InvalidInput(reason)) => reason,
+            result => panic!("{:?} should be invalid input: {:?}", input, result),
+        }
+    }
+
+    fn generate(game: &Game, from: (char, u8), to: (char, u8)) -> ChuiResult<String> {
+        UciParser::new(game.to_move).generate_move_from_board_coordinates(
+            game,
+            Coord::try_from(from)?,
+            Coord::try_from(to)?,
+        )
+    }
+
+    #[test]
+    fn a_move_is_two_lower_case_squares() -> ChuiResult<()> {
+        let the_move = parse("e2e4", Color::White)?;
+        assert_eq!(the_move.from_coord, Coord::try_from(E2).ok());
+        assert_eq!(the_move.to_coord, Coord::try_from(E4).ok());
+        assert_eq!(the_move.promotion, None);
+
+        assert_eq!(
+            reason("e2"),
+            "e2 is an invalid move: the move must be two squares"
+        );
+        assert!(parse("E2E4", Color::White).is_err());
+        assert!(parse("e2-e4", Color::White).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn there_are_no_piece_letters_or_checks() {
+        assert!(parse("Ng1f3", Color::White).is_err());
+        assert!(parse("e2e4+", Color::White).is_err());
+        assert!(parse("O-O", Color::White).is_err());
+    }
+
+    #[test]
+    fn the_promotion_letter_is_lower_case() -> ChuiResult<()> {
+        let the_move = parse("e7e8q", Color::White)?;
+        assert_eq!(
+            the_move.promotion.map(|piece| piece.get_kind()),
+            Some(PieceKind::Queen)
+        );
+
+        let the_move = parse("b2b1n", Color::Black)?;
+        assert_eq!(
+            the_move.promotion.map(|piece| piece.get_kind()),
+            Some(PieceKind::Knight)
+        );
+
+        for input in ["e7e8Q", "e7e8=q", "e7e8k"] {
+            assert_eq!(
+                reason(input),
+                format!(
+                    "{} is an invalid move: the promotion piece must be one of `q`, `r`, `b` \
+                    or `n`",
+                    input
+                )
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn null_moves_are_not_supported() {
+        assert_eq!(
+            reason("0000"),
+            "0000 is an invalid move: null moves are not supported"
+        );
+    }
+
+    #[test]
+    fn castling_is_the_kings_move() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Uci);
+        play(
+            &mut game,
+            &[
+                "e2e4", "e7e5", "g1f3", "b8c6", "f1b5", "a7a6", "b5c6", "d7c6", "e1g1",
+            ],
+        )?;
+        assert_eq!(
+            game.get_san_move_list(),
+            vec!["e4", "e5", "Nf3", "Nc6", "Bb5", "a6", "Bxc6", "dxc6", "O-O"]
+        );
+
+        game.set_fen("4k3/1P6/8/8/8/8/8/4K3 w - - 0 1")?;
+        play(&mut game, &["b7b8r"])?;
+        assert_eq!(game.get_san_move_list(), vec!["b8=R+"]);
+        Ok(())
+    }
+
+    #[test]
+    fn chess960_castling_is_the_king_onto_its_rook() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Uci);
+        game.set_variant(Variant::Chess960(518));
+        game.set_fen("rk5r/8/8/8/8/8/8/RK5R w HAha - 0 1")?;
+
+        play(&mut game, &["b1h1", "b8a8"])?;
+        assert_eq!(game.get_san_move_list(), vec!["O-O", "O-O-O"]);
+        assert!(game
+            .get_fen()
+            .starts_with("FEN: 2kr3r/8/8/8/8/8/8/R4RK1 w - - 2 2"));
+        Ok(())
+    }
+
+    #[test]
+    fn castling_is_written_for_the_variant() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Uci);
+        assert_eq!(generate(&game, G1, F3)?, "g1f3");
+        assert!(generate(&game, G1, G3).is_err());
+
+        game.set_fen("r3k2r/1P6/8/8/8/8/8/R3K2R w KQkq - 0 1")?;
+        assert_eq!(generate(&game, B7, A8)?, "b7a8q");
+        assert_eq!(generate(&game, E1, G1)?, "e1g1");
+        assert_eq!(generate(&game, E1, C1)?, "e1c1");
+
+        // On a Chess960 board the King castles onto its Rook, whichever square is picked.
+        game.set_variant(Variant::Chess960(518));
+        game.set_fen("r3k2r/8/8/8/8/8/8/R3K2R w HAha - 0 1")?;
+        assert_eq!(generate(&game, E1, G1)?, "e1h1");
+        assert_eq!(generate(&game, E1, H1)?, "e1h1");
+        assert_eq!(generate(&game, E1, A1)?, "e1a1");
+        Ok(())
+    }
+}
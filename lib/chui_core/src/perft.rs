@@ -0,0 +1,312 @@
+//! Perft module.
+//!
+//! Perft (performance test) walks the tree of legal moves from a position to a given depth and
+//! counts the leaf nodes. The counts are compared against published results to verify the move
+//! generator.
+
+use std::time::Instant;
+
+use crate::prelude::*;
+
+/// Counts the leaf nodes of the legal move tree of a position.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Perft;
+
+impl Perft {
+    /// Count the leaf nodes of the legal move tree of `board`, with `to_move` to move, to
+    /// `depth` plies. Each move is played on a copy of the board rather than with
+    /// `Game::apply_move()` and `Game::undo()`, which also keep the move list, the move tree
+    /// and the position record of the game, none of which perft needs. The tests count the
+    /// nodes through `Game` as well, so both paths are checked against the same results.
+    ///
+    /// # Errors
+    ///
+    /// * Errors if a legal move cannot be played on the board, which would be a bug in the
+    ///   move generator.
+    pub fn perft(board: &Board, to_move: Color, depth: usize) -> ChuiResult<u64> {
+        if depth == 0 {
+            return Ok(1);
+        }
+
+        let moves: Vec<ChessMove> = board.legal_moves(to_move);
+
+        // No need to play the moves at the last ply.
+        if depth == 1 {
+            return Ok(moves.len() as u64);
+        }
+
+        moves
+            .iter()
+            .map(|chess_move| Perft::perft_move(board, chess_move, depth))
+            .sum()
+    }
+
+    /// Count the leaf nodes of the legal move tree of `board`, with `to_move` to move, to
+    /// `depth` plies for each legal move. The counts add up to `perft()`. Useful to find
+    /// which move a wrong count comes from.
+    ///
+    /// # Errors
+    ///
+    /// * Errors if a legal move cannot be played on the board (see `perft()`).
+    pub fn divide(
+        board: &Board,
+        to_move: Color,
+        depth: usize,
+    ) -> ChuiResult<Vec<(ChessMove, u64)>> {
+        if depth == 0 {
+            return Ok(Vec::<(ChessMove, u64)>::new());
+        }
+
+        board
+            .legal_moves(to_move)
+            .into_iter()
+            .map(|chess_move| {
+                let nodes = Perft::perft_move(board, &chess_move, depth)?;
+                Ok((chess_move, nodes))
+            })
+            .collect()
+    }
+
+    /// Get the report of `divide()`: the leaf node count below each legal move (in
+    /// coordinate notation), followed by the number of moves, the total node count and the
+    /// time taken.
+    ///
+    /// # Errors
+    ///
+    /// * Errors if a legal move cannot be played on the board (see `perft()`).
+    pub fn divide_report(board: &Board, to_move: Color, depth: usize) -> ChuiResult<String> {
+        let start = Instant::now();
+        let divide = Perft::divide(board, to_move, depth)?;
+        let elapsed = start.elapsed();
+
+        let nodes: u64 = if depth == 0 {
+            1
+        } else {
+            divide.iter().map(|(_, nodes)| nodes).sum()
+        };
+
+        let mut report = String::new();
+
+        for (chess_move, nodes) in divide.iter() {
+            report.push_str(&format!(
+                "{}: {}\n",
+                chess_move.get_coordinate_text(),
+                nodes
+            ));
+        }
+
+        report.push_str(&format!(
+            "\nMoves: {}\nNodes: {}\nTime: {:.3}s",
+            divide.len(),
+            nodes,
+            elapsed.as_secs_f64()
+        ));
+
+        Ok(report)
+    }
+
+    /// Play `chess_move` on a copy of `board` and count the leaf nodes below it.
+    fn perft_move(board: &Board, chess_move: &ChessMove, depth: usize) -> ChuiResult<u64> {
+        let mut board: Board = *board;
+        board.apply_move(chess_move)?;
+
+        Perft::perft(&board, chess_move.to_move.opposite(), depth - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const START_FEN: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+    const KIWIPETE_FEN: &str =
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+    const POSITION_3_FEN: &str = "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1";
+    const POSITION_4_FEN: &str = "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1";
+    const POSITION_4_MIRRORED_FEN: &str =
+        "r2q1rk1/pP1p2pp/Q4n2/bbp1p3/Np6/1B3NBn/pPPP1PPP/R3K2R b KQ - 0 1";
+    const POSITION_5_FEN: &str = "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8";
+    const POSITION_6_FEN: &str =
+        "r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 10";
+
+    // Chess960 positions, given as Shredder-FEN.
+    const KIWIPETE_960_FEN: &str =
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w HAha - 0 1";
+    const CHESS960_1_FEN: &str =
+        "bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w HFhf - 2 9";
+    const CHESS960_2_FEN: &str = "2nnrbkr/p1qppppp/8/1ppb4/6PP/3PP3/PPP2P2/BQNNRBKR w HEhe - 1 9";
+    const CHESS960_3_FEN: &str = "b1q1rrkb/pppppppp/3nn3/8/P7/1PPP4/4PPPP/BQNNRKRB w GE - 1 9";
+    const CHESS960_4_FEN: &str = "qbbnnrkr/2pp2pp/p7/1p2pp2/8/P3PP2/1PPP1KPP/QBBNNR1R w hf - 0 9";
+
+    fn assert_perft(fen: &str, expected_nodes: &[u64]) -> ChuiResult<()> {
+        let board = Board::from_fen(fen)?;
+        let to_move = if fen.split(' ').nth(1) == Some("w") {
+            Color::White
+        } else {
+            Color::Black
+        };
+
+        for (depth, expected) in expected_nodes.iter().enumerate() {
+            assert_eq!(
+                *expected,
+                Perft::perft(&board, to_move, depth + 1)?,
+                "{} at depth {}",
+                fen,
+                depth + 1
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Count the leaf nodes by playing each move with `Game::apply_move()` and taking it back
+    /// with `Game::undo()`.
+    fn game_perft(game: &mut Game, depth: usize) -> ChuiResult<u64> {
+        if depth == 0 {
+            return Ok(1);
+        }
+
+        let mut nodes: u64 = 0;
+
+        for chess_move in game.legal_moves() {
+            game.set_current_move(Some(chess_move));
+            game.apply_move()?;
+            nodes += game_perft(game, depth - 1)?;
+            game.undo()?;
+        }
+
+        Ok(nodes)
+    }
+
+    #[test]
+    fn perft_start_position() -> ChuiResult<()> {
+        assert_perft(START_FEN, &[20, 400, 8_902])
+    }
+
+    #[test]
+    fn perft_game_apply_and_undo() -> ChuiResult<()> {
+        let mut game = Game::default();
+        assert_eq!(8_902, game_perft(&mut game, 3)?);
+        assert_eq!(Fen::get_fen(&game), START_FEN);
+
+        game.set_fen(KIWIPETE_FEN)?;
+        assert_eq!(2_039, game_perft(&mut game, 2)?);
+        assert_eq!(Fen::get_fen(&game), KIWIPETE_FEN);
+
+        game.set_fen(POSITION_3_FEN)?;
+        assert_eq!(2_812, game_perft(&mut game, 3)?);
+        Ok(())
+    }
+
+    #[test]
+    fn divide_report() -> ChuiResult<()> {
+        let board = Board::from_fen(START_FEN)?;
+        let report = Perft::divide_report(&board, Color::White, 2)?;
+
+        assert!(report.contains("a2a3: 20\n"), "{}", report);
+        assert!(
+            report.contains("\n\nMoves: 20\nNodes: 400\nTime: "),
+            "{}",
+            report
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[ignore = "slow"]
+    fn perft_start_position_deep() -> ChuiResult<()> {
+        assert_perft(START_FEN, &[20, 400, 8_902, 197_281, 4_865_609])
+    }
+
+    #[test]
+    fn perft_kiwipete() -> ChuiResult<()> {
+        assert_perft(KIWIPETE_FEN, &[48, 2_039])
+    }
+
+    #[test]
+    #[ignore = "slow"]
+    fn perft_kiwipete_deep() -> ChuiResult<()> {
+        assert_perft(KIWIPETE_FEN, &[48, 2_039, 97_862, 4_085_603])
+    }
+
+    #[test]
+    fn perft_position_3() -> ChuiResult<()> {
+        assert_perft(POSITION_3_FEN, &[14, 191, 2_812, 43_238])
+    }
+
+    #[test]
+    #[ignore = "slow"]
+    fn perft_position_3_deep() -> ChuiResult<()> {
+        assert_perft(
+            POSITION_3_FEN,
+            &[14, 191, 2_812, 43_238, 674_624, 11_030_083],
+        )
+    }
+
+    #[test]
+    fn perft_position_4() -> ChuiResult<()> {
+        assert_perft(POSITION_4_FEN, &[6, 264, 9_467])?;
+        assert_perft(POSITION_4_MIRRORED_FEN, &[6, 264, 9_467])
+    }
+
+    #[test]
+    #[ignore = "slow"]
+    fn perft_position_4_deep() -> ChuiResult<()> {
+        assert_perft(POSITION_4_FEN, &[6, 264, 9_467, 422_333])?;
+        assert_perft(POSITION_4_MIRRORED_FEN, &[6, 264, 9_467, 422_333])
+    }
+
+    #[test]
+    fn perft_position_5() -> ChuiResult<()> {
+        assert_perft(POSITION_5_FEN, &[44, 1_486])
+    }
+
+    #[test]
+    #[ignore = "slow"]
+    fn perft_position_5_deep() -> ChuiResult<()> {
+        assert_perft(POSITION_5_FEN, &[44, 1_486, 62_379, 2_103_487])
+    }
+
+    #[test]
+    fn perft_position_6() -> ChuiResult<()> {
+        assert_perft(POSITION_6_FEN, &[46, 2_079])
+    }
+
+    #[test]
+    #[ignore = "slow"]
+    fn perft_position_6_deep() -> ChuiResult<()> {
+        assert_perft(POSITION_6_FEN, &[46, 2_079, 89_890, 3_894_594])
+    }
+
+    #[test]
+    fn perft_chess960() -> ChuiResult<()> {
+        assert_perft(KIWIPETE_960_FEN, &[48, 2_039])?;
+        assert_perft(CHESS960_1_FEN, &[21, 528, 12_189])?;
+        assert_perft(CHESS960_2_FEN, &[21, 807, 18_002])?;
+        assert_perft(CHESS960_3_FEN, &[20, 479, 10_471])?;
+        assert_perft(CHESS960_4_FEN, &[22, 593, 13_440])
+    }
+
+    #[test]
+    #[ignore = "slow"]
+    fn perft_chess960_deep() -> ChuiResult<()> {
+        assert_perft(KIWIPETE_960_FEN, &[48, 2_039, 97_862, 4_085_603])?;
+        assert_perft(CHESS960_1_FEN, &[21, 528, 12_189, 326_672])?;
+        assert_perft(CHESS960_2_FEN, &[21, 807, 18_002, 667_366])?;
+        assert_perft(CHESS960_3_FEN, &[20, 479, 10_471, 273_318])?;
+        assert_perft(CHESS960_4_FEN, &[22, 593, 13_440, 382_958])
+    }
+
+    #[test]
+    fn divide_adds_up_to_perft() -> ChuiResult<()> {
+        let board = Board::from_fen(KIWIPETE_FEN)?;
+        let divide = Perft::divide(&board, Color::White, 2)?;
+
+        assert_eq!(48, divide.len());
+        assert_eq!(2_039, divide.iter().map(|(_, nodes)| nodes).sum::<u64>());
+        assert!(divide
+            .iter()
+            .any(|(chess_move, nodes)| chess_move.get_coordinate_text() == "e1g1" && *nodes == 43));
+        Ok(())
+    }
+}
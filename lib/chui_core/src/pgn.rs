@@ -0,0 +1,1353 @@
+//! PGN notation module.
+//!
+//! PGN stands for Portable Game Notation.
+
+use std::fmt;
+use std::io::BufRead;
+use std::mem;
+
+use crate::prelude::*;
+
+/// A move in the move text of a PGN game, with its annotations.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PgnMove {
+    /// The move as written in the move text (e.g., `Nf3` or `exd8=Q+`).
+    pub san: String,
+
+    /// The Numeric Annotation Glyphs of the move (e.g., `1` for `$1` or `!`).
+    pub nags: Vec<u8>,
+
+    /// The comments before the move, when it is the first move of the game or of a
+    /// variation.
+    pub pre_comments: Vec<String>,
+
+    /// The comments after the move.
+    pub comments: Vec<String>,
+
+    /// The variations of the move: the lines played instead of this move.
+    pub variations: Vec<Vec<PgnMove>>,
+
+    /// The line of the move in the PGN text, starting at 1.
+    pub line: usize,
+
+    /// The column of the move in the PGN text, starting at 1.
+    pub column: usize,
+}
+
+/// A game read from PGN text.
+#[derive(Debug)]
+pub struct PgnGame {
+    /// The tags of the game (e.g., `("White", "Camina Drummer")`), in the order read.
+    pub tags: Vec<(String, String)>,
+
+    /// The comments of a game without moves.
+    pub comments: Vec<String>,
+
+    /// The moves of the main line, with their annotations and variations.
+    pub moves: Vec<PgnMove>,
+
+    /// The game termination marker: `1-0`, `0-1`, `1/2-1/2` or `*`. Falls back to the
+    /// `Result` tag when the move text has no marker.
+    pub result: Option<String>,
+
+    /// The game, with the moves of the main line played.
+    pub game: Game,
+}
+
+impl PgnGame {
+    /// The tags that every PGN game has, in the order they are exported.
+    pub const SEVEN_TAG_ROSTER: [&'static str; 7] =
+        ["Event", "Site", "Date", "Round", "White", "Black", "Result"];
+
+    /// Get the value of the tag with the given name.
+    pub fn get_tag(&self, name: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|(tag, _)| tag == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Write the game as PGN text in export format, with its tags, comments, variations
+    /// and result (see `PgnWriter`).
+    pub fn to_pgn(&self) -> String {
+        let (move_number, to_move) = PgnWriter::get_first_move(&self.game);
+
+        PgnWriter::write(
+            &self.tags,
+            &self.comments,
+            &self.moves,
+            move_number,
+            to_move,
+            self.result.as_deref().unwrap_or("*"),
+        )
+    }
+}
+
+/// Writes games as PGN text in export format: the Seven Tag Roster first, in order, then
+/// the other tags, then a blank line and the move text, with move numbers, comments,
+/// Numeric Annotation Glyphs and variations, wrapped at 80 columns.
+///
+/// Example:
+///
+/// ```
+/// use chui_core::prelude::*;
+///
+/// let moves: Vec<PgnMove> = ["e4", "e5"]
+///     .into_iter()
+///     .map(|san| PgnMove {
+///         san: san.to_string(),
+///         ..PgnMove::default()
+///     })
+///     .collect();
+/// let pgn = PgnWriter::write(&[], &[], &moves, 1, Color::White, "*");
+///
+/// assert!(pgn.starts_with("[Event \"?\"]\n[Site \"?\"]\n[Date \"????.??.??\"]\n"));
+/// assert!(pgn.ends_with("[Result \"*\"]\n\n1. e4 e5 *\n"));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PgnWriter;
+
+impl PgnWriter {
+    /// The longest line of move text.
+    pub const MAX_LINE_LENGTH: usize = 80;
+
+    /// Write a game as PGN text. The first move is numbered `move_number`, and is played by
+    /// `to_move`. The tags of the Seven Tag Roster that are missing are written as unknown
+    /// (e.g., `[Site "?"]`), but for the `Result` tag, which is written as `result`.
+    pub fn write(
+        tags: &[(String, String)],
+        comments: &[String],
+        moves: &[PgnMove],
+        move_number: usize,
+        to_move: Color,
+        result: &str,
+    ) -> String {
+        let mut pgn: String = String::new();
+
+        for name in PgnGame::SEVEN_TAG_ROSTER {
+            let value: &str = tags.iter().find(|(tag, _)| tag == name).map_or(
+                match name {
+                    "Date" => "????.??.??",
+                    "Result" => result,
+                    _ => "?",
+                },
+                |(_, value)| value.as_str(),
+            );
+            pgn.push_str(&PgnWriter::write_tag(name, value));
+        }
+
+        for (name, value) in tags
+            .iter()
+            .filter(|(tag, _)| !PgnGame::SEVEN_TAG_ROSTER.contains(&tag.as_str()))
+        {
+            pgn.push_str(&PgnWriter::write_tag(name, value));
+        }
+
+        let mut tokens: Vec<String> = Vec::new();
+        for comment in comments.iter() {
+            PgnWriter::push_comment(&mut tokens, comment);
+        }
+        PgnWriter::push_line(&mut tokens, moves, move_number, to_move);
+        tokens.push(result.to_string());
+
+        pgn.push('\n');
+        pgn.push_str(&PgnWriter::wrap(&tokens));
+
+        pgn
+    }
+
+    /// Get the moves of the main line of a move tree, with their annotations and
+    /// variations.
+    pub fn get_moves(tree: &MoveTree) -> Vec<PgnMove> {
+        tree.get_node(MoveTree::ROOT)
+            .and_then(|root| root.get_children().first())
+            .map_or_else(Vec::new, |first| PgnWriter::get_line(tree, *first, true))
+    }
+
+    /// Get the moves of the line starting at `first` and following the main line after
+    /// it. The variations of `first` are only included when `with_variations` is set, as
+    /// they are not part of the line of a variation.
+    fn get_line(tree: &MoveTree, first: NodeId, with_variations: bool) -> Vec<PgnMove> {
+        let mut moves: Vec<PgnMove> = Vec::new();
+        let mut next: Option<NodeId> = Some(first);
+
+        while let Some(node_id) = next {
+            let node: &MoveNode = match tree.get_node(node_id) {
+                Some(node) => node,
+                None => break,
+            };
+            let variations: Vec<Vec<PgnMove>> = match node.get_parent() {
+                Some(parent) if with_variations || node_id != first => tree
+                    .get_node(parent)
+                    .map_or(&[][..], MoveNode::get_children)
+                    .iter()
+                    .skip(1)
+                    .map(|variation| PgnWriter::get_line(tree, *variation, false))
+                    .collect(),
+                _ => Vec::new(),
+            };
+
+            moves.push(PgnMove {
+                san: node.get_san().unwrap_or_default().to_string(),
+                nags: node.nags.clone(),
+                pre_comments: node.pre_comments.clone(),
+                comments: node.comments.clone(),
+                variations,
+                ..PgnMove::default()
+            });
+            next = node.get_children().first().copied();
+        }
+
+        moves
+    }
+
+    /// Get the number of the first move of the game, and the color that played it.
+    pub fn get_first_move(game: &Game) -> (usize, Color) {
+        let first_ply: usize = game
+            .half_move_counter
+            .saturating_sub(game.get_move_history().len());
+        let to_move: Color = if first_ply % 2 == 0 {
+            Color::White
+        } else {
+            Color::Black
+        };
+
+        (first_ply / 2 + 1, to_move)
+    }
+
+    /// Write a tag pair (e.g., `[White "Camina Drummer"]`), escaping the value.
+    fn write_tag(name: &str, value: &str) -> String {
+        format!(
+            "[{} \"{}\"]\n",
+            name,
+            value.replace('\\', "\\\\").replace('"', "\\\"")
+        )
+    }
+
+    /// Push the tokens of a line of moves: the move numbers, moves, annotations and
+    /// variations. A move of Black is numbered (e.g., `3...`) when it begins the line or
+    /// follows a comment or variation.
+    fn push_line(
+        tokens: &mut Vec<String>,
+        moves: &[PgnMove],
+        mut move_number: usize,
+        mut to_move: Color,
+    ) {
+        let mut is_numbered: bool = true;
+
+        for pgn_move in moves.iter() {
+            for comment in pgn_move.pre_comments.iter() {
+                PgnWriter::push_comment(tokens, comment);
+            }
+
+            // A move number is kept on the line of its move.
+            tokens.push(match to_move {
+                Color::White => format!("{}. {}", move_number, pgn_move.san),
+                Color::Black if is_numbered => format!("{}... {}", move_number, pgn_move.san),
+                Color::Black => pgn_move.san.clone(),
+            });
+            tokens.extend(pgn_move.nags.iter().map(|nag| format!("${}", nag)));
+
+            for comment in pgn_move.comments.iter() {
+                PgnWriter::push_comment(tokens, comment);
+            }
+
+            for variation in pgn_move.variations.iter() {
+                let mut line: Vec<String> = Vec::new();
+                PgnWriter::push_line(&mut line, variation, move_number, to_move);
+
+                if let Some(first) = line.first_mut() {
+                    first.insert(0, '(');
+                }
+                if let Some(last) = line.last_mut() {
+                    last.push(')');
+                }
+                tokens.append(&mut line);
+            }
+
+            is_numbered = !pgn_move.comments.is_empty() || !pgn_move.variations.is_empty();
+            if to_move == Color::Black {
+                move_number += 1;
+            }
+            to_move = to_move.opposite();
+        }
+    }
+
+    /// Push a comment as one token per word, so that long comments are wrapped too. A
+    /// comment cannot hold a `}`, which would end it early, so any are left out.
+    fn push_comment(tokens: &mut Vec<String>, comment: &str) {
+        let comment: String = format!(
+            "{{{}}}",
+            comment
+                .replace('}', "")
+                .split_whitespace()
+                .collect::<Vec<&str>>()
+                .join(" ")
+        );
+
+        tokens.extend(comment.split(' ').map(str::to_string));
+    }
+
+    /// Join the tokens with spaces into lines of at most `MAX_LINE_LENGTH` characters. A
+    /// token longer than a line is given a line of its own.
+    fn wrap(tokens: &[String]) -> String {
+        let mut text: String = String::new();
+        let mut line_length: usize = 0;
+
+        for token in tokens.iter() {
+            let length: usize = token.chars().count();
+
+            if line_length > 0 && line_length + 1 + length > PgnWriter::MAX_LINE_LENGTH {
+                text.push('\n');
+                line_length = 0;
+            } else if line_length > 0 {
+                text.push(' ');
+                line_length += 1;
+            }
+
+            text.push_str(token);
+            line_length += length;
+        }
+        text.push('\n');
+
+        text
+    }
+}
+
+/// A token of PGN text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    /// `[`
+    TagOpen,
+
+    /// `]`
+    TagClose,
+
+    /// A quoted string, unescaped.
+    String(String),
+
+    /// A move, a tag name or a move number.
+    Symbol(String),
+
+    /// `.`
+    Period,
+
+    /// A `{}` or `;` comment.
+    Comment(String),
+
+    /// `(`
+    VariationOpen,
+
+    /// `)`
+    VariationClose,
+
+    /// A Numeric Annotation Glyph, given as `$1` or as a suffix like `!?`.
+    Nag(u8),
+
+    /// A game termination marker.
+    Result(String),
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Token::TagOpen => write!(f, "`[`"),
+            Token::TagClose => write!(f, "`]`"),
+            Token::String(string) => write!(f, "the string \"{}\"", string),
+            Token::Symbol(symbol) => write!(f, "`{}`", symbol),
+            Token::Period => write!(f, "`.`"),
+            Token::Comment(_) => write!(f, "a comment"),
+            Token::VariationOpen => write!(f, "`(`"),
+            Token::VariationClose => write!(f, "`)`"),
+            Token::Nag(nag) => write!(f, "`${}`", nag),
+            Token::Result(marker) => write!(f, "`{}`", marker),
+        }
+    }
+}
+
+/// Return the reason of an error, without its kind and final period (e.g., "No White
+/// Knight can move to `f6`").
+fn reason(error: &ChuiError) -> String {
+    let message: String = error.to_string();
+
+    message
+        .split_once("): ")
+        .map_or(message.as_str(), |(_, reason)| reason)
+        .trim_end_matches('.')
+        .to_string()
+}
+
+/// Reads the games of PGN text one at a time, from any `BufRead` (e.g., a `File` in a
+/// `BufReader`, or `&[u8]`), so files with any number of games can be read.
+///
+/// Each game is replayed through the Algebraic Parser. A game that cannot be read errors
+/// with `ChuiError::InvalidPgn`, giving the line and column of the problem, and the reader
+/// moves on to the next game.
+///
+/// Example:
+///
+/// ```
+/// use chui_core::prelude::*;
+///
+/// let pgn = "[White \"Camina Drummer\"]\n[Black \"Klaes Ashford\"]\n\n1. e4 e5 2. Nf3 1-0\n";
+///
+/// for pgn_game in PgnReader::new(pgn.as_bytes()) {
+///     let pgn_game = pgn_game.unwrap();
+///     assert_eq!(pgn_game.get_tag("White"), Some("Camina Drummer"));
+///     assert_eq!(pgn_game.game.get_san_move_list(), vec!["e4", "e5", "Nf3"]);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct PgnReader<R: BufRead> {
+    /// The PGN text.
+    reader: R,
+
+    /// The current line.
+    line: Vec<char>,
+
+    /// The number of the current line, starting at 1.
+    line_number: usize,
+
+    /// The index of the next character of the current line.
+    column: usize,
+
+    /// Has the end of the text been reached?
+    eof: bool,
+
+    /// A token that was read, but not used yet, with its line and column.
+    peeked: Option<(Token, usize, usize)>,
+}
+
+impl<R: BufRead> Iterator for PgnReader<R> {
+    type Item = ChuiResult<PgnGame>;
+
+    fn next(&mut self) -> Option<ChuiResult<PgnGame>> {
+        match self.peek_token() {
+            Ok(None) => return None,
+            Ok(Some(_)) => (),
+            Err(error) => {
+                self.skip_game(false);
+                return Some(Err(error));
+            }
+        }
+
+        let mut in_move_text: bool = false;
+        let result: ChuiResult<PgnGame> = self.read_game(&mut in_move_text);
+
+        if result.is_err() {
+            self.skip_game(in_move_text);
+        }
+
+        Some(result)
+    }
+}
+
+impl<R: BufRead> PgnReader<R> {
+    /// Return a new reader of the PGN text.
+    pub fn new(reader: R) -> PgnReader<R> {
+        PgnReader {
+            reader,
+            line: Vec::new(),
+            line_number: 0,
+            column: 0,
+            eof: false,
+            peeked: None,
+        }
+    }
+
+    /// Read the next game. `in_move_text` is set once the tags have been read.
+    ///
+    /// # Errors
+    ///
+    /// Errors when the game is malformed or a move cannot be played.
+    fn read_game(&mut self, in_move_text: &mut bool) -> ChuiResult<PgnGame> {
+        let (line, column) = match self.peek_token()? {
+            Some((_, line, column)) => (line, column),
+            None => (self.line_number, self.column + 1),
+        };
+        let tags: Vec<(String, String)> = self.read_tags()?;
+        *in_move_text = true;
+
+        let mut game: Game = PgnReader::<R>::new_game(&tags).map_err(|error| {
+            PgnReader::<R>::error_at(
+                line,
+                column,
+                format!("the game cannot be set up ({})", reason(&error)),
+            )
+        })?;
+        let mut result: Option<String> = None;
+        let (moves, comments) = self.read_line(&mut game, 0, &mut result)?;
+
+        let result: Option<String> = result.or_else(|| {
+            tags.iter()
+                .find(|(tag, _)| tag == "Result")
+                .map(|(_, value)| value.clone())
+        });
+
+        Ok(PgnGame {
+            tags,
+            comments,
+            moves,
+            result,
+            game,
+        })
+    }
+
+    /// Read the tag pairs of a game (e.g., `[White "Camina Drummer"]`).
+    ///
+    /// # Errors
+    ///
+    /// Errors when a tag pair is malformed.
+    fn read_tags(&mut self) -> ChuiResult<Vec<(String, String)>> {
+        let mut tags: Vec<(String, String)> = Vec::new();
+
+        while let Some((Token::TagOpen, _, _)) = self.peek_token()? {
+            self.peeked = None;
+
+            let name: String = match self.next_token()? {
+                Some((Token::Symbol(name), _, _)) => name,
+                other => return Err(self.unexpected(other, "a tag name")),
+            };
+            let value: String = match self.next_token()? {
+                Some((Token::String(value), _, _)) => value,
+                other => return Err(self.unexpected(other, "a quoted tag value")),
+            };
+
+            match self.next_token()? {
+                Some((Token::TagClose, _, _)) => (),
+                other => return Err(self.unexpected(other, "`]`")),
+            }
+
+            tags.push((name, value));
+        }
+
+        Ok(tags)
+    }
+
+    /// Read a line of moves, playing them on `game`: the main line when `depth` is `0`,
+    /// or else a variation of the last move played, which ends at `)`. The moves and their
+    /// annotations are added to the move tree of the game. Returns the moves, and the
+    /// comments of a main line without moves. The game termination marker is set in
+    /// `result`.
+    ///
+    /// # Errors
+    ///
+    /// Errors when the move text is malformed or a move cannot be played.
+    fn read_line(
+        &mut self,
+        game: &mut Game,
+        depth: usize,
+        result: &mut Option<String>,
+    ) -> ChuiResult<(Vec<PgnMove>, Vec<String>)> {
+        let mut moves: Vec<PgnMove> = Vec::new();
+        let mut comments: Vec<String> = Vec::new();
+        let (start_line, start_column) = (self.line_number, self.column);
+        let mut variation_of: Option<NodeId> = if depth > 0 {
+            Some(game.get_move_tree().get_cursor())
+        } else {
+            None
+        };
+
+        loop {
+            let (token, line, column) = match self.peek_token()? {
+                Some(peeked) => peeked,
+                None if depth == 0 => break,
+                None => {
+                    return Err(PgnReader::<R>::error_at(
+                        start_line,
+                        start_column,
+                        "the variation is not closed with `)`",
+                    ))
+                }
+            };
+
+            // A new game starts without a termination marker for this one.
+            if token == Token::TagOpen && depth == 0 {
+                break;
+            }
+
+            self.peeked = None;
+
+            match token {
+                Token::Symbol(number) if number.chars().all(|c| c.is_ascii_digit()) => (),
+                Token::Period => (),
+                Token::Symbol(san) => {
+                    match variation_of.take() {
+                        Some(node_id) => game.add_variation(node_id, &san).map(|_| ()),
+                        None => PgnReader::<R>::play(game, &san),
+                    }
+                    .map_err(|error| {
+                        PgnReader::<R>::error_at(
+                            line,
+                            column,
+                            format!("`{}` cannot be played ({})", san, reason(&error)),
+                        )
+                    })?;
+                    PgnReader::<R>::get_node(game).pre_comments = comments.clone();
+                    moves.push(PgnMove {
+                        san,
+                        pre_comments: mem::take(&mut comments),
+                        line,
+                        column,
+                        ..PgnMove::default()
+                    });
+                }
+                Token::Comment(comment) => match moves.last_mut() {
+                    Some(last) => {
+                        PgnReader::<R>::get_node(game)
+                            .comments
+                            .push(comment.clone());
+                        last.comments.push(comment);
+                    }
+                    None => comments.push(comment),
+                },
+                Token::Nag(nag) => match moves.last_mut() {
+                    Some(last) => {
+                        PgnReader::<R>::get_node(game).nags.push(nag);
+                        last.nags.push(nag);
+                    }
+                    None => {
+                        return Err(PgnReader::<R>::error_at(
+                            line,
+                            column,
+                            "an annotation glyph must follow a move",
+                        ))
+                    }
+                },
+                Token::VariationOpen => {
+                    if moves.is_empty() {
+                        return Err(PgnReader::<R>::error_at(
+                            line,
+                            column,
+                            "a variation must follow the move it replaces",
+                        ));
+                    }
+
+                    let variation: Vec<PgnMove> = self.read_variation(game, line, column)?;
+
+                    if let Some(last) = moves.last_mut() {
+                        last.variations.push(variation);
+                    }
+                }
+                Token::VariationClose if depth > 0 => break,
+                Token::Result(marker) if depth == 0 => {
+                    *result = Some(marker);
+                    break;
+                }
+                other => return Err(self.unexpected(Some((other, line, column)), "a move")),
+            }
+        }
+
+        if depth > 0 && moves.is_empty() {
+            return Err(PgnReader::<R>::error_at(
+                start_line,
+                start_column,
+                "a variation must have moves",
+            ));
+        }
+
+        if moves.is_empty() {
+            PgnReader::<R>::get_node(game).pre_comments = comments.clone();
+        }
+
+        Ok((moves, comments))
+    }
+
+    /// Read a variation of the last move played on `game`: play the variation instead of
+    /// the move, then go back to the move. `line` and `column` are those of the `(`.
+    ///
+    /// # Errors
+    ///
+    /// Errors when the variation is malformed or a move cannot be played.
+    fn read_variation(
+        &mut self,
+        game: &mut Game,
+        line: usize,
+        column: usize,
+    ) -> ChuiResult<Vec<PgnMove>> {
+        let restore = |error: ChuiError| -> ChuiError {
+            PgnReader::<R>::error_at(
+                line,
+                column,
+                format!("the variation cannot be read ({})", reason(&error)),
+            )
+        };
+        let replaced: NodeId = game.get_move_tree().get_cursor();
+        let (variation, _) = self.read_line(game, 1, &mut None)?;
+        game.goto(replaced).map_err(restore)?;
+
+        Ok(variation)
+    }
+
+    /// Get the move tree node of the current position of `game`, to annotate it.
+    fn get_node(game: &mut Game) -> &mut MoveNode {
+        let cursor: NodeId = game.get_move_tree().get_cursor();
+
+        game.get_node_mut(cursor)
+            .expect("The cursor is a node of the move tree")
+    }
+
+    /// Skip the rest of a game that could not be read: up to its termination marker, or
+    /// up to the tags of the next game.
+    fn skip_game(&mut self, mut in_move_text: bool) {
+        self.peeked = None;
+
+        loop {
+            match self.next_token() {
+                Ok(None) => return,
+                Ok(Some((Token::Result(_), _, _))) => return,
+                Ok(Some((Token::TagOpen, line, column))) if in_move_text && column == 1 => {
+                    self.peeked = Some((Token::TagOpen, line, column));
+                    return;
+                }
+                Ok(Some((Token::TagOpen | Token::TagClose | Token::String(_), _, _))) => (),
+                Ok(Some(_)) => in_move_text = true,
+                // Skip the rest of a line that cannot be read.
+                Err(_) => self.column = self.line.len(),
+            }
+        }
+    }
+
+    /// Return a new game for the tags: the players, the variant and the start position.
+    ///
+    /// # Errors
+    ///
+    /// Errors when the `FEN` tag is not a valid position.
+    fn new_game(tags: &[(String, String)]) -> ChuiResult<Game> {
+        let get_tag = |name: &str| -> Option<&str> {
+            tags.iter()
+                .find(|(tag, _)| tag == name)
+                .map(|(_, value)| value.as_str())
+                .filter(|value| !value.is_empty() && *value != "?")
+        };
+        let white = Player::new(
+            Color::White,
+            get_tag("White"),
+            None,
+            get_tag("WhiteElo").and_then(|elo| elo.parse::<u32>().ok()),
+        );
+        let black = Player::new(
+            Color::Black,
+            get_tag("Black"),
+            None,
+            get_tag("BlackElo").and_then(|elo| elo.parse::<u32>().ok()),
+        );
+        let mut game: Game = Game::new(white, black, ParserEngine::Algebraic)?;
+
+        if get_tag("Variant").is_some_and(|variant| variant.contains("960")) {
+            game.set_variant(Variant::Chess960(518));
+        }
+
+        if let Some(fen) = get_tag("FEN") {
+            game.set_fen(fen)?;
+        }
+
+        Ok(game)
+    }
+
+    /// Play the move on the game.
+    ///
+    /// # Errors
+    ///
+    /// Errors when the move cannot be parsed or played.
+    fn play(game: &mut Game, san: &str) -> ChuiResult<()> {
+        let chess_move: ChessMove = game.parse(san.to_string(), game.to_move)?;
+        game.set_current_move(Some(chess_move));
+        game.apply_move()
+    }
+
+    /// Return the next token without using it.
+    ///
+    /// # Errors
+    ///
+    /// Errors when the token is malformed.
+    fn peek_token(&mut self) -> ChuiResult<Option<(Token, usize, usize)>> {
+        if self.peeked.is_none() {
+            self.peeked = self.read_token()?;
+        }
+
+        Ok(self.peeked.clone())
+    }
+
+    /// Return the next token.
+    ///
+    /// # Errors
+    ///
+    /// Errors when the token is malformed.
+    fn next_token(&mut self) -> ChuiResult<Option<(Token, usize, usize)>> {
+        match self.peeked.take() {
+            Some(peeked) => Ok(Some(peeked)),
+            None => self.read_token(),
+        }
+    }
+
+    /// Read the next token from the text, with its line and column.
+    ///
+    /// # Errors
+    ///
+    /// Errors when the token is malformed.
+    fn read_token(&mut self) -> ChuiResult<Option<(Token, usize, usize)>> {
+        let c: char = loop {
+            match self.peek_char() {
+                None => return Ok(None),
+                // A `%` in the first column escapes the line.
+                Some('%') if self.column == 0 => self.column = self.line.len(),
+                Some(c) if c.is_whitespace() => self.column += 1,
+                Some(c) => break c,
+            }
+        };
+        let (line, column) = (self.line_number, self.column + 1);
+        self.column += 1;
+
+        let token: Token = match c {
+            '[' => Token::TagOpen,
+            ']' => Token::TagClose,
+            '(' => Token::VariationOpen,
+            ')' => Token::VariationClose,
+            '.' => Token::Period,
+            '*' => Token::Result("*".to_string()),
+            '"' => Token::String(self.read_string(line, column)?),
+            '{' => Token::Comment(self.read_brace_comment(line, column)?),
+            ';' => {
+                let comment: String = self.line[self.column..].iter().collect();
+                self.column = self.line.len();
+                Token::Comment(comment.trim().to_string())
+            }
+            '$' => {
+                let digits: String = self.take_while(|c| c.is_ascii_digit());
+                Token::Nag(digits.parse::<u8>().map_err(|_| {
+                    PgnReader::<R>::error_at(
+                        line,
+                        column,
+                        format!("`${}` is not a valid annotation glyph", digits),
+                    )
+                })?)
+            }
+            '!' | '?' => {
+                let suffix: String = format!("{}{}", c, self.take_while(|c| "!?".contains(c)));
+                Token::Nag(match suffix.as_str() {
+                    "!" => 1,
+                    "?" => 2,
+                    "!!" => 3,
+                    "??" => 4,
+                    "!?" => 5,
+                    "?!" => 6,
+                    _ => {
+                        return Err(PgnReader::<R>::error_at(
+                            line,
+                            column,
+                            format!("`{}` is not a valid annotation", suffix),
+                        ))
+                    }
+                })
+            }
+            c if PgnReader::<R>::is_symbol_char(c) => {
+                let symbol: String =
+                    format!("{}{}", c, self.take_while(PgnReader::<R>::is_symbol_char));
+
+                match symbol.as_str() {
+                    "1-0" | "0-1" | "1/2-1/2" => Token::Result(symbol),
+                    _ => Token::Symbol(symbol),
+                }
+            }
+            _ => {
+                return Err(PgnReader::<R>::error_at(
+                    line,
+                    column,
+                    format!("`{}` was not expected", c),
+                ))
+            }
+        };
+
+        Ok(Some((token, line, column)))
+    }
+
+    /// Read a quoted string, after its opening `"`, unescaping `\"` and `\\`.
+    ///
+    /// # Errors
+    ///
+    /// Errors when the string is not closed on its line.
+    fn read_string(&mut self, line: usize, column: usize) -> ChuiResult<String> {
+        let mut string: String = String::new();
+
+        loop {
+            match self.line.get(self.column).copied() {
+                Some('"') => {
+                    self.column += 1;
+                    return Ok(string);
+                }
+                Some('\\') if self.line.get(self.column + 1).is_some() => {
+                    string.push(self.line[self.column + 1]);
+                    self.column += 2;
+                }
+                Some(c) if c != '\n' => {
+                    string.push(c);
+                    self.column += 1;
+                }
+                _ => {
+                    return Err(PgnReader::<R>::error_at(
+                        line,
+                        column,
+                        "the string is not closed with `\"`",
+                    ))
+                }
+            }
+        }
+    }
+
+    /// Read a `{}` comment, after its opening `{`. The comment may span lines.
+    ///
+    /// # Errors
+    ///
+    /// Errors when the comment is not closed.
+    fn read_brace_comment(&mut self, line: usize, column: usize) -> ChuiResult<String> {
+        let mut comment: String = String::new();
+
+        loop {
+            match self.peek_char() {
+                Some('}') => {
+                    self.column += 1;
+                    return Ok(comment.split_whitespace().collect::<Vec<&str>>().join(" "));
+                }
+                Some(c) => {
+                    comment.push(c);
+                    self.column += 1;
+                }
+                None => {
+                    return Err(PgnReader::<R>::error_at(
+                        line,
+                        column,
+                        "the comment is not closed with `}`",
+                    ))
+                }
+            }
+        }
+    }
+
+    /// Can the character be part of a symbol (a move, a tag name or a move number)?
+    /// Figurines are read as part of a move.
+    fn is_symbol_char(c: char) -> bool {
+        c.is_alphanumeric()
+            || "_+#=:-/".contains(c)
+            || (!c.is_ascii() && Piece::try_from(c).is_ok())
+    }
+
+    /// Take the characters of the current line while they match.
+    fn take_while(&mut self, matches: impl Fn(char) -> bool) -> String {
+        let start: usize = self.column;
+
+        while self.line.get(self.column).is_some_and(|c| matches(*c)) {
+            self.column += 1;
+        }
+
+        self.line[start..self.column].iter().collect()
+    }
+
+    /// Return the next character of the text without using it, reading the next line when
+    /// the current line is used up. Returns `None` at the end of the text.
+    fn peek_char(&mut self) -> Option<char> {
+        while self.column >= self.line.len() {
+            if self.eof {
+                return None;
+            }
+
+            let mut line: String = String::new();
+
+            match self.reader.read_line(&mut line) {
+                Ok(0) | Err(_) => {
+                    self.eof = true;
+                    self.line.clear();
+                    self.column = 0;
+                    return None;
+                }
+                Ok(_) => {
+                    self.line = line.trim_end_matches(['\r', '\n']).chars().collect();
+                    self.line.push('\n');
+                    self.line_number += 1;
+                    self.column = 0;
+                }
+            }
+        }
+
+        self.line.get(self.column).copied()
+    }
+
+    /// Return the error for a token that was not expected.
+    fn unexpected(&self, token: Option<(Token, usize, usize)>, expected: &str) -> ChuiError {
+        match token {
+            Some((token, line, column)) => PgnReader::<R>::error_at(
+                line,
+                column,
+                format!("expected {}, found {}", expected, token),
+            ),
+            None => PgnReader::<R>::error_at(
+                self.line_number,
+                self.column + 1,
+                format!("expected {}, found the end of the text", expected),
+            ),
+        }
+    }
+
+    /// Return an error at the line and column.
+    fn error_at(line: usize, column: usize, reason: impl AsRef<str>) -> ChuiError {
+        ChuiError::InvalidPgn(format!(
+            "line {}, column {}: {}",
+            line,
+            column,
+            reason.as_ref()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{new_game, play};
+
+    const GAMES: &str = r#"[Event "Tycho Station Open"]
+[Site "Ceres"]
+[Date "2350.01.02"]
+[Round "1"]
+[White "Camina Drummer"]
+[Black "Klaes Ashford"]
+[Result "1-0"]
+[WhiteElo "2210"]
+[Annotator "Anderson \"Dawes\" Jr."]
+
+{The Scholar's Mate.} 1. e4 e5 2. Bc4 $1 Nc6 (2... Nf6 3. d3 (3. Nc3) 3...
+Bc5) 3. Qh5 Nf6?? {Black blunders.} ; Nf6 loses at once
+4. Qxf7# 1-0
+
+% An escaped line, which is skipped.
+[Event "Casual"]
+[White "Naomi Nagata"]
+[Black "James Holden"]
+[Result "*"]
+
+1.d4 d5 2.c4!? dxc4 3.e3 b5 4.a4 c6 5.axb5 cxb5 6.Qf3 Nc6 7.Qxc6+ Bd7 8.Qe4 *
+
+[White "Amos Burton"]
+[Black "Alex Kamal"]
+[FEN "4k3/P7/8/8/8/8/8/4K3 w - - 0 1"]
+[SetUp "1"]
+
+1. a8=Q+ Kd7 1/2-1/2
+"#;
+
+    fn read_games(pgn: &str) -> Vec<ChuiResult<PgnGame>> {
+        PgnReader::new(pgn.as_bytes()).collect()
+    }
+
+    #[test]
+    fn read_tags() -> ChuiResult<()> {
+        let games = read_games(GAMES);
+        assert_eq!(games.len(), 3);
+
+        let pgn_game = games[0].as_ref().map_err(Clone::clone)?;
+        assert_eq!(pgn_game.tags.len(), 9);
+        for (tag, value) in PgnGame::SEVEN_TAG_ROSTER.iter().zip([
+            "Tycho Station Open",
+            "Ceres",
+            "2350.01.02",
+            "1",
+            "Camina Drummer",
+            "Klaes Ashford",
+            "1-0",
+        ]) {
+            assert_eq!(pgn_game.get_tag(tag), Some(value));
+        }
+        assert_eq!(
+            pgn_game.get_tag("Annotator"),
+            Some("Anderson \"Dawes\" Jr.")
+        );
+        assert_eq!(pgn_game.game.white.name, Some("Camina Drummer".to_string()));
+        assert_eq!(pgn_game.game.white.rating, Some(2210));
+        assert_eq!(pgn_game.game.black.name, Some("Klaes Ashford".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn read_move_text() -> ChuiResult<()> {
+        let games = read_games(GAMES);
+        let pgn_game = games[0].as_ref().map_err(Clone::clone)?;
+
+        assert_eq!(
+            pgn_game.game.get_san_move_list(),
+            vec!["e4", "e5", "Bc4", "Nc6", "Qh5", "Nf6", "Qxf7#"]
+        );
+        assert!(pgn_game.game.white_wins);
+        assert_eq!(pgn_game.result, Some("1-0".to_string()));
+
+        let moves = &pgn_game.moves;
+        assert_eq!(moves[0].pre_comments, vec!["The Scholar's Mate."]);
+        assert_eq!(moves[2].nags, vec![1]);
+        assert_eq!(moves[5].nags, vec![4]);
+        assert_eq!(
+            moves[5].comments,
+            vec!["Black blunders.", "Nf6 loses at once"]
+        );
+        assert_eq!((moves[6].line, moves[6].column), (13, 4));
+
+        // The variation replaces `2... Nc6`, and has a variation of its own.
+        assert_eq!(moves[3].variations.len(), 1);
+        let variation = &moves[3].variations[0];
+        let sans: Vec<&str> = variation.iter().map(|m| m.san.as_str()).collect();
+        assert_eq!(sans, vec!["Nf6", "d3", "Bc5"]);
+        assert_eq!(variation[1].variations[0][0].san, "Nc3");
+
+        let pgn_game = games[1].as_ref().map_err(Clone::clone)?;
+        assert_eq!(pgn_game.game.get_san_move_list().len(), 15);
+        assert_eq!(pgn_game.moves[2].nags, vec![5]);
+        assert_eq!(pgn_game.result, Some("*".to_string()));
+
+        let pgn_game = games[2].as_ref().map_err(Clone::clone)?;
+        assert_eq!(pgn_game.game.get_san_move_list(), vec!["a8=Q+", "Kd7"]);
+        assert_eq!(pgn_game.result, Some("1/2-1/2".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn read_games_without_tags_or_results() -> ChuiResult<()> {
+        let games = read_games("1. e4 e5 2. Nf3\n\n[White \"Bobbie Draper\"]\n\n1. d4\n");
+        assert_eq!(games.len(), 2);
+        assert_eq!(
+            games[0]
+                .as_ref()
+                .map_err(Clone::clone)?
+                .game
+                .get_san_move_list(),
+            vec!["e4", "e5", "Nf3"]
+        );
+        assert_eq!(games[0].as_ref().map_err(Clone::clone)?.result, None);
+        assert_eq!(
+            games[1]
+                .as_ref()
+                .map_err(Clone::clone)?
+                .game
+                .get_san_move_list(),
+            vec!["d4"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn report_error_positions() {
+        let pgn = "[White \"Camina Drummer\"]\n\n1. e4 e5 2. Nf6 Nc6 1-0\n\n1. d4 *\n";
+        let games = read_games(pgn);
+        assert_eq!(games.len(), 2);
+
+        match &games[0] {
+            Err(ChuiError::InvalidPgn(reason)) => {
+                assert!(
+                    reason.starts_with("line 3, column 13: `Nf6` cannot be played"),
+                    "{}",
+                    reason
+                );
+            }
+            other => panic!("{:?} should be an invalid PGN", other),
+        }
+
+        // The reader moves on to the next game.
+        assert!(games[1].is_ok());
+
+        for (pgn, position) in [
+            ("[White \"Camina Drummer]\n1. e4 *", "line 1, column 8"),
+            ("1. e4 {unclosed", "line 1, column 7"),
+            ("1. e4 (1. d4", "line 1, column 7"),
+            ("1. e4 e5 ) *", "line 1, column 10"),
+            ("$1 1. e4 *", "line 1, column 1"),
+            ("1. e4 ?!? *", "line 1, column 7"),
+            ("1. e4 e5\n2. Nf3 &", "line 2, column 8"),
+        ] {
+            match read_games(pgn).first() {
+                Some(Err(ChuiError::InvalidPgn(reason))) => {
+                    assert!(reason.starts_with(position), "{:?}: {}", pgn, reason)
+                }
+                other => panic!("{:?} should be an invalid PGN: {:?}", pgn, other),
+            }
+        }
+    }
+
+    #[test]
+    fn write_games() -> ChuiResult<()> {
+        let games = read_games(GAMES);
+        let pgn_game = games[0].as_ref().map_err(Clone::clone)?;
+        let pgn = pgn_game.to_pgn();
+
+        assert!(pgn.starts_with(
+            "[Event \"Tycho Station Open\"]\n[Site \"Ceres\"]\n[Date \"2350.01.02\"]\n"
+        ));
+        assert!(pgn.contains("[Annotator \"Anderson \\\"Dawes\\\" Jr.\"]\n\n"));
+        let move_text: String = pgn.replace('\n', " ");
+        assert!(move_text.contains("2. Bc4 $1 Nc6 (2... Nf6 3. d3 (3. Nc3) 3... Bc5) 3. Qh5"));
+        assert!(move_text.contains("Nf6 $4 {Black blunders.} {Nf6 loses at once} 4. Qxf7# 1-0"));
+
+        // The PGN reads back as the same game.
+        for pgn_game in games.iter() {
+            let pgn = pgn_game.as_ref().map_err(Clone::clone)?.to_pgn();
+            let read_back = read_games(&pgn);
+            assert_eq!(read_back.len(), 1);
+            assert_eq!(read_back[0].as_ref().map_err(Clone::clone)?.to_pgn(), pgn);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn read_variations_into_the_move_tree() -> ChuiResult<()> {
+        let games = read_games(GAMES);
+        let pgn_game = games[0].as_ref().map_err(Clone::clone)?;
+        let move_text =
+            |pgn: &str| -> String { pgn.split("\n\n").nth(1).unwrap_or_default().to_string() };
+
+        assert_eq!(
+            pgn_game.game.get_main_line_san(),
+            vec!["e4", "e5", "Bc4", "Nc6", "Qh5", "Nf6", "Qxf7#"]
+        );
+        assert_eq!(
+            move_text(&pgn_game.game.to_pgn()),
+            move_text(&pgn_game.to_pgn())
+        );
+
+        let tree = pgn_game.game.get_move_tree();
+        let nc6 = tree.get_main_line()[3];
+        let nf6 = tree
+            .get_node(tree.get_node(nc6).unwrap().get_parent().unwrap())
+            .unwrap()
+            .get_children()[1];
+        assert_eq!(tree.get_node(nf6).and_then(MoveNode::get_san), Some("Nf6"));
+        assert_eq!(
+            tree.get_node(tree.get_main_line()[5])
+                .map(|node| node.nags.clone()),
+            Some(vec![4])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn wrap_move_text() -> ChuiResult<()> {
+        let mut moves: Vec<PgnMove> = ["Nf3", "Nf6", "Ng1", "Ng8"]
+            .iter()
+            .cycle()
+            .take(14)
+            .map(|san| PgnMove {
+                san: san.to_string(),
+                ..PgnMove::default()
+            })
+            .collect();
+        moves[6].comments.push(
+            "The Knights go back and forth, and back and forth, and back and forth again, \
+             and nobody wants to claim the draw."
+                .to_string(),
+        );
+        let pgn = PgnWriter::write(&[], &[], &moves, 1, Color::White, "*");
+        let move_text: &str = pgn.split("\n\n").nth(1).unwrap_or_default();
+
+        assert!(move_text.lines().count() > 2);
+        for line in move_text.lines() {
+            assert!(line.len() <= PgnWriter::MAX_LINE_LENGTH, "{:?}", line);
+            assert!(!line.ends_with('.'), "{:?}", line);
+        }
+        let move_text: String = move_text.replace('\n', " ");
+        assert!(move_text.contains("4. Ng1 {The Knights"));
+        assert!(move_text.contains("draw.} 4... Ng8 5. Nf3"));
+
+        let read_back = read_games(&pgn);
+        let pgn_game = read_back[0].as_ref().map_err(Clone::clone)?;
+        assert_eq!(pgn_game.game.get_move_history().len(), 14);
+        assert_eq!(pgn_game.moves[6].comments, moves[6].comments);
+        Ok(())
+    }
+
+    #[test]
+    fn write_comment_closing_brace() -> ChuiResult<()> {
+        let mut moves: Vec<PgnMove> = ["e4", "e5", "Nf3"]
+            .iter()
+            .map(|san| PgnMove {
+                san: san.to_string(),
+                ..PgnMove::default()
+            })
+            .collect();
+        moves[1]
+            .comments
+            .push("The {usual} reply} 2. Qh5".to_string());
+        let pgn = PgnWriter::write(&[], &[], &moves, 1, Color::White, "*");
+        assert!(pgn.contains("1. e4 e5 {The {usual reply 2. Qh5} 2. Nf3 *"));
+
+        let read_back = read_games(&pgn);
+        let pgn_game = read_back[0].as_ref().map_err(Clone::clone)?;
+        assert_eq!(pgn_game.game.get_san_move_list(), vec!["e4", "e5", "Nf3"]);
+        assert_eq!(pgn_game.moves[1].comments, vec!["The {usual reply 2. Qh5"]);
+        Ok(())
+    }
+
+    #[test]
+    fn write_game_results() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Algebraic);
+        let tags = game.get_pgn_tags();
+
+        assert_eq!(tags.len(), 8);
+        assert_eq!(tags[4], ("White".to_string(), "Camina Drummer".to_string()));
+        assert_eq!(tags[6], ("Result".to_string(), "*".to_string()));
+        assert_eq!(tags[7], ("BlackElo".to_string(), "1500".to_string()));
+        assert!(game.to_pgn().ends_with("[BlackElo \"1500\"]\n\n*\n"));
+
+        for san in ["f3", "e5", "g4", "Qh4#"] {
+            PgnReader::<&[u8]>::play(&mut game, san)?;
+        }
+        assert_eq!(game.get_pgn_result(), "0-1");
+        assert!(game.to_pgn().ends_with("\n\n1. f3 e5 2. g4 Qh4# 0-1\n"));
+
+        game.set_fen("4k3/8/8/8/8/8/8/4K2R b K - 3 12")?;
+        PgnReader::<&[u8]>::play(&mut game, "Kd7")?;
+        game.resign(Color::Black);
+        let pgn = game.to_pgn();
+        assert!(pgn.contains("[Result \"1-0\"]\n"));
+        assert!(pgn.contains("[SetUp \"1\"]\n[FEN \"4k3/8/8/8/8/8/8/4K2R b K - 3 12\"]\n"));
+        assert!(pgn.ends_with("\n\n12... Kd7 1-0\n"));
+
+        game.resign(Color::White);
+        assert_eq!(game.get_pgn_result(), "0-1");
+        game.win_condition = None;
+        game.draw_condition = Some(DrawCondition::AgreeToDraw);
+        assert_eq!(game.get_pgn_result(), "1/2-1/2");
+
+        game.set_variant(Variant::Chess960(0));
+        let pgn = game.to_pgn();
+        assert!(pgn.contains("[Variant \"Chess960\"]\n[SetUp \"1\"]\n"));
+
+        let read_back = read_games(&pgn);
+        let pgn_game = read_back[0].as_ref().map_err(Clone::clone)?;
+        assert_eq!(Fen::get_x_fen(&pgn_game.game), Fen::get_x_fen(&game));
+        Ok(())
+    }
+
+    #[test]
+    fn write_the_result_of_the_main_line() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Algebraic);
+        play(&mut game, &["f3", "e5", "g4", "Qh4#"])?;
+
+        game.goto(MoveTree::ROOT)?;
+        assert!(!game.is_game_over());
+        assert_eq!(game.get_pgn_result(), "0-1");
+        assert!(game.to_pgn().ends_with("\n\n1. f3 e5 2. g4 Qh4# 0-1\n"));
+
+        // A variation that ends in mate does not decide the result of the main line.
+        let mut game = new_game(ParserEngine::Algebraic);
+        let nodes = play(&mut game, &["f3", "e5", "g4", "d5"])?;
+        game.add_variation(nodes[3], "Qh4#")?;
+        assert!(game.black_wins);
+        assert_eq!(game.get_pgn_result(), "*");
+        assert!(game.to_pgn().ends_with(" d5 (2... Qh4#) *\n"));
+
+        // A resignation is kept with its position when the game goes elsewhere.
+        game.goto(nodes[3])?;
+        game.resign(Color::White);
+        game.goto(nodes[0])?;
+        assert_eq!(game.get_pgn_result(), "0-1");
+        game.goto(nodes[3])?;
+        assert_eq!(game.win_condition, Some(WinCondition::WhiteResigns));
+        assert!(game.black_wins);
+
+        Ok(())
+    }
+}
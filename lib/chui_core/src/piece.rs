@@ -10,6 +10,7 @@ use crate::prelude::{coord::*, *};
 
 /// Piece color. Either `White` or `Black` variants.
 #[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Color {
     /// Player color White.
     White,
@@ -31,8 +32,32 @@ impl fmt::Display for Color {
     }
 }
 
+impl Color {
+    /// Return the opposing [`Color`].
+    pub const fn opposite(&self) -> Color {
+        match self {
+            Color::White => Color::Black,
+            Color::Black => Color::White,
+        }
+    }
+}
+
+/// How a piece is represented: by its letter (e.g., `N` for a White Knight, `n` for a
+/// Black Knight) or by its Unicode figurine (e.g., `♘` or `♞`).
+#[derive(Debug, Clone, Copy, Default, PartialOrd, Ord, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PieceStyle {
+    /// The piece letter.
+    #[default]
+    Letter,
+
+    /// The Unicode chess figurine.
+    Figurine,
+}
+
 /// Piece kind. One of `Pawn`, `Knight`, `Bishop`, `Rook`, `Queen`, `King`.
 #[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PieceKind {
     /// A Pawn.
     Pawn,
@@ -54,6 +79,24 @@ pub enum PieceKind {
 }
 
 impl PieceKind {
+    /// Get the Unicode figurine of the piece kind for the color.
+    pub const fn get_figurine(&self, color: Color) -> char {
+        match (self, color) {
+            (PieceKind::King, Color::White) => '♔',
+            (PieceKind::Queen, Color::White) => '♕',
+            (PieceKind::Rook, Color::White) => '♖',
+            (PieceKind::Bishop, Color::White) => '♗',
+            (PieceKind::Knight, Color::White) => '♘',
+            (PieceKind::Pawn, Color::White) => '♙',
+            (PieceKind::King, Color::Black) => '♚',
+            (PieceKind::Queen, Color::Black) => '♛',
+            (PieceKind::Rook, Color::Black) => '♜',
+            (PieceKind::Bishop, Color::Black) => '♝',
+            (PieceKind::Knight, Color::Black) => '♞',
+            (PieceKind::Pawn, Color::Black) => '♟',
+        }
+    }
+
     /// Get the sprite index of the board asset.
     pub const fn get_sprite_index(&self, color: Color) -> usize {
         match (self, color) {
@@ -107,7 +150,7 @@ impl PieceKind {
 ///
 /// ```
 /// #[allow(clippy::wildcard_imports)]
-/// use chui_core::{Piece, PieceKind, Color, Coord, constants::*};
+/// use chui_core::prelude::{coord::*, *};
 ///
 /// // `.unwrap()` should not panic if using our constants.
 /// let white_pawn = Piece::new(PieceKind::Pawn, Color::White, Coord::try_from(A2).unwrap());
@@ -117,6 +160,7 @@ impl PieceKind {
 /// println!("{}: {:?}", black_queen.get_text(), black_queen);
 /// ```
 #[derive(Debug, Clone, Copy, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Piece {
     /// The kind of piece.
     kind: PieceKind,
@@ -531,16 +575,41 @@ impl Piece {
             (PieceKind::Pawn, Color::Black) => ("p".to_string(), "♟".to_string()),
         }
     }
+
+    /// Return a string containing the representation of a piece in the given style.
+    pub fn repr_style(&self, style: PieceStyle) -> String {
+        let (letter, figurine) = self.repr();
+
+        match style {
+            PieceStyle::Letter => letter,
+            PieceStyle::Figurine => figurine,
+        }
+    }
+
+    /// Return a colored string containing the representation of a piece in the given
+    /// style.
+    pub fn repr_colored_style(&self, style: PieceStyle) -> ColoredString {
+        let (letter, figurine) = self.repr_colored();
+
+        match style {
+            PieceStyle::Letter => letter,
+            PieceStyle::Figurine => figurine,
+        }
+    }
 }
 
-/// Returns a UTF-8, colored, string containing the string
-/// representation of the chess piece. (E.g., yellow "♙"
-/// for a White Pawn.)
-///
-/// TODO: Make representation configurable.
+/// Returns a colored string containing the representation of the chess piece: its letter
+/// (e.g., yellow "P" for a White Pawn), or its UTF-8 figurine with the alternate flag
+/// (e.g., `format!("{:#}", piece)` is yellow "♙" for a White Pawn).
 impl fmt::Display for Piece {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.repr_colored().0)
+        let style: PieceStyle = if f.alternate() {
+            PieceStyle::Figurine
+        } else {
+            PieceStyle::Letter
+        };
+
+        write!(f, "{}", self.repr_colored_style(style))
     }
 }
 
@@ -604,6 +673,25 @@ impl TryFrom<char> for Piece {
 mod tests {
     use super::*;
 
+    #[test]
+    fn piece_styles() -> ChuiResult<()> {
+        let knight = Piece::white_knight(G1)?;
+        assert_eq!(knight.repr_style(PieceStyle::Letter), "N");
+        assert_eq!(knight.repr_style(PieceStyle::Figurine), "♘");
+
+        let queen = Piece::black_queen(D8)?;
+        assert_eq!(queen.repr_style(PieceStyle::Letter), "q");
+        assert_eq!(queen.repr_style(PieceStyle::Figurine), "♛");
+
+        assert!(format!("{}", knight).contains('N'));
+        assert!(format!("{:#}", queen).contains('♛'));
+
+        for style in [PieceStyle::Letter, PieceStyle::Figurine] {
+            assert_eq!(Piece::try_from(queen.repr_style(style).as_str())?, queen);
+        }
+        Ok(())
+    }
+
     // #[test]
     // fn format_pieces() {
     //     assert_eq!(
@@ -14,7 +14,7 @@ use super::piece::Color;
 /// Example:
 ///
 /// ```
-/// use chui_core::{Color, Player};
+/// use chui_core::prelude::*;
 ///
 /// let player = Player::new(
 ///     Color::White,
@@ -24,6 +24,7 @@ use super::piece::Color;
 /// );
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Player {
     /// The piece color of the player. One of `Color::White`
     /// or `Color::Black`.
@@ -22,6 +22,7 @@ pub type BitmaskArray = [u64; 8];
 ///
 /// Bitmask for all Kings on the board given the Standard Chess variation:
 ///
+/// ```text
 ///        h8            a8
 ///     MSB 0 0 0 1 0 0 0 0
 ///         0 0 0 0 0 0 0 0
@@ -32,6 +33,7 @@ pub type BitmaskArray = [u64; 8];
 ///         0 0 0 0 0 0 0 0
 ///         0 0 0 1 0 0 0 0 LSB
 ///        h1             a1
+/// ```
 ///
 /// * The above bitmask in decimal: `115_292_1504_606_846_992`.
 /// * The above bitmask in hexidecimal: `0x10_00_00_00_00_00_00_10`.
@@ -42,6 +44,7 @@ pub type BitmaskArray = [u64; 8];
 ///
 /// Bitmask for all Pawns on the board given the Standard Chess variation:
 ///
+/// ```text
 ///        h8            a8
 ///     MSB 0 0 0 0 0 0 0 0
 ///         1 1 1 1 1 1 1 1
@@ -52,6 +55,7 @@ pub type BitmaskArray = [u64; 8];
 ///         1 1 1 1 1 1 1 1
 ///         0 0 0 0 0 0 0 0 LSB
 ///        h1             a1
+/// ```
 ///
 /// * The above bitmask in decimal: `71_776_119_061_282_560`.
 /// * The above bitmask in hexidecimal: `0x00_FF_00_00_00_00_FF_00`.
@@ -65,6 +69,15 @@ impl ArrayBitPosition {
     pub fn new(chess_variant: Variant) -> ArrayBitPosition {
         match chess_variant {
             Variant::Empty => ArrayBitPosition::default(),
+            Variant::Chess960(_) => {
+                let mut position = ArrayBitPosition::default();
+
+                for piece in chess_variant.get_pieces() {
+                    position.put_piece(Some(piece), Some(piece.get_coord()));
+                }
+
+                position
+            }
             Variant::StandardChess => ArrayBitPosition([
                 0x1000000000000010, // Kings
                 0x800000000000008,  // Queens
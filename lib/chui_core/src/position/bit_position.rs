@@ -17,6 +17,7 @@ use crate::prelude::*;
 ///
 /// Bitmask for all Kings on the board given the Standard Chess variation:
 ///
+/// ```text
 ///        h8            a8
 ///     MSB 0 0 0 1 0 0 0 0
 ///         0 0 0 0 0 0 0 0
@@ -27,6 +28,7 @@ use crate::prelude::*;
 ///         0 0 0 0 0 0 0 0
 ///         0 0 0 1 0 0 0 0 LSB
 ///        h1             a1
+/// ```
 ///
 /// * The above bitmask in decimal: `115_292_1504_606_846_992`.
 /// * The above bitmask in hexidecimal: `0x10_00_00_00_00_00_00_10`.
@@ -37,6 +39,7 @@ use crate::prelude::*;
 ///
 /// Bitmask for all Pawns on the board given the Standard Chess variation:
 ///
+/// ```text
 ///        h8            a8
 ///     MSB 0 0 0 0 0 0 0 0
 ///         1 1 1 1 1 1 1 1
@@ -47,6 +50,7 @@ use crate::prelude::*;
 ///         1 1 1 1 1 1 1 1
 ///         0 0 0 0 0 0 0 0 LSB
 ///        h1             a1
+/// ```
 ///
 /// * The above bitmask in decimal: `71_776_119_061_282_560`.
 /// * The above bitmask in hexidecimal: `0x00_FF_00_00_00_00_FF_00`.
@@ -70,6 +74,9 @@ pub struct BitPosition {
     white: u64,
     /// Bitmask for the Black pieces on the board.
     black: u64,
+    /// Zobrist hash of the pieces on the board. Updated incrementally as pieces are put onto
+    /// and taken off of the board.
+    hash: u64,
 }
 
 impl BitPosition {
@@ -77,18 +84,45 @@ impl BitPosition {
     pub fn new(chess_variant: Variant) -> BitPosition {
         match chess_variant {
             Variant::Empty => BitPosition::default(),
-            Variant::StandardChess => BitPosition {
-                kings: 0x1000000000000010,
-                queens: 0x800000000000008,
-                rooks: 0x8100000000000081,
-                bishops: 0x2400000000000024,
-                knights: 0x4200000000000042,
-                pawns: 0xFF00000000FF00,
-                white: 0xFFFF,
-                black: 0xFFFF000000000000,
-            },
+            Variant::Chess960(_) => {
+                let mut position = BitPosition::default();
+
+                for piece in chess_variant.get_pieces() {
+                    position.put_piece(Some(piece), Some(piece.get_coord()));
+                }
+
+                position
+            }
+            Variant::StandardChess => {
+                let mut position = BitPosition {
+                    kings: 0x1000000000000010,
+                    queens: 0x800000000000008,
+                    rooks: 0x8100000000000081,
+                    bishops: 0x2400000000000024,
+                    knights: 0x4200000000000042,
+                    pawns: 0xFF00000000FF00,
+                    white: 0xFFFF,
+                    black: 0xFFFF000000000000,
+                    hash: 0,
+                };
+                position.hash = position.compute_hash();
+                position
+            }
         }
     }
+
+    /// Get the Zobrist hash of the pieces on the board.
+    pub const fn get_hash(&self) -> u64 {
+        self.hash
+    }
+
+    /// Compute the Zobrist hash of the pieces on the board from scratch.
+    pub fn compute_hash(&self) -> u64 {
+        self.get_pieces_for_color(Color::White)
+            .into_iter()
+            .chain(self.get_pieces_for_color(Color::Black))
+            .fold(0, |hash, piece| hash ^ Zobrist::piece(piece))
+    }
 }
 
 impl Position for BitPosition {
@@ -136,23 +170,6 @@ impl Position for BitPosition {
         Some(Piece::new(piece_kind, color, coord))
     }
 
-    // /// Get the available [`Piece`]s for a [`Color`].
-    // fn get_pieces(&self, piece: Piece) -> Vec<Piece> {
-    //     let mut pieces = Vec::<Piece>::new();
-    //     let mut bitmask: u64 = 1;
-    //     let mut idx: u8 = 0;
-
-    //     match piece.get_color() {
-    //         Color::White => {
-    //             //
-    //         },
-    //         Color::Black => {
-    //             //
-    //         }
-    //     }
-    //     pieces
-    // }
-
     /// Put a piece onto the board. Return any piece on the given square if it's occupied
     /// already.
     ///
@@ -164,93 +181,119 @@ impl Position for BitPosition {
         let idx: u8 = coord.unwrap().get_index();
         let bitmask: u64 = 1 << idx;
 
+        if let Some(ret_piece) = ret_piece {
+            self.hash ^= Zobrist::piece(ret_piece);
+        }
+
+        // Clear the square first so that a piece replacing another piece of a different kind
+        // or color does not leave the old piece's bits behind.
+        self.kings &= !bitmask;
+        self.queens &= !bitmask;
+        self.rooks &= !bitmask;
+        self.bishops &= !bitmask;
+        self.knights &= !bitmask;
+        self.pawns &= !bitmask;
+        self.white &= !bitmask;
+        self.black &= !bitmask;
+
         if let Some(piece) = piece {
             match piece.get_kind() {
-                PieceKind::King => {
-                    if bitmask & self.kings == 0 {
-                        self.kings |= bitmask;
-                    }
-                }
-                PieceKind::Queen => {
-                    if bitmask & self.queens == 0 {
-                        self.queens |= bitmask;
-                    }
-                }
-                PieceKind::Rook => {
-                    if bitmask & self.rooks == 0 {
-                        self.rooks |= bitmask;
-                    }
-                }
-                PieceKind::Bishop => {
-                    if bitmask & self.bishops == 0 {
-                        self.kings |= bitmask;
-                    }
-                }
-                PieceKind::Knight => {
-                    if bitmask & self.knights == 0 {
-                        self.knights |= bitmask;
-                    }
-                }
-                PieceKind::Pawn => {
-                    if bitmask & self.pawns == 0 {
-                        self.pawns |= bitmask;
-                    }
-                }
+                PieceKind::King => self.kings |= bitmask,
+                PieceKind::Queen => self.queens |= bitmask,
+                PieceKind::Rook => self.rooks |= bitmask,
+                PieceKind::Bishop => self.bishops |= bitmask,
+                PieceKind::Knight => self.knights |= bitmask,
+                PieceKind::Pawn => self.pawns |= bitmask,
             }
 
             match piece.get_color() {
-                Color::White => {
-                    if bitmask & self.white == 0 {
-                        self.white |= bitmask;
-                    }
-
-                    if bitmask & self.black > 0 {
-                        self.black ^= bitmask;
-                    }
-                }
-                Color::Black => {
-                    if bitmask & self.black == 0 {
-                        self.black |= bitmask;
-                    }
-
-                    if bitmask & self.white > 0 {
-                        self.white ^= bitmask;
-                    }
-                }
-            }
-        } else {
-            if bitmask & self.kings > 0 {
-                self.kings ^= bitmask;
-            } else if bitmask & self.queens > 0 {
-                self.queens ^= bitmask;
-            } else if bitmask & self.rooks > 0 {
-                self.rooks ^= bitmask;
-            } else if bitmask & self.bishops > 0 {
-                self.bishops ^= bitmask;
-            } else if bitmask & self.knights > 0 {
-                self.knights ^= bitmask;
-            } else if bitmask & self.pawns > 0 {
-                self.pawns ^= bitmask;
+                Color::White => self.white |= bitmask,
+                Color::Black => self.black |= bitmask,
             }
 
-            if bitmask & self.white > 0 {
-                self.white ^= bitmask;
-            } else if bitmask & self.black > 0 {
-                self.black ^= bitmask;
-            }
+            self.hash ^= Zobrist::piece(Piece::new(
+                piece.get_kind(),
+                piece.get_color(),
+                coord.unwrap(),
+            ));
         }
 
         ret_piece
     }
 
-    /// Get all [`Piece`]s attacking a given coordinate.
+    /// Get all [`Piece`]s attacking a given coordinate. Attacking pieces are those of the
+    /// opposite color of `piece`. The `piece` itself is lifted off of the board first so that
+    /// it does not shield any squares behind it (e.g., a King stepping back along a Rook's
+    /// file).
     fn get_pieces_attacking_coord(
         &self,
         _board: &Board,
-        _piece: Piece,
-        _coord: Option<Coord>,
+        piece: Piece,
+        coord: Option<Coord>,
     ) -> Vec<Piece> {
-        Vec::<Piece>::new()
+        let mut pieces = Vec::<Piece>::new();
+        let coord: Coord = match coord {
+            Some(coord) => coord,
+            None => return pieces,
+        };
+        let opponent: Color = piece.get_color().opposite();
+        let mut position: BitPosition = *self;
+
+        if position.get_piece(Some(piece.get_coord())) == Some(piece) {
+            position.take_piece(Some(piece.get_coord()));
+        }
+
+        // Sliding pieces (and the King) along the orthogonals and diagonals.
+        for (file_delta, rank_delta) in ORTHOGONALS.iter().chain(DIAGONALS.iter()) {
+            let is_diagonal = *file_delta != 0 && *rank_delta != 0;
+            let mut distance: i8 = 1;
+
+            while let Some(o_coord) = coord.offset(file_delta * distance, rank_delta * distance) {
+                if let Some(o_piece) = position.get_piece(Some(o_coord)) {
+                    let is_attacking = match o_piece.get_kind() {
+                        PieceKind::Queen => true,
+                        PieceKind::Rook => !is_diagonal,
+                        PieceKind::Bishop => is_diagonal,
+                        PieceKind::King => distance == 1,
+                        PieceKind::Knight | PieceKind::Pawn => false,
+                    };
+
+                    if is_attacking && o_piece.get_color() == opponent {
+                        pieces.push(o_piece);
+                    }
+
+                    break;
+                }
+
+                distance += 1;
+            }
+        }
+
+        // Knights.
+        for (file_delta, rank_delta) in KNIGHT_OFFSETS.iter() {
+            if let Some(o_piece) = position.get_piece(coord.offset(*file_delta, *rank_delta)) {
+                if o_piece.is_knight() && o_piece.get_color() == opponent {
+                    pieces.push(o_piece);
+                }
+            }
+        }
+
+        // Pawns attack diagonally forward, so an opposing Pawn attacks from one rank "ahead"
+        // of the coordinate from the perspective of `piece`.
+        let rank_delta: i8 = match opponent {
+            Color::White => -1,
+            Color::Black => 1,
+        };
+
+        for file_delta in [-1, 1] {
+            if let Some(o_piece) = position.get_piece(coord.offset(file_delta, rank_delta)) {
+                if o_piece.is_pawn() && o_piece.get_color() == opponent {
+                    pieces.push(o_piece);
+                }
+            }
+        }
+
+        pieces
     }
 }
 
@@ -22,6 +22,7 @@ use crate::prelude::*;
 ///
 /// Bitmask for all Kings on the board given the Standard Chess variation:
 ///
+/// ```text
 ///        h8            a8
 ///     MSB 0 0 0 1 0 0 0 0
 ///         0 0 0 0 0 0 0 0
@@ -32,6 +33,7 @@ use crate::prelude::*;
 ///         0 0 0 0 0 0 0 0
 ///         0 0 0 1 0 0 0 0 LSB
 ///        h1             a1
+/// ```
 ///
 /// * The above bitmask in decimal: `115_292_1504_606_846_992`.
 /// * The above bitmask in hexidecimal: `0x10_00_00_00_00_00_00_10`.
@@ -42,6 +44,7 @@ use crate::prelude::*;
 ///
 /// Bitmask for all Pawns on the board given the Standard Chess variation:
 ///
+/// ```text
 ///        h8            a8
 ///     MSB 0 0 0 0 0 0 0 0
 ///         1 1 1 1 1 1 1 1
@@ -52,6 +55,7 @@ use crate::prelude::*;
 ///         1 1 1 1 1 1 1 1
 ///         0 0 0 0 0 0 0 0 LSB
 ///        h1             a1
+/// ```
 ///
 /// * The above bitmask in decimal: `71_776_119_061_282_560`.
 /// * The above bitmask in hexidecimal: `0x00_FF_00_00_00_00_FF_00`.
@@ -82,6 +86,15 @@ impl BitSetPosition {
     pub fn new(chess_variant: Variant) -> BitSetPosition {
         match chess_variant {
             Variant::Empty => BitSetPosition::default(),
+            Variant::Chess960(_) => {
+                let mut position = BitSetPosition::default();
+
+                for piece in chess_variant.get_pieces() {
+                    position.put_piece(Some(piece), Some(piece.get_coord()));
+                }
+
+                position
+            }
             Variant::StandardChess => BitSetPosition {
                 kings: 0x1000000000000010,
                 queens: 0x800000000000008,
@@ -18,6 +18,15 @@ impl Easy1DPosition {
     pub fn new(chess_variant: Variant) -> Easy1DPosition {
         match chess_variant {
             Variant::Empty => Easy1DPosition::default(),
+            Variant::Chess960(_) => {
+                let mut position = Easy1DPosition::default();
+
+                for piece in chess_variant.get_pieces() {
+                    position.put_piece(Some(piece), Some(piece.get_coord()));
+                }
+
+                position
+            }
             Variant::StandardChess => Easy1DPosition::new_standard_chess(),
         }
     }
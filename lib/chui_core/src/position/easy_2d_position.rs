@@ -18,6 +18,15 @@ impl Easy2DPosition {
     pub fn new(chess_variant: Variant) -> Easy2DPosition {
         match chess_variant {
             Variant::Empty => Easy2DPosition::default(),
+            Variant::Chess960(_) => {
+                let mut position = Easy2DPosition::default();
+
+                for piece in chess_variant.get_pieces() {
+                    position.put_piece(Some(piece), Some(piece.get_coord()));
+                }
+
+                position
+            }
             Variant::StandardChess => Easy2DPosition::new_standard_chess(),
         }
     }
@@ -121,6 +121,15 @@ impl EnumPosition {
     pub fn new(chess_variant: Variant) -> EnumPosition {
         match chess_variant {
             Variant::Empty => EnumPosition::default(),
+            Variant::Chess960(_) => {
+                let mut position = EnumPosition::default();
+
+                for piece in chess_variant.get_pieces() {
+                    position.put_piece(Some(piece), Some(piece.get_coord()));
+                }
+
+                position
+            }
             Variant::StandardChess => EnumPosition::new_standard_chess(),
         }
     }
@@ -69,6 +69,39 @@ pub enum ChuiError {
     /// Invalid en passant.
     InvalidEnPassant(String),
 
+    /// Invalid FEN. This variant shows up when a FEN string does not have
+    /// exactly six space separated fields, or when the side to move, the
+    /// half-move clock, or the full-move counter fields are malformed.
+    InvalidFen(String),
+
+    /// Invalid piece placement. This variant shows up when the piece
+    /// placement field of a FEN string is malformed or describes an
+    /// illegal position (e.g., a missing King or a Pawn on the back rank).
+    InvalidPiecePlacement(String),
+
+    /// Invalid castling rights. This variant shows up when the castling
+    /// field of a FEN string is malformed or does not agree with the
+    /// placement of the Kings and Rooks.
+    InvalidCastlingRights(String),
+
+    /// Invalid PGN. This variant shows up when the text of a PGN game is
+    /// malformed, or a move of the game cannot be played, with the line and
+    /// column where the problem was found.
+    InvalidPgn(String),
+
+    /// Invalid EPD. This variant shows up when an EPD record is missing a
+    /// position field, or has a malformed operation.
+    InvalidEpd(String),
+
+    /// The game is over. This variant shows up when a move is applied after
+    /// the game has been won, lost, or drawn.
+    GameOver(String),
+
+    /// An invalid draw claim. This variant shows up when a player claims a
+    /// draw by threefold repetition or the fifty move rule, but the game
+    /// does not satisfy either condition.
+    InvalidDrawClaim(String),
+
     /// The [`TryFromIntError`] type.
     TryFromIntError(String),
 
@@ -143,6 +176,34 @@ impl fmt::Display for ChuiError {
                 write!(f, "Error (Invalid En Passant): {}.", reason)
             }
 
+            ChuiError::InvalidFen(reason) => {
+                write!(f, "Error (Invalid FEN): {}.", reason)
+            }
+
+            ChuiError::InvalidPiecePlacement(reason) => {
+                write!(f, "Error (Invalid Piece Placement): {}.", reason)
+            }
+
+            ChuiError::InvalidCastlingRights(reason) => {
+                write!(f, "Error (Invalid Castling Rights): {}.", reason)
+            }
+
+            ChuiError::InvalidPgn(reason) => {
+                write!(f, "Error (Invalid PGN): {}.", reason)
+            }
+
+            ChuiError::InvalidEpd(reason) => {
+                write!(f, "Error (Invalid EPD): {}.", reason)
+            }
+
+            ChuiError::GameOver(reason) => {
+                write!(f, "Error (Game Over): {}.", reason)
+            }
+
+            ChuiError::InvalidDrawClaim(reason) => {
+                write!(f, "Error (Invalid Draw Claim): {}.", reason)
+            }
+
             ChuiError::TryFromIntError(reason) => {
                 write!(f, "Error (TryFromIntError): {}.", reason)
             }
@@ -0,0 +1,677 @@
+//! Serde serialization, enabled by the `serde` feature.
+//!
+//! The representations of the types below are kept stable across changes to the types.
+//! [`Board`] and [`Game`] carry a version, which changes with their representation, and a
+//! representation of another version is refused rather than misread.
+//!
+//! * [`Coord`] is its name in algebraic notation (e.g., `"e4"`).
+//! * [`BitPosition`] is the piece placement field of a FEN string.
+//! * [`Board`] is a versioned record of its FEN string, with its castling Rooks named by
+//!   their files on a Chess960 board. As with `Board::from_fen()`, only a board with a King
+//!   for each side deserializes.
+//! * [`Game`] is a versioned record of its players, its start position, the moves of the
+//!   main line and of the line to the current position (in Standard Algebraic Notation),
+//!   the result at the end of the main line and its settings. The moves are played again
+//!   on deserialize, which restores the board, the clocks, the captured pieces and the game
+//!   result, and leaves the game at its current position. Of the variations, only the one
+//!   the current position is in is kept.
+//!
+//! The other types derive their representation from the names of their fields and
+//! variants, which may change from one release to the next, so are not covered on their
+//! own. Those that are part of a `Game` record ([`Player`], [`Color`], [`WinCondition`],
+//! [`DrawCondition`], [`Locale`] and [`PieceStyle`]) are covered as part of it: a change to
+//! their representation is a new version of the `Game` representation.
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::prelude::*;
+
+/// The version of the [`Board`] and [`Game`] representations.
+const VERSION: u32 = 1;
+
+/// The parser engines a deserialized [`Game`] may be set to, found by the name of the
+/// parser.
+const PARSER_ENGINES: [ParserEngine; 9] = [
+    ParserEngine::Algebraic,
+    ParserEngine::ConciseReversible,
+    ParserEngine::Coordinate,
+    ParserEngine::Descriptive,
+    ParserEngine::ICCF,
+    ParserEngine::LongAlgebraic,
+    ParserEngine::ReversibleAlgebraic,
+    ParserEngine::Smith,
+    ParserEngine::Uci,
+];
+
+/// Check the version of a deserialized representation.
+fn check_version<E: Error>(kind: &str, version: u32) -> Result<(), E> {
+    if version == VERSION {
+        Ok(())
+    } else {
+        Err(E::custom(ChuiError::InvalidInput(format!(
+            "version {} of the {} representation is not supported (expected version {})",
+            version, kind, VERSION
+        ))))
+    }
+}
+
+/// Serialize an optional file or rank index (0..=7) as an optional `u8`.
+pub(crate) mod option_non_max_u8 {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        index: &Option<NonMaxU8>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        index.map(|index| index.get()).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<NonMaxU8>, D::Error> {
+        Option::<u8>::deserialize(deserializer)?
+            .map(|index| {
+                NonMaxU8::new(index)
+                    .ok_or_else(|| D::Error::custom(format!("{} is not a valid index", index)))
+            })
+            .transpose()
+    }
+}
+
+impl Serialize for Coord {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Coord {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Coord, D::Error> {
+        let coord = String::deserialize(deserializer)?;
+
+        Coord::try_from(coord.as_str()).map_err(D::Error::custom)
+    }
+}
+
+impl Serialize for BitPosition {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut board = Board::new(Variant::Empty);
+        *board.get_position_mut() = *self;
+
+        serializer.serialize_str(&Fen::get_piece_placement(&board))
+    }
+}
+
+impl<'de> Deserialize<'de> for BitPosition {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<BitPosition, D::Error> {
+        let placement = String::deserialize(deserializer)?;
+
+        Fen::parse_pieces(&placement).map_err(D::Error::custom)
+    }
+}
+
+/// The representation of a [`Board`].
+#[derive(Serialize, Deserialize)]
+struct BoardRecord {
+    /// The version of the representation.
+    version: u32,
+
+    /// The FEN string of the board. The clocks are not kept by the board, so are always
+    /// `0 1`.
+    fen: String,
+
+    /// Is this a Chess960 board?
+    chess960: bool,
+}
+
+/// Get the FEN string of a board. The castling Rooks are named by their files (as with
+/// Shredder-FEN) on a Chess960 board, and by their side of the board (`KQkq`) otherwise.
+fn get_board_fen(board: &Board) -> String {
+    let castling: String = [Color::White, Color::Black]
+        .into_iter()
+        .flat_map(|color| [(color, Castling::King), (color, Castling::Queen)])
+        .filter(|(color, castling)| board.can_castle(*color, *castling))
+        .filter_map(|(color, castling)| {
+            let letter = if board.is_chess960() {
+                char::from(
+                    b'a' + board
+                        .get_castling_rook_coord(color, castling)
+                        .ok()?
+                        .get_file(),
+                )
+            } else if castling == Castling::King {
+                'k'
+            } else {
+                'q'
+            };
+
+            Some(match color {
+                Color::White => letter.to_ascii_uppercase(),
+                Color::Black => letter,
+            })
+        })
+        .collect();
+
+    format!(
+        "{} {} {} {} 0 1",
+        Fen::get_piece_placement(board),
+        match board.get_to_move() {
+            Color::White => 'w',
+            Color::Black => 'b',
+        },
+        if castling.is_empty() { "-" } else { &castling },
+        board
+            .get_en_passant_coord()
+            .map_or_else(|| "-".to_string(), |coord| coord.to_string()),
+    )
+}
+
+impl Serialize for Board {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        BoardRecord {
+            version: VERSION,
+            fen: get_board_fen(self),
+            chess960: self.is_chess960(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Board {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Board, D::Error> {
+        let record = BoardRecord::deserialize(deserializer)?;
+        check_version::<D::Error>("Board", record.version)?;
+
+        if record.chess960 {
+            Board::from_chess960_fen(&record.fen)
+        } else {
+            Board::from_fen(&record.fen)
+        }
+        .map_err(D::Error::custom)
+    }
+}
+
+/// The representation of a [`Game`].
+#[derive(Serialize, Deserialize)]
+struct GameRecord {
+    /// The version of the representation.
+    version: u32,
+
+    /// The White player.
+    white: Player,
+
+    /// The Black player.
+    black: Player,
+
+    /// The name of the move parser (e.g., `Algebraic Parser`).
+    parser: String,
+
+    /// Is this a Chess960 game?
+    chess960: bool,
+
+    /// The FEN string of the start position (X-FEN for a Chess960 game).
+    start_fen: String,
+
+    /// The moves of the main line, in Standard Algebraic Notation.
+    moves: Vec<String>,
+
+    /// The moves from the start position to the current position, in Standard Algebraic
+    /// Notation. They leave the main line when the current position is in a variation.
+    current_line: Vec<String>,
+
+    /// The win condition at the end of the main line.
+    win_condition: Option<WinCondition>,
+
+    /// The draw condition at the end of the main line.
+    draw_condition: Option<DrawCondition>,
+
+    /// Display the chessboard for a particular `Color`.
+    display_for: Option<Color>,
+
+    /// Accept moves as people tend to type them.
+    lenient: bool,
+
+    /// The language of the piece letters of the moves parsed.
+    locale: Locale,
+
+    /// Display the pieces on the board by their letters or by their figurines.
+    piece_style: PieceStyle,
+}
+
+impl Serialize for Game {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (win_condition, draw_condition, _) = self.get_main_line_result();
+
+        GameRecord {
+            version: VERSION,
+            white: self.white.clone(),
+            black: self.black.clone(),
+            parser: self.parser.name(),
+            chess960: self.board.is_chess960(),
+            start_fen: self.get_start_fen().to_string(),
+            moves: self.get_main_line_san(),
+            current_line: self.get_san_move_list(),
+            win_condition,
+            draw_condition,
+            display_for: self.display_for,
+            lenient: self.lenient,
+            locale: self.locale,
+            piece_style: self.piece_style,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Game {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Game, D::Error> {
+        let record = GameRecord::deserialize(deserializer)?;
+        check_version::<D::Error>("Game", record.version)?;
+
+        let parser_engine = PARSER_ENGINES
+            .into_iter()
+            .find(|engine| ParserEngine::new(*engine, Color::White).name() == record.parser)
+            .ok_or_else(|| {
+                D::Error::custom(ChuiError::InvalidInput(format!(
+                    "`{}` is not a parser",
+                    record.parser
+                )))
+            })?;
+
+        // The moves are written in English, so are played with the algebraic parser before
+        // the game's own parser and locale are set.
+        let mut game = Game::new(record.white, record.black, ParserEngine::Algebraic)
+            .map_err(D::Error::custom)?;
+
+        if record.chess960 {
+            game.set_variant(Variant::Chess960(518));
+        }
+
+        game.set_fen(&record.start_fen).map_err(D::Error::custom)?;
+
+        let play = |game: &mut Game, moves: &[String]| -> ChuiResult<()> {
+            for the_move in moves.iter() {
+                let chess_move = game.parse(the_move.clone(), game.to_move)?;
+                game.set_current_move(Some(chess_move));
+                game.apply_move()?;
+            }
+            Ok(())
+        };
+
+        play(&mut game, &record.moves).map_err(D::Error::custom)?;
+
+        // Resignations and draws that were agreed to or claimed do not follow from the
+        // moves played.
+        if !game.is_game_over() {
+            game.set_result(record.win_condition, record.draw_condition);
+        }
+
+        // Go back to the last move the current line shares with the main line, then play
+        // the rest of it, which adds it as a variation.
+        let shared: usize = record
+            .moves
+            .iter()
+            .zip(record.current_line.iter())
+            .take_while(|(main_move, current_move)| main_move == current_move)
+            .count();
+
+        if shared < record.moves.len() {
+            let node_id: NodeId = match shared {
+                0 => MoveTree::ROOT,
+                _ => game.get_move_tree().get_main_line()[shared - 1],
+            };
+            game.goto(node_id).map_err(D::Error::custom)?;
+        }
+
+        play(&mut game, &record.current_line[shared..]).map_err(D::Error::custom)?;
+
+        game.set_parser(parser_engine);
+        game.set_locale(record.locale);
+        game.display_for = record.display_for;
+        game.lenient = record.lenient;
+        game.piece_style = record.piece_style;
+
+        Ok(game)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Debug;
+
+    use super::*;
+    use crate::testing::{new_game, play};
+
+    fn round_trip<T>(value: &T) -> T
+    where
+        T: Serialize + for<'de> Deserialize<'de> + PartialEq + Debug,
+    {
+        let bytes = bincode::serialize(value).unwrap();
+        let deserialized: T = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(&deserialized, value, "{:?}", bytes);
+
+        let json = serde_json::to_string(value).unwrap();
+        let deserialized: T = serde_json::from_str(&json).unwrap();
+        assert_eq!(&deserialized, value, "{}", json);
+        deserialized
+    }
+
+    /// A `Game` is not `PartialEq`, so is compared by `assert_same_game()`.
+    fn round_trip_game(game: &Game) -> Game {
+        let bytes = bincode::serialize(game).unwrap();
+        let deserialized: Game = bincode::deserialize(&bytes).unwrap();
+        assert_same_game(&deserialized, game);
+
+        let json = serde_json::to_string(game).unwrap();
+        let deserialized: Game = serde_json::from_str(&json).unwrap();
+        assert_same_game(&deserialized, game);
+        deserialized
+    }
+
+    fn assert_same_game(deserialized: &Game, game: &Game) {
+        assert_eq!(deserialized.white, game.white);
+        assert_eq!(deserialized.black, game.black);
+        assert_eq!(deserialized.parser.name(), game.parser.name());
+        assert_eq!(deserialized.board, game.board);
+        assert_eq!(deserialized.captured_pieces, game.captured_pieces);
+        assert_eq!(deserialized.to_move, game.to_move);
+        assert_eq!(deserialized.position_record, game.position_record);
+        assert_eq!(deserialized.white_wins, game.white_wins);
+        assert_eq!(deserialized.black_wins, game.black_wins);
+        assert_eq!(deserialized.is_draw, game.is_draw);
+        assert_eq!(deserialized.half_move_counter, game.half_move_counter);
+        assert_eq!(deserialized.half_move_clock, game.half_move_clock);
+        assert_eq!(deserialized.move_counter, game.move_counter);
+        // The moves are played again from their algebraic notation, whatever the notation
+        // they were parsed from.
+        let get_moves = |game: &Game| -> Vec<(Option<Coord>, Option<Coord>, Option<Piece>)> {
+            game.move_list
+                .iter()
+                .map(|chess_move| {
+                    (
+                        chess_move.from_coord,
+                        chess_move.to_coord,
+                        chess_move.promotion,
+                    )
+                })
+                .collect()
+        };
+        assert_eq!(get_moves(deserialized), get_moves(game));
+        assert_eq!(deserialized.get_san_move_list(), game.get_san_move_list());
+        assert_eq!(deserialized.get_main_line_san(), game.get_main_line_san());
+        assert_eq!(deserialized.get_pgn_result(), game.get_pgn_result());
+        assert_eq!(deserialized.get_start_fen(), game.get_start_fen());
+        assert_eq!(deserialized.win_condition, game.win_condition);
+        assert_eq!(deserialized.draw_condition, game.draw_condition);
+        assert_eq!(deserialized.display_for, game.display_for);
+        assert_eq!(deserialized.lenient, game.lenient);
+        assert_eq!(deserialized.locale, game.locale);
+        assert_eq!(deserialized.piece_style, game.piece_style);
+        assert_eq!(Fen::get_fen(deserialized), Fen::get_fen(game));
+    }
+
+    #[test]
+    fn round_trip_enums() {
+        for color in [Color::White, Color::Black] {
+            round_trip(&color);
+        }
+        for kind in [
+            PieceKind::King,
+            PieceKind::Queen,
+            PieceKind::Rook,
+            PieceKind::Bishop,
+            PieceKind::Knight,
+            PieceKind::Pawn,
+        ] {
+            round_trip(&kind);
+        }
+        for style in [PieceStyle::Letter, PieceStyle::Figurine] {
+            round_trip(&style);
+        }
+        for variant in [
+            Variant::StandardChess,
+            Variant::Empty,
+            Variant::Chess960(959),
+        ] {
+            round_trip(&variant);
+        }
+        for win_condition in [
+            WinCondition::Checkmate,
+            WinCondition::WhiteResigns,
+            WinCondition::BlackResigns,
+        ] {
+            round_trip(&win_condition);
+        }
+        for draw_condition in [
+            DrawCondition::AgreeToDraw,
+            DrawCondition::Stalemate,
+            DrawCondition::ThirdRepitition,
+            DrawCondition::FifthRepetition,
+            DrawCondition::FiftyMoveRule,
+            DrawCondition::SeventyFiveMoveRule,
+            DrawCondition::InsufficientMaterial,
+            DrawCondition::PerpetualCheck,
+        ] {
+            round_trip(&draw_condition);
+        }
+        for castling in [Castling::King, Castling::Queen] {
+            round_trip(&castling);
+        }
+        for check in [Check::Check, Check::Mate] {
+            round_trip(&check);
+        }
+        for move_type in [
+            MoveType::PawnMove,
+            MoveType::PawnCapture,
+            MoveType::PieceMove,
+            MoveType::PieceCapture,
+            MoveType::Castle,
+        ] {
+            round_trip(&move_type);
+        }
+        for locale in Locale::ALL {
+            round_trip(&locale);
+        }
+    }
+
+    #[test]
+    fn round_trip_pieces_and_players() -> ChuiResult<()> {
+        let coord = Coord::try_from("e4")?;
+        assert_eq!(serde_json::to_string(&coord).unwrap(), "\"e4\"");
+        round_trip(&coord);
+        assert!(serde_json::from_str::<Coord>("\"i9\"").is_err());
+
+        let piece = Piece::new(PieceKind::Knight, Color::Black, Coord::try_from("g8")?);
+        assert_eq!(round_trip(&piece).get_coord(), piece.get_coord());
+
+        round_trip(&Player::new(
+            Color::White,
+            Some("Camina Drummer"),
+            Some(37),
+            None,
+        ));
+        round_trip(&Player::new(Color::Black, None, None, Some(1500)));
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip_chess_moves() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Algebraic);
+        play(&mut game, &["e4", "d5", "exd5", "Qxd5", "Nc3"])?;
+
+        for chess_move in game.move_list.iter() {
+            round_trip(chess_move);
+        }
+
+        let chess_move = game.parse("Qa5".to_string(), game.to_move)?;
+        let json = serde_json::to_string(&chess_move).unwrap();
+        assert!(json.contains("\"to_coord\":\"a5\""), "{}", json);
+        round_trip(&chess_move);
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip_positions_and_boards() -> ChuiResult<()> {
+        let board = Board::new(Variant::StandardChess);
+        let position = board.get_position();
+        assert_eq!(
+            serde_json::to_string(&position).unwrap(),
+            "\"rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR\""
+        );
+        round_trip(&position);
+        round_trip(&board);
+        assert!(serde_json::from_str::<BitPosition>("\"rnbqkbnr/9\"").is_err());
+
+        // En passant and lost castling rights.
+        let mut game = new_game(ParserEngine::Algebraic);
+        play(&mut game, &["e4", "Nf6", "e5", "d5", "Ke2"])?;
+        round_trip(&game.board);
+        round_trip(&game.board.get_position());
+
+        round_trip(&Board::from_fen("8/8/8/4k3/8/8/8/R3K2R b KQ - 0 1")?);
+        round_trip(&Board::new(Variant::Chess960(0)));
+        round_trip(&Board::new(Variant::Empty).get_position());
+        let json = serde_json::to_string(&Board::new(Variant::Empty)).unwrap();
+        assert!(serde_json::from_str::<Board>(&json).is_err());
+
+        let json = serde_json::to_string(&board).unwrap();
+        let json = json.replace("\"version\":1", "\"version\":2");
+        assert!(serde_json::from_str::<Board>(&json).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip_games() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Algebraic);
+        game.set_locale(Locale::German);
+        game.lenient = true;
+        game.piece_style = PieceStyle::Figurine;
+        game.display_for = Some(Color::Black);
+        play(
+            &mut game,
+            &["e4", "e5", "Sf3", "Sc6", "Lb5", "a6", "Lxc6", "dxc6"],
+        )?;
+
+        let json = serde_json::to_string(&game).unwrap();
+        assert!(json.contains("\"version\":1"), "{}", json);
+        assert!(
+            json.contains(
+                "\"moves\":[\"e4\",\"e5\",\"Nf3\",\"Nc6\",\"Bb5\",\"a6\",\"Bxc6\",\"dxc6\"]"
+            ),
+            "{}",
+            json
+        );
+        // The game's own parser and locale are restored.
+        let mut deserialized = round_trip_game(&game);
+        play(&mut deserialized, &["O-O", "Sf6"])?;
+
+        // A game that has ended.
+        let mut game = new_game(ParserEngine::Algebraic);
+        play(&mut game, &["f3", "e5", "g4", "Qh4#"])?;
+        let deserialized = round_trip_game(&game);
+        assert!(deserialized.black_wins);
+
+        // Results that do not follow from the moves.
+        let mut game = new_game(ParserEngine::Algebraic);
+        play(&mut game, &["d4", "d5"])?;
+        game.resign(Color::White);
+        round_trip_game(&game);
+
+        let mut game = new_game(ParserEngine::Algebraic);
+        play(
+            &mut game,
+            &["Nf3", "Nf6", "Ng1", "Ng8", "Nf3", "Nf6", "Ng1", "Ng8"],
+        )?;
+        game.claim_draw()?;
+        let deserialized = round_trip_game(&game);
+        assert_eq!(
+            deserialized.draw_condition,
+            Some(DrawCondition::ThirdRepitition)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip_games_away_from_the_end_of_the_main_line() -> ChuiResult<()> {
+        // Moves taken back stay in the main line.
+        let mut game = new_game(ParserEngine::Algebraic);
+        play(&mut game, &["e4", "e5", "Nf3", "Nc6"])?;
+        game.undo()?;
+        game.undo()?;
+        let deserialized = round_trip_game(&game);
+        assert_eq!(deserialized.get_main_line_san(), ["e4", "e5", "Nf3", "Nc6"]);
+        assert_eq!(deserialized.get_san_move_list(), ["e4", "e5"]);
+
+        // A variation keeps its place after the main line, and the game stays in it.
+        let mut game = new_game(ParserEngine::Algebraic);
+        let nodes = play(&mut game, &["e4", "e5", "Nf3"])?;
+        game.add_variation(nodes[0], "d4")?;
+        play(&mut game, &["d5"])?;
+        let deserialized = round_trip_game(&game);
+        assert_eq!(deserialized.get_main_line_san(), ["e4", "e5", "Nf3"]);
+        assert_eq!(deserialized.get_san_move_list(), ["d4", "d5"]);
+
+        // The result of the main line is kept when the game is not at its end.
+        let mut game = new_game(ParserEngine::Algebraic);
+        let nodes = play(&mut game, &["f3", "e5", "g4", "Qh4#"])?;
+        game.goto(nodes[1])?;
+        let deserialized = round_trip_game(&game);
+        assert_eq!(deserialized.get_pgn_result(), "0-1");
+        assert!(!deserialized.is_game_over());
+        Ok(())
+    }
+
+    #[test]
+    fn read_version_1_games() -> ChuiResult<()> {
+        // A change to this representation, or to the derived representations of the types
+        // in it, is a new version.
+        let json = r#"{
+            "version": 1,
+            "white": {"color": "White", "name": "Camina Drummer", "age": 37, "rating": null},
+            "black": {"color": "Black", "name": "Klaes Ashford", "age": 72, "rating": 1500},
+            "parser": "Algebraic Parser",
+            "chess960": false,
+            "start_fen": "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "moves": ["d4", "d5", "c4"],
+            "current_line": ["d4", "d5"],
+            "win_condition": "WhiteResigns",
+            "draw_condition": null,
+            "display_for": null,
+            "lenient": false,
+            "locale": "English",
+            "piece_style": "Letter"
+        }"#;
+
+        let mut game = new_game(ParserEngine::Algebraic);
+        play(&mut game, &["d4", "d5", "c4"])?;
+        game.resign(Color::White);
+        game.undo()?;
+
+        let deserialized: Game = serde_json::from_str(json).unwrap();
+        assert_same_game(&deserialized, &game);
+        assert_eq!(
+            serde_json::to_value(&game).unwrap(),
+            serde_json::from_str::<serde_json::Value>(json).unwrap()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip_games_from_positions() -> ChuiResult<()> {
+        let mut game = new_game(ParserEngine::Algebraic);
+        game.set_fen("4k3/8/8/8/8/8/4P3/4K3 w - - 3 40")?;
+        play(&mut game, &["e4", "Kd7"])?;
+        round_trip_game(&game);
+
+        let mut game = new_game(ParserEngine::Algebraic);
+        game.set_parser(ParserEngine::Uci);
+        game.set_variant(Variant::Chess960(0));
+        play(&mut game, &["b2b3", "b7b6", "a1b2"])?;
+        let deserialized = round_trip_game(&game);
+        assert!(deserialized.board.is_chess960());
+
+        let json = serde_json::to_string(&game).unwrap();
+        let json = json.replace("\"version\":1", "\"version\":0");
+        assert!(serde_json::from_str::<Game>(&json).is_err());
+        Ok(())
+    }
+}
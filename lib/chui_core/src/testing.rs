@@ -0,0 +1,28 @@
+//! Helpers shared by the unit tests.
+
+#![cfg(test)]
+
+use crate::prelude::*;
+
+/// Get a new game between two players, with moves parsed by `parser_engine`.
+pub fn new_game(parser_engine: ParserEngine) -> Game {
+    let white = Player::new(Color::White, Some("Camina Drummer"), Some(37), None);
+    let black = Player::new(Color::Black, Some("Klaes Ashford"), Some(72), Some(1500));
+    Game::new(white, black, parser_engine).unwrap()
+}
+
+/// Play the moves, as parsed by the game's parser, returning the move tree node of each.
+///
+/// # Errors
+///
+/// Errors when a move cannot be parsed or played.
+pub fn play(game: &mut Game, moves: &[&str]) -> ChuiResult<Vec<NodeId>> {
+    let mut nodes: Vec<NodeId> = Vec::new();
+    for the_move in moves.iter() {
+        let chess_move = game.parse(the_move.to_string(), game.to_move)?;
+        game.set_current_move(Some(chess_move));
+        game.apply_move()?;
+        nodes.push(game.get_move_tree().get_cursor());
+    }
+    Ok(nodes)
+}
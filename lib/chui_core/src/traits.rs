@@ -10,4 +10,3 @@ mod position;
 pub use position::Position;
 
 mod fen;
-pub use fen::Fen;
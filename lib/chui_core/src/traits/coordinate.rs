@@ -3,4 +3,5 @@
 pub use chui_macros::Coordinate;
 
 /// Coordinate trait.
+#[allow(dead_code)]
 trait Coordinate {}
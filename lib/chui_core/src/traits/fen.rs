@@ -5,6 +5,7 @@
 use crate::prelude::*;
 
 /// Represents the FEN notation of a chess position.
+#[allow(dead_code)]
 pub trait Fen {
     /// Get the FEN layout of the board.
     fn get_fen(board: &Board) -> String;
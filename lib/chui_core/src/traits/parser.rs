@@ -2,7 +2,7 @@
 
 use crate::prelude::*;
 
-/// Implement this trait to define the `parse()` method on a parser.
+/// Implement this trait to define the `parse_notation()` method on a parser.
 /// Any struct implementing this trait should parse a chess move
 /// in an expected notation and return a `ChessMove` object, representing
 /// the validty or invalidity of the requested move for the given
@@ -16,7 +16,7 @@ use crate::prelude::*;
 /// pub struct MyParser;
 ///
 /// impl Parser for MyParser {
-///     fn parse(&mut self, _the_move: String, _color: Color) -> ChuiResult<ChessMove> {
+///     fn parse_notation(&mut self, _the_move: String, _color: Color) -> ChuiResult<ChessMove> {
 ///         Err(
 ///             ChuiError::InvalidMove(
 ///                 "MyParser not implemented.".to_string()
@@ -43,13 +43,39 @@ use crate::prelude::*;
 /// }
 /// ```
 pub trait Parser: Send + Sync {
-    /// Parse the chess move, return `Ok(ChessMove)` on success,
-    /// `ChuiError::InvalidMove(reason)` on failure.
+    /// Parse the chess move from its notation alone, for the color `to_move`. The move is
+    /// only as resolved as the notation allows (e.g., `Nf3` does not say which Knight
+    /// moves). Return `Ok(ChessMove)` on success, `ChuiError::InvalidMove(reason)` on
+    /// failure.
     ///
     /// # Errors
     ///
     /// * Errors when the parser cannot parse a move.
-    fn parse(&mut self, move_string: String, to_move: Color) -> ChuiResult<ChessMove>;
+    fn parse_notation(&mut self, move_string: String, to_move: Color) -> ChuiResult<ChessMove>;
+
+    /// Parse the chess move for the position on `board`, for the color to move on the
+    /// board, and return the move fully resolved: `from_coord`, `from_piece`, `to_piece`
+    /// (the captured piece, if any), castling, en passant, promotion and check are set, so
+    /// the move can be applied with `Board::apply_move()`. Defaults to `parse_notation()`
+    /// followed by `ChessMove::validate_move_for_board()`. Notations that cannot be
+    /// understood without the position (e.g., `NxN` in descriptive notation) resolve the
+    /// move here.
+    ///
+    /// # Errors
+    ///
+    /// * Errors when the parser cannot parse a move.
+    /// * Errors when the move is not legal on the board, with the reason why.
+    fn parse(&mut self, move_string: String, board: &Board) -> ChuiResult<ChessMove> {
+        let mut chess_move: ChessMove = self.parse_notation(move_string, board.get_to_move())?;
+        chess_move.validate_move_for_board(board)?;
+        chess_move.check = chess_move.get_check(board)?;
+
+        Ok(chess_move)
+    }
+
+    /// Set the language of the piece letters, for notations that use them (see `Locale`).
+    /// Does nothing by default.
+    fn set_locale(&mut self, _locale: Locale) {}
 
     /// The name of the parser. Used in help messages and debug.
     fn name(&self) -> String;
@@ -69,6 +95,40 @@ pub trait Parser: Send + Sync {
         to_coord: Coord,
     ) -> ChuiResult<String>;
 
+    /// Return the move from board Coordinates, validated for the game's board. Board
+    /// Coordinates do not name a promotion piece, so a Pawn moving to the last rank
+    /// promotes to a Queen.
+    ///
+    /// # Errors
+    ///
+    /// * Errors when the move is not valid for the board.
+    fn get_move_from_board_coordinates(
+        &self,
+        game: &Game,
+        from_coord: Coord,
+        to_coord: Coord,
+    ) -> ChuiResult<ChessMove> {
+        let mut the_move = ChessMove::new(game.to_move);
+        let last_rank: u8 = if game.to_move == Color::White { 7 } else { 0 };
+
+        the_move.set_from_coord(Some(from_coord));
+        the_move.set_to_coord(Some(to_coord));
+
+        if game
+            .board
+            .get_position()
+            .get_piece(Some(from_coord))
+            .is_some_and(|piece| piece.is_pawn())
+            && to_coord.get_rank() == last_rank
+        {
+            the_move.set_promotion(Piece::new(PieceKind::Queen, game.to_move, to_coord));
+        }
+
+        the_move.validate_move_for_board(&game.board)?;
+
+        Ok(the_move)
+    }
+
     /// Trim the whitespace from `the_move` and check to see that
     /// the move doesn't contain any whitespace after the trim.
     ///
@@ -79,7 +139,7 @@ pub trait Parser: Send + Sync {
     fn trim_and_check_whitespace(&self, move_string: &str) -> ChuiResult<String> {
         let the_move: String = move_string.trim().to_string();
 
-        if the_move.eq("") {
+        if the_move.is_empty() {
             self.invalid_input("Input move cannot be empty")?;
         }
 
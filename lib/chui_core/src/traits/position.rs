@@ -2,15 +2,28 @@
 
 use std::fmt;
 
-use crate::prelude::{coord::*, *};
+use crate::prelude::*;
 
 /// trait Position.
 pub trait Position: fmt::Display {
     /// Get the piece at the given coordinate.
     fn get_piece(&self, coord: Option<Coord>) -> Option<Piece>;
 
-    // /// Get the available [`Piece`]s for a [`Color`].
-    // fn get_pieces(&self, piece: Piece) -> Vec<Piece>;
+    /// Get the [`Piece`]s on the board that are of the same kind and color as `piece`.
+    fn get_pieces(&self, piece: Piece) -> Vec<Piece> {
+        self.get_pieces_for_color(piece.get_color())
+            .into_iter()
+            .filter(|p| p.is_same_kind(piece))
+            .collect()
+    }
+
+    /// Get all of the [`Piece`]s on the board for a [`Color`], ordered from `a1` to `h8`.
+    fn get_pieces_for_color(&self, color: Color) -> Vec<Piece> {
+        (0..64)
+            .filter_map(|idx: u8| self.get_piece(Coord::try_from(idx).ok()))
+            .filter(|p| p.get_color() == color)
+            .collect()
+    }
 
     /// Take a piece off of the board.
     fn take_piece(&mut self, coord: Option<Coord>) -> Option<Piece> {
@@ -77,65 +90,59 @@ pub trait Position: fmt::Display {
             })
             .collect();
 
-        // Add any valid castling coordinates
-        match piece.get_color() {
-            Color::White => {
-                if board.white_can_castle_kingside {
-                    let d1 = Coord::try_from(D1).ok();
-                    let c1 = Coord::try_from(C1).ok();
-
-                    if self.get_piece(d1).is_none() && self.get_piece(c1).is_none() {
-                        let d1_pieces = self.get_pieces_attacking_coord(board, *piece, d1);
-                        let c1_pieces = self.get_pieces_attacking_coord(board, *piece, c1);
-
-                        if d1_pieces.is_empty() && c1_pieces.is_empty() {
-                            coords.extend(vec![c1.unwrap()]);
-                        }
-                    }
-                }
-
-                if board.white_can_castle_queenside {
-                    let f1 = Coord::try_from(F1).ok();
-                    let g1 = Coord::try_from(G1).ok();
+        // Add any valid castling coordinates. The King cannot castle out of check, the
+        // squares the King and the Rook move across (and land on) must be empty except for
+        // the King and the Rook themselves, and the King cannot pass through or land on an
+        // attacked square. On a Chess960 board the King castles by moving onto the Rook.
+        let color: Color = piece.get_color();
+
+        if (!board.can_castle(color, Castling::King) && !board.can_castle(color, Castling::Queen))
+            || !self
+                .get_pieces_attacking_coord(board, *piece, Some(piece.get_coord()))
+                .is_empty()
+        {
+            return coords;
+        }
 
-                    if self.get_piece(f1).is_none() && self.get_piece(g1).is_none() {
-                        let f1_pieces = self.get_pieces_attacking_coord(board, *piece, f1);
-                        let g1_pieces = self.get_pieces_attacking_coord(board, *piece, g1);
+        for castling in [Castling::King, Castling::Queen] {
+            let Ok(castling_coord) = board.get_castling_coord(color, castling) else {
+                continue;
+            };
+            let Ok(rook_coord) = board.get_castling_rook_coord(color, castling) else {
+                continue;
+            };
 
-                        if f1_pieces.is_empty() && g1_pieces.is_empty() {
-                            coords.extend(vec![g1.unwrap()]);
-                        }
-                    }
-                }
+            if board.get_castling(*piece, castling_coord) != Some(castling) {
+                continue;
             }
-            Color::Black => {
-                if board.black_can_castle_kingside {
-                    let d8 = Coord::try_from(D8).ok();
-                    let c8 = Coord::try_from(C8).ok();
-
-                    if self.get_piece(d8).is_none() && self.get_piece(c8).is_none() {
-                        let d8_pieces = self.get_pieces_attacking_coord(board, *piece, d8);
-                        let c8_pieces = self.get_pieces_attacking_coord(board, *piece, c8);
-
-                        if d8_pieces.is_empty() && c8_pieces.is_empty() {
-                            coords.extend(vec![c8.unwrap()]);
-                        }
-                    }
-                }
 
-                if board.black_can_castle_queenside {
-                    let f8 = Coord::try_from(F8).ok();
-                    let g8 = Coord::try_from(G8).ok();
+            let rank: u8 = piece.get_rank();
+            let king_from: u8 = piece.get_file();
+            let rook_from: u8 = rook_coord.get_file();
+            let (king_to, rook_to): (u8, u8) = match castling {
+                Castling::King => (6, 5),
+                Castling::Queen => (2, 3),
+            };
+
+            if !self
+                .get_piece(Coord::new(rook_from, rank).ok())
+                .is_some_and(|p| p.is_rook() && p.is_same_color(*piece))
+            {
+                continue;
+            }
 
-                    if self.get_piece(f8).is_none() && self.get_piece(g8).is_none() {
-                        let f8_pieces = self.get_pieces_attacking_coord(board, *piece, f8);
-                        let g8_pieces = self.get_pieces_attacking_coord(board, *piece, g8);
+            let low: u8 = king_from.min(king_to).min(rook_from).min(rook_to);
+            let high: u8 = king_from.max(king_to).max(rook_from).max(rook_to);
+            let is_empty = (low..=high)
+                .filter(|file| *file != king_from && *file != rook_from)
+                .all(|file| self.get_piece(Coord::new(file, rank).ok()).is_none());
+            let is_safe = (king_from.min(king_to)..=king_from.max(king_to)).all(|file| {
+                self.get_pieces_attacking_coord(board, *piece, Coord::new(file, rank).ok())
+                    .is_empty()
+            });
 
-                        if f8_pieces.is_empty() && g8_pieces.is_empty() {
-                            coords.extend(vec![g8.unwrap()]);
-                        }
-                    }
-                }
+            if is_empty && is_safe {
+                coords.push(castling_coord);
             }
         }
 
@@ -278,6 +285,13 @@ pub trait Position: fmt::Display {
         let file_idx = piece.get_file();
         let rank_idx = piece.get_rank();
 
+        // Only the opponent's Pawn can be captured en passant.
+        let en_passant = board.get_en_passant_coord().filter(|_| {
+            board
+                .get_en_passant_piece()
+                .is_some_and(|p| !p.is_same_color(*piece))
+        });
+
         if piece.get_color() == Color::White {
             let new_coord_1 = Coord::new(file_idx, rank_idx + 1);
             let new_coord_2 = Coord::new(file_idx, rank_idx + 2);
@@ -310,7 +324,7 @@ pub trait Position: fmt::Display {
                     }
                 }
 
-                if let Some(en_passant) = board.get_en_passant_coord() {
+                if let Some(en_passant) = en_passant {
                     if capture_1 == en_passant {
                         coords.push(capture_1);
                     }
@@ -324,7 +338,7 @@ pub trait Position: fmt::Display {
                     }
                 }
 
-                if let Some(en_passant) = board.get_en_passant_coord() {
+                if let Some(en_passant) = en_passant {
                     if capture_2 == en_passant {
                         coords.push(capture_2);
                     }
@@ -362,7 +376,7 @@ pub trait Position: fmt::Display {
                     }
                 }
 
-                if let Some(en_passant) = board.get_en_passant_coord() {
+                if let Some(en_passant) = en_passant {
                     if capture_1 == en_passant {
                         coords.push(capture_1);
                     }
@@ -376,7 +390,7 @@ pub trait Position: fmt::Display {
                     }
                 }
 
-                if let Some(en_passant) = board.get_en_passant_coord() {
+                if let Some(en_passant) = en_passant {
                     if capture_2 == en_passant {
                         coords.push(capture_2);
                     }
@@ -391,6 +405,7 @@ pub trait Position: fmt::Display {
     // Position Coords.
     //
 
+    #[allow(clippy::explicit_counter_loop)]
     /// Get any Coordates North of the indicated indices that a piece can move.
     fn get_top_coords(&self, piece: &Piece) -> Vec<Coord> {
         let mut coords = Vec::<Coord>::new();
@@ -418,6 +433,7 @@ pub trait Position: fmt::Display {
         coords
     }
 
+    #[allow(clippy::explicit_counter_loop)]
     /// Get any Coordates East of the indicated indices that a piece can move.
     fn get_right_coords(&self, piece: &Piece) -> Vec<Coord> {
         let mut coords = Vec::<Coord>::new();
@@ -499,6 +515,7 @@ pub trait Position: fmt::Display {
         coords
     }
 
+    #[allow(clippy::explicit_counter_loop)]
     /// Get any Coordates North West of the indicated indices that a piece can move.
     fn get_top_left_coords(&self, piece: &Piece) -> Vec<Coord> {
         let mut coords = Vec::<Coord>::new();
@@ -528,6 +545,7 @@ pub trait Position: fmt::Display {
         coords
     }
 
+    #[allow(clippy::explicit_counter_loop)]
     /// Get any Coordates North East of the indicated indices that a piece can move.
     fn get_top_right_coords(&self, piece: &Piece) -> Vec<Coord> {
         let mut coords = Vec::<Coord>::new();
@@ -557,6 +575,7 @@ pub trait Position: fmt::Display {
         coords
     }
 
+    #[allow(clippy::explicit_counter_loop)]
     /// Get any Coordates South East of the indicated indices that a piece can move.
     fn get_bottom_right_coords(&self, piece: &Piece) -> Vec<Coord> {
         let mut coords = Vec::<Coord>::new();
@@ -1,7 +1,10 @@
 //! Chess variants.
 
+use crate::prelude::*;
+
 /// The various chess variants available in Chui.
 #[derive(Debug, Copy, Clone, Default, PartialOrd, Ord, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Variant {
     /// Standard Chess is the default chess variant. Used in all tournaments
     /// and official gameplay.
@@ -10,5 +13,155 @@ pub enum Variant {
 
     /// Empty chessboard.
     Empty,
-    //Chess960,
+
+    /// Chess960 (Fischer Random Chess), given the index (0-959) of the start position.
+    /// The pieces on the back ranks are shuffled, with the Bishops on squares of opposite
+    /// colors and the King between the Rooks. Index 518 is the Standard Chess start
+    /// position. Indices past 959 wrap around.
+    Chess960(u16),
+}
+
+impl Variant {
+    /// Get the kinds of the pieces on the back rank of the start position, from the a-file
+    /// to the h-file. Returns `None` for the empty chessboard.
+    pub fn get_back_rank(self) -> Option<[PieceKind; 8]> {
+        match self {
+            Variant::StandardChess => Some(Variant::chess960_back_rank(518)),
+            Variant::Empty => None,
+            Variant::Chess960(idx) => Some(Variant::chess960_back_rank(idx % 960)),
+        }
+    }
+
+    /// Get the pieces of the start position, for both colors.
+    pub fn get_pieces(self) -> Vec<Piece> {
+        let mut pieces = Vec::<Piece>::new();
+
+        if let Some(back_rank) = self.get_back_rank() {
+            for (file, kind) in (0..8).zip(back_rank) {
+                for (color, back, front) in [(Color::White, 0, 1), (Color::Black, 7, 6)] {
+                    if let (Ok(back), Ok(front)) = (Coord::new(file, back), Coord::new(file, front))
+                    {
+                        pieces.push(Piece::new(kind, color, back));
+                        pieces.push(Piece::new(PieceKind::Pawn, color, front));
+                    }
+                }
+            }
+        }
+
+        pieces
+    }
+
+    /// Get the back rank of the Chess960 start position with the given index, using the
+    /// Scharnagl numbering: the index picks, in turn, the file of the light-squared Bishop,
+    /// the file of the dark-squared Bishop, the empty square of the Queen, and the empty
+    /// squares of the Knights. The Rooks and the King take the last three empty squares.
+    fn chess960_back_rank(idx: u16) -> [PieceKind; 8] {
+        const KNIGHTS: [(usize, usize); 10] = [
+            (0, 1),
+            (0, 2),
+            (0, 3),
+            (0, 4),
+            (1, 2),
+            (1, 3),
+            (1, 4),
+            (2, 3),
+            (2, 4),
+            (3, 4),
+        ];
+
+        let mut back_rank: [Option<PieceKind>; 8] = [None; 8];
+        let mut n = usize::from(idx);
+
+        back_rank[n % 4 * 2 + 1] = Some(PieceKind::Bishop);
+        n /= 4;
+        back_rank[n % 4 * 2] = Some(PieceKind::Bishop);
+        n /= 4;
+
+        let empty = |back_rank: &[Option<PieceKind>; 8]| -> Vec<usize> {
+            (0..8).filter(|file| back_rank[*file].is_none()).collect()
+        };
+
+        back_rank[empty(&back_rank)[n % 6]] = Some(PieceKind::Queen);
+        n /= 6;
+
+        let files = empty(&back_rank);
+        let (first, second) = KNIGHTS[n % 10];
+        back_rank[files[first]] = Some(PieceKind::Knight);
+        back_rank[files[second]] = Some(PieceKind::Knight);
+
+        let files = empty(&back_rank);
+        back_rank[files[0]] = Some(PieceKind::Rook);
+        back_rank[files[1]] = Some(PieceKind::King);
+        back_rank[files[2]] = Some(PieceKind::Rook);
+
+        back_rank.map(|kind| kind.unwrap_or(PieceKind::Pawn))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chess960_standard_position() {
+        assert_eq!(
+            Variant::Chess960(518).get_back_rank(),
+            Variant::StandardChess.get_back_rank()
+        );
+        assert_eq!(
+            Variant::StandardChess.get_back_rank(),
+            Some([
+                PieceKind::Rook,
+                PieceKind::Knight,
+                PieceKind::Bishop,
+                PieceKind::Queen,
+                PieceKind::King,
+                PieceKind::Bishop,
+                PieceKind::Knight,
+                PieceKind::Rook,
+            ])
+        );
+    }
+
+    #[test]
+    fn chess960_positions() -> ChuiResult<()> {
+        // Known start positions.
+        for (idx, back_rank) in [(0, "BBQNNRKR"), (1, "BQNBNRKR"), (959, "RKRNNQBB")] {
+            let kinds: Vec<PieceKind> = back_rank
+                .chars()
+                .map(|c| Piece::try_from(c).map(|piece| piece.get_kind()))
+                .collect::<ChuiResult<_>>()?;
+            assert_eq!(
+                Variant::Chess960(idx).get_back_rank().unwrap().to_vec(),
+                kinds
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn chess960_positions_are_distinct_and_valid() {
+        let mut back_ranks: Vec<[PieceKind; 8]> = (0..960)
+            .map(|idx| Variant::Chess960(idx).get_back_rank().unwrap())
+            .collect();
+
+        for back_rank in back_ranks.iter() {
+            let files = |kind: PieceKind| -> Vec<usize> {
+                (0..8).filter(|file| back_rank[*file] == kind).collect()
+            };
+            let bishops = files(PieceKind::Bishop);
+            let rooks = files(PieceKind::Rook);
+            let king = files(PieceKind::King);
+
+            assert_eq!(bishops.len(), 2);
+            assert_ne!(bishops[0] % 2, bishops[1] % 2);
+            assert_eq!(files(PieceKind::Knight).len(), 2);
+            assert_eq!(files(PieceKind::Queen).len(), 1);
+            assert!(rooks[0] < king[0] && king[0] < rooks[1]);
+        }
+
+        back_ranks.sort_unstable();
+        back_ranks.dedup();
+        assert_eq!(back_ranks.len(), 960);
+    }
 }
@@ -0,0 +1,134 @@
+//! Zobrist hashing module.
+//!
+//! Each feature of a position (a piece on a square, the side to move, a castling right, and the
+//! en passant file) has a random 64-bit key. The hash of a position is the XOR of the keys of
+//! all of its features. Since XOR is its own inverse, the hash is updated incrementally as
+//! pieces are put onto and taken off of the board.
+
+use crate::prelude::*;
+
+/// The number of keys for the pieces: 6 kinds, 2 colors, 64 squares.
+const PIECE_KEYS: usize = 6 * 2 * 64;
+
+/// The offset of the side to move key.
+const SIDE_KEY: usize = PIECE_KEYS;
+
+/// The offset of the castling right keys (White King side, White Queen side, Black King
+/// side, Black Queen side).
+const CASTLING_KEYS: usize = SIDE_KEY + 1;
+
+/// The offset of the en passant file keys.
+const EN_PASSANT_KEYS: usize = CASTLING_KEYS + 4;
+
+/// The total number of keys.
+const KEY_COUNT: usize = EN_PASSANT_KEYS + 8;
+
+/// The keys, generated at compile time from a fixed seed so that hashes are the same between
+/// runs and builds.
+const KEYS: [u64; KEY_COUNT] = generate_keys(0x6368_7569_5f7a_6f62);
+
+/// Generate the keys with the SplitMix64 pseudo-random number generator.
+const fn generate_keys(seed: u64) -> [u64; KEY_COUNT] {
+    let mut keys = [0u64; KEY_COUNT];
+    let mut state = seed;
+    let mut idx = 0;
+
+    while idx < KEY_COUNT {
+        state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        keys[idx] = z ^ (z >> 31);
+        idx += 1;
+    }
+
+    keys
+}
+
+/// Zobrist keys for the features of a position.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Zobrist;
+
+impl Zobrist {
+    /// Get the key of `piece` on its square.
+    pub fn piece(piece: Piece) -> u64 {
+        let kind: usize = match piece.get_kind() {
+            PieceKind::Pawn => 0,
+            PieceKind::Knight => 1,
+            PieceKind::Bishop => 2,
+            PieceKind::Rook => 3,
+            PieceKind::Queen => 4,
+            PieceKind::King => 5,
+        };
+        let color: usize = match piece.get_color() {
+            Color::White => 0,
+            Color::Black => 1,
+        };
+
+        KEYS[(color * 6 + kind) * 64 + piece.get_coord().get_index() as usize]
+    }
+
+    /// Get the key for Black to move.
+    pub const fn black_to_move() -> u64 {
+        KEYS[SIDE_KEY]
+    }
+
+    /// Get the key of the castling rights of `board`.
+    pub const fn castling(board: &Board) -> u64 {
+        let mut key: u64 = 0;
+
+        if board.white_can_castle_kingside {
+            key ^= KEYS[CASTLING_KEYS];
+        }
+
+        if board.white_can_castle_queenside {
+            key ^= KEYS[CASTLING_KEYS + 1];
+        }
+
+        if board.black_can_castle_kingside {
+            key ^= KEYS[CASTLING_KEYS + 2];
+        }
+
+        if board.black_can_castle_queenside {
+            key ^= KEYS[CASTLING_KEYS + 3];
+        }
+
+        key
+    }
+
+    /// Get the key of the file of an en passant target square.
+    pub fn en_passant(coord: Coord) -> u64 {
+        KEYS[EN_PASSANT_KEYS + coord.get_file() as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys_are_unique() {
+        let mut keys = KEYS.to_vec();
+        keys.sort_unstable();
+        keys.dedup();
+        assert_eq!(KEY_COUNT, keys.len());
+        assert!(!keys.contains(&0));
+    }
+
+    #[test]
+    fn piece_keys_differ() -> ChuiResult<()> {
+        assert_ne!(
+            Zobrist::piece(Piece::white_pawn(('e', 4))?),
+            Zobrist::piece(Piece::black_pawn(('e', 4))?)
+        );
+        assert_ne!(
+            Zobrist::piece(Piece::white_pawn(('e', 4))?),
+            Zobrist::piece(Piece::white_knight(('e', 4))?)
+        );
+        assert_ne!(
+            Zobrist::piece(Piece::white_pawn(('e', 4))?),
+            Zobrist::piece(Piece::white_pawn(('e', 5))?)
+        );
+        Ok(())
+    }
+}